@@ -1,7 +1,9 @@
 //! The module with functions to test optimization algorithms.
 
 use num::Float;
-
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 /// Paraboloid.
 ///
@@ -74,18 +76,258 @@ pub fn rastrigin<G: Float>(x: &Vec<G>) -> f64 {
 /// Global minimum is x' = (1, 1, ...) for xn in (-inf; +inf)
 /// f(x') = 0
 ///
+/// The function is defined for two and more dimensions. The function returns 0 for empty and
+/// single-element vectors (the sum has no terms).
+///
 /// ```
 /// use optlib_testfunc::rosenbrock;
 ///
 /// let x = vec![1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32];
 /// let value = rosenbrock(&x);
 /// assert!(value.abs() < 1e-7);
+///
+/// assert_eq!(rosenbrock::<f64>(&vec![]), 0.0);
+/// assert_eq!(rosenbrock(&vec![5.0]), 0.0);
 /// ```
 pub fn rosenbrock<G: Float>(x: &Vec<G>) -> f64 {
     let mut sum = G::from(0.0).unwrap();
-    for n in 0..x.len() - 1 {
+    for n in 0..x.len().saturating_sub(1) {
         sum = sum + G::from(100.0).unwrap() * ((x[n + 1] - x[n] * x[n]).powi(2)) + (G::from(1.0).unwrap() - x[n]).powi(2);
     }
 
     sum.to_f64().unwrap()
 }
+
+/// The Styblinski-Tang function
+///
+/// # Parameters
+/// Usually xn lies in [-5.0; 5.0].
+/// Global minimum is x' = (-2.903534, -2.903534, ...).
+/// f(x') = -39.16599 * n, where n is dimension.
+///
+/// ```
+/// use optlib_testfunc::styblinski_tang;
+///
+/// let x = vec![-2.903534, -2.903534];
+/// let value = styblinski_tang(&x);
+/// assert!((value + 78.332).abs() < 1e-3);
+/// ```
+pub fn styblinski_tang<G: Float>(x: &Vec<G>) -> f64 {
+    let sum = x.iter().fold(G::zero(), |acc, &xi| {
+        acc + xi.powi(4) - G::from(16.0).unwrap() * xi * xi + G::from(5.0).unwrap() * xi
+    });
+
+    (G::from(0.5).unwrap() * sum).to_f64().unwrap()
+}
+
+/// The Zakharov function
+///
+/// # Parameters
+/// Usually xn lies in [-5.0; 10.0].
+/// Global minimum is x' = (0, 0, ...).
+/// f(x') = 0
+///
+/// ```
+/// use optlib_testfunc::zakharov;
+///
+/// let x = vec![0.0; 5];
+/// let value = zakharov(&x);
+/// assert!(value.abs() < 1e-10);
+///
+/// // 2 + (0.5 * 1 + 0.5 * 2)^2 + (0.5 * 1 + 0.5 * 2)^4
+/// let value = zakharov(&vec![1.0, 1.0]);
+/// assert!((value - 9.3125).abs() < 1e-10);
+/// ```
+pub fn zakharov<G: Float>(x: &Vec<G>) -> f64 {
+    let half = G::from(0.5).unwrap();
+    let sum_squares = x.iter().fold(G::zero(), |acc, &xi| acc + xi * xi);
+    let sum_weighted = x.iter().enumerate().fold(G::zero(), |acc, (i, &xi)| {
+        acc + half * G::from(i + 1).unwrap() * xi
+    });
+
+    (sum_squares + sum_weighted.powi(2) + sum_weighted.powi(4))
+        .to_f64()
+        .unwrap()
+}
+
+/// The Rosenbrock function with the rotated and shifted coordinates.
+///
+/// f(x) = rosenbrock(R * (x - offset)), where R is the orthogonal rotation matrix.
+/// The valley of the function is not aligned with the axes.
+/// Global minimum is x' = offset + R^T * (1, 1, ...), f(x') = 0.
+///
+/// The crate does not depend on optlib, so wrap the function in a struct which implements
+/// `optlib::Goal` to use it as goal function.
+///
+/// ```
+/// use optlib_testfunc::RotatedRosenbrock;
+///
+/// let goal = RotatedRosenbrock::<f64>::from_seed(5, 42);
+/// let minimum = goal.get_minimum();
+/// let value = goal.get(&minimum);
+/// assert!(value.abs() < 1e-10);
+///
+/// let not_minimum = vec![1.0; 5];
+/// assert!(goal.get(&not_minimum) > 1e-3);
+/// ```
+pub struct RotatedRosenbrock<G: Float> {
+    rotation: Vec<Vec<G>>,
+    offset: Vec<G>,
+}
+
+impl<G: Float> RotatedRosenbrock<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `rotation` - square orthogonal matrix. rotation[i] is the i-th row of the matrix.
+    pub fn new(rotation: Vec<Vec<G>>) -> Self {
+        let dimension = rotation.len();
+        assert!(dimension > 1, "The dimension must be greater than 1");
+
+        let eps = G::from(1e-6).unwrap();
+        for i in 0..dimension {
+            assert_eq!(rotation[i].len(), dimension, "The rotation matrix must be square");
+            for j in 0..dimension {
+                let dot = Self::dot(&rotation[i], &rotation[j]);
+                let expected = if i == j { G::one() } else { G::zero() };
+                assert!((dot - expected).abs() < eps, "The rotation matrix must be orthogonal");
+            }
+        }
+
+        Self {
+            rotation,
+            offset: vec![G::zero(); dimension],
+        }
+    }
+
+    /// Create the function with the random rotation matrix generated from `seed`.
+    /// The same seed gives the same matrix.
+    ///
+    /// # Parameters
+    /// * `dimension` - count of the function arguments.
+    /// * `seed` - seed for the random numbers generator.
+    pub fn from_seed(dimension: usize, seed: u64) -> Self {
+        assert!(dimension > 1, "The dimension must be greater than 1");
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let between = Uniform::new(-1.0_f64, 1.0_f64);
+
+        // Gram-Schmidt orthonormalization of the random vectors
+        let mut rows: Vec<Vec<f64>> = Vec::with_capacity(dimension);
+        while rows.len() < dimension {
+            let mut row: Vec<f64> = (0..dimension).map(|_| between.sample(&mut rng)).collect();
+            for prev in rows.iter() {
+                let dot: f64 = row.iter().zip(prev.iter()).map(|(a, b)| a * b).sum();
+                for (x, p) in row.iter_mut().zip(prev.iter()) {
+                    *x -= dot * p;
+                }
+            }
+
+            let norm = row.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm < 1e-3 {
+                continue;
+            }
+
+            rows.push(row.iter().map(|x| x / norm).collect());
+        }
+
+        let rotation = rows
+            .iter()
+            .map(|row| row.iter().map(|&x| G::from(x).unwrap()).collect())
+            .collect();
+
+        Self::new(rotation)
+    }
+
+    /// Set the shift of the function. The function is evaluated for (x - offset).
+    pub fn offset(mut self, offset: Vec<G>) -> Self {
+        assert_eq!(offset.len(), self.rotation.len(), "Invalid offset length");
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the rotation matrix.
+    pub fn get_rotation(&self) -> &Vec<Vec<G>> {
+        &self.rotation
+    }
+
+    /// Returns the point of the global minimum: offset + R^T * (1, 1, ...).
+    pub fn get_minimum(&self) -> Vec<G> {
+        let dimension = self.rotation.len();
+        (0..dimension)
+            .map(|j| {
+                self.offset[j]
+                    + self
+                        .rotation
+                        .iter()
+                        .fold(G::zero(), |acc, row| acc + row[j])
+            })
+            .collect()
+    }
+
+    /// Returns value of the function for the point `x`.
+    pub fn get(&self, x: &[G]) -> f64 {
+        assert_eq!(x.len(), self.rotation.len(), "Invalid dimension of x");
+
+        let shifted: Vec<G> = x
+            .iter()
+            .zip(self.offset.iter())
+            .map(|(&xi, &offset)| xi - offset)
+            .collect();
+
+        let rotated: Vec<G> = self
+            .rotation
+            .iter()
+            .map(|row| Self::dot(row, &shifted))
+            .collect();
+
+        rosenbrock(&rotated)
+    }
+
+    fn dot(a: &[G], b: &[G]) -> G {
+        a.iter()
+            .zip(b.iter())
+            .fold(G::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotated_rosenbrock_identity() {
+        let rotation = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let goal = RotatedRosenbrock::new(rotation);
+
+        for x in &[vec![1.0, 1.0], vec![0.0, 0.0], vec![-1.5, 2.0]] {
+            assert_eq!(goal.get(x), rosenbrock(x));
+        }
+    }
+
+    #[test]
+    fn rotated_rosenbrock_minimum() {
+        let offset = vec![0.5, -1.0, 2.0, 0.0];
+        let goal = RotatedRosenbrock::<f64>::from_seed(4, 7).offset(offset.clone());
+
+        let minimum = goal.get_minimum();
+        assert!(goal.get(&minimum).abs() < 1e-10);
+        assert!(goal.get(&offset) > 1e-3);
+    }
+
+    #[test]
+    fn rotated_rosenbrock_seed() {
+        let goal_1 = RotatedRosenbrock::<f64>::from_seed(3, 42);
+        let goal_2 = RotatedRosenbrock::<f64>::from_seed(3, 42);
+        let goal_3 = RotatedRosenbrock::<f64>::from_seed(3, 43);
+
+        assert_eq!(goal_1.get_rotation(), goal_2.get_rotation());
+        assert_ne!(goal_1.get_rotation(), goal_3.get_rotation());
+    }
+
+    #[test]
+    #[should_panic(expected = "orthogonal")]
+    fn rotated_rosenbrock_not_orthogonal() {
+        RotatedRosenbrock::new(vec![vec![1.0, 1.0], vec![0.0, 1.0]]);
+    }
+}
@@ -99,29 +99,8 @@ fn print_convergence_statistics(
     }
 }
 
-fn print_solution(
-    mut writer: &mut dyn io::Write,
-    stat: &statistics::Statistics<Vec<Coordinate>>,
-) {
-    let run_count = stat.get_run_count();
-
-    // Print solutions for every running
-    let results = stat.get_results();
-    for n in 0..run_count {
-        if let Some((solution, goal)) = &results[n] {
-            let mut result_str = String::new();
-            result_str = result_str + &format!("{:<8}", n);
-
-            for x in solution {
-                result_str = result_str + &format!("  {:<20.10}", x);
-            }
-            result_str = result_str + &format!("  {:20.10}", goal);
-
-            writeln!(&mut writer, "{}", result_str).unwrap();
-        } else {
-            writeln!(&mut writer, "{n:<8}  Failed", n = n).unwrap();
-        }
-    }
+fn print_solution(writer: &mut dyn io::Write, stat: &statistics::Statistics<Vec<Coordinate>>) {
+    statistics::write_results(writer, stat.get_results(), 10, false).unwrap();
 }
 
 fn print_statistics(
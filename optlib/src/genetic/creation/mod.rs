@@ -1,2 +1,79 @@
 //! The module with algorithms with initial creation of individuals
 pub mod vec_float;
+pub mod vec_int;
+
+use crate::genetic::Creator;
+
+/// Creator which joins chromosomes from several creators.
+/// The result of `create()` is concatenation of the outputs of all creators in order
+/// of the adding.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct CompositeCreator<T> {
+    creators: Vec<Box<dyn Creator<T>>>,
+}
+
+impl<T> CompositeCreator<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `creators` - vector of the creators. The creators will be called in order of the vector.
+    pub fn new(creators: Vec<Box<dyn Creator<T>>>) -> Self {
+        Self { creators }
+    }
+}
+
+impl<T> Creator<T> for CompositeCreator<T> {
+    fn create(&mut self) -> Vec<T> {
+        let mut result = vec![];
+        for creator in self.creators.iter_mut() {
+            result.append(&mut creator.create());
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::creation::vec_float::RandomCreator;
+
+    /// Creator returns preset chromosomes.
+    struct PresetCreator {
+        chromosomes: Vec<Vec<f64>>,
+    }
+
+    impl Creator<Vec<f64>> for PresetCreator {
+        fn create(&mut self) -> Vec<Vec<f64>> {
+            self.chromosomes.clone()
+        }
+    }
+
+    #[test]
+    fn composite_empty() {
+        let mut creator: CompositeCreator<Vec<f64>> = CompositeCreator::new(vec![]);
+        assert_eq!(creator.create().len(), 0);
+    }
+
+    #[test]
+    fn composite_preset_random() {
+        let preset = PresetCreator {
+            chromosomes: vec![vec![-1.0, -2.0], vec![-3.0, -4.0]],
+        };
+        let random = RandomCreator::new(10, vec![(0.0, 1.0), (0.0, 1.0)]);
+
+        let mut creator = CompositeCreator::new(vec![Box::new(preset), Box::new(random)]);
+        let chromosomes = creator.create();
+
+        assert_eq!(chromosomes.len(), 12);
+        assert_eq!(chromosomes[0], vec![-1.0, -2.0]);
+        assert_eq!(chromosomes[1], vec![-3.0, -4.0]);
+
+        for chromosome in &chromosomes[2..] {
+            assert_eq!(chromosome.len(), 2);
+            assert!(chromosome[0] >= 0.0 && chromosome[0] <= 1.0);
+            assert!(chromosome[1] >= 0.0 && chromosome[1] <= 1.0);
+        }
+    }
+}
@@ -7,13 +7,14 @@ use rand::distributions::{Distribution, Uniform};
 use rand::rngs::ThreadRng;
 
 use crate::genetic::Creator;
+use crate::tools::Intervals;
 
 /// Creator to initialize population by individuals with random genes in the preset
 /// intervals.
 /// `G` - type of genes. Chromosome is vector of the genes.
 pub struct RandomCreator<G: NumCast + PartialOrd> {
     population_size: usize,
-    intervals: Vec<(G, G)>,
+    intervals: Intervals<G>,
     random: ThreadRng,
 }
 
@@ -26,13 +27,14 @@ impl<G: NumCast + PartialOrd> RandomCreator<G> {
     /// * `population_size` - individuals count in the first generation.
     /// * `intervals` - vector of the tuples (minval, maxval). Length of the `intervals` must
     /// equal genes count in the chromosome. The values of `minval` and `maxval` will be included
-    /// in random interval.
+    /// in random interval. `minval` must be less or equal than `maxval`.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(population_size: usize, intervals: Vec<(G, G)>) -> Self {
         assert!(population_size > 0);
         assert!(!intervals.is_empty());
-        for interval in &intervals {
-            assert!(interval.0 < interval.1);
-        }
+        let intervals = Intervals::new(intervals).unwrap();
 
         let random = rand::thread_rng();
         Self {
@@ -50,7 +52,7 @@ impl<G: NumCast + PartialOrd> Creator<Vec<G>> for RandomCreator<G> {
 
         for _ in 0..self.population_size {
             let mut chromo = Vec::with_capacity(chromo_count);
-            for interval in &self.intervals {
+            for interval in self.intervals.iter() {
                 let between = Uniform::new_inclusive(
                     interval.0.to_f64().unwrap(),
                     interval.1.to_f64().unwrap(),
@@ -123,11 +125,17 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn invalid_intervals_02() {
+    fn degenerate_intervals() {
         let population_size = 10;
-        let intervals = vec![(0.0, 0.0)];
-        RandomCreator::new(population_size, intervals);
+        let intervals = vec![(0.0, 0.0), (0.0, 1.0)];
+        let mut creator = RandomCreator::new(population_size, intervals);
+
+        let chromosomes: Vec<Vec<f64>> = creator.create();
+        for chromosome in chromosomes {
+            assert_eq!(chromosome[0], 0.0);
+            assert!(chromosome[1] >= 0.0);
+            assert!(chromosome[1] <= 1.0);
+        }
     }
 
     #[test]
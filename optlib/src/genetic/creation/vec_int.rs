@@ -0,0 +1,101 @@
+//! The module with Creators for the case when chromosomes are Vec<i64> (vector of the integer
+//! genes). Gene - single value in chromosome.
+//! The Creators used to create the first generation of individuals.
+
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::ThreadRng;
+
+use crate::genetic::Creator;
+use crate::tools::Intervals;
+
+/// Creator to initialize population by individuals with random integer genes in the preset
+/// intervals. Chromosome is vector of the genes (`Vec<i64>`).
+pub struct RandomCreator {
+    population_size: usize,
+    intervals: Intervals<i64>,
+    random: ThreadRng,
+}
+
+impl RandomCreator {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `intervals` - vector of the tuples (minval, maxval). Length of the `intervals` must
+    ///   equal genes count in the chromosome. The values of `minval` and `maxval` will be
+    ///   included in random interval. `minval` must be less or equal than `maxval`.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(population_size: usize, intervals: Vec<(i64, i64)>) -> Self {
+        assert!(population_size > 0);
+        assert!(!intervals.is_empty());
+        let intervals = Intervals::new(intervals).unwrap();
+
+        let random = rand::thread_rng();
+        Self {
+            population_size,
+            intervals,
+            random,
+        }
+    }
+}
+
+impl Creator<Vec<i64>> for RandomCreator {
+    fn create(&mut self) -> Vec<Vec<i64>> {
+        let distributions: Vec<Uniform<i64>> = self
+            .intervals
+            .iter()
+            .map(|(min, max)| Uniform::new_inclusive(min, max))
+            .collect();
+
+        let mut population = Vec::with_capacity(self.population_size * 2);
+        for _ in 0..self.population_size {
+            let chromo = distributions
+                .iter()
+                .map(|between| between.sample(&mut self.random))
+                .collect();
+
+            population.push(chromo);
+        }
+
+        population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn population_size() {
+        let population_size = 10;
+        let mut creator = RandomCreator::new(population_size, vec![(0, 1)]);
+
+        assert_eq!(creator.create().len(), population_size);
+    }
+
+    #[test]
+    fn intervals() {
+        let intervals = vec![(0, 1), (-5, 5), (100, 100)];
+        let mut creator = RandomCreator::new(1000, intervals);
+
+        let chromosomes = creator.create();
+        for chromosome in &chromosomes {
+            assert_eq!(chromosome.len(), 3);
+            assert!(chromosome[0] >= 0 && chromosome[0] <= 1);
+            assert!(chromosome[1] >= -5 && chromosome[1] <= 5);
+            assert_eq!(chromosome[2], 100);
+        }
+
+        // The bounds are included
+        assert!(chromosomes.iter().any(|chromosome| chromosome[1] == -5));
+        assert!(chromosomes.iter().any(|chromosome| chromosome[1] == 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_intervals() {
+        RandomCreator::new(10, vec![(1, 0)]);
+    }
+}
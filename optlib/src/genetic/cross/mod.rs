@@ -5,28 +5,72 @@
 use std::mem;
 
 use crate::genetic::Cross;
+use crate::tools::Intervals;
+use crate::Objective;
 use num::{Float, Num, NumCast};
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs::ThreadRng;
 
 /// Struct to cross all genes (`G` - type of genes) in chromosome of type Vec<G>.
+/// The inner cross algorithm gets genes of all parents (two or more) for every gene position.
 pub struct VecCrossAllGenes<G> {
     single_cross: Box<dyn Cross<G>>,
 }
 
+/// Struct to call the inner cross algorithm several times for every family. Result of cross
+/// is all children of the inner cross algorithm. The struct is useful for stochastic cross
+/// algorithms (`FloatCrossExp`, `CrossBitwise` etc).
+pub struct RepeatCross<T> {
+    cross: Box<dyn Cross<T>>,
+    count: usize,
+}
+
 /// Child chromosome is arithmetic mean of parent chromosomes. Result of cross is single child.
 /// The chromosomes must be numeric type.
 pub struct CrossMean;
 
+/// Child chromosome of type Vec<G> is arithmetic mean of all parent chromosomes for every gene
+/// (`CrossMean` for any count of the parents). Result of cross is single child.
+/// The genes must be numeric type.
+#[derive(Default)]
+pub struct MultiParentMean;
+
 /// For float type chromosomes (f32, f64). Child chromosome is geometric mean of parent
 /// chromosomes. Result of cross is single child.
 pub struct FloatCrossGeometricMean;
 
+/// For float type chromosomes (f32, f64). Child chromosome is biased toward the best parent:
+/// `child = bias * best + (1 - bias) * mean(others)`, where `best` is the parent with the best
+/// value of goal function and `mean(others)` is arithmetic mean of other parents. The cross uses
+/// the goals of the parents if it is called by `Cross::cross_with_goals()` (`GeneticOptimizer`
+/// does it). If the goals are unknown (`Cross::cross()`), child is arithmetic mean of the
+/// parents. Result of cross is single child.
+pub struct FitnessBiasedCross {
+    bias: f64,
+}
+
+/// For float type chromosomes (f32, f64). The cross borrows the mutation of differential
+/// evolution: `child = parent_1 + F * (parent_2 - parent_3)`. The cross requires exactly three
+/// parents (use `pairing::Tournament` with `partners_count(3)`). Use `VecCrossAllGenes` to cross
+/// the chromosomes of type Vec<G>. Result of cross is single child.
+pub struct DifferentialCross {
+    factor: f64,
+}
+
 /// Bitwise cross. Use single point crossing. Result of cross is single child.
 pub struct CrossBitwise {
     random: ThreadRng,
 }
 
+/// Bitwise cross for chromosomes of type Vec<G> which keeps every gene of the child inside its
+/// interval. Every gene is crossed by single point crossing as `CrossBitwise`, then the gene is
+/// reflected from the bounds of the interval if it is outside. If the gene is NaN or infinite,
+/// the gene is random value in the interval. Result of cross is single child.
+pub struct BoundedCrossBitwise<G> {
+    intervals: Intervals<G>,
+    random: ThreadRng,
+}
+
 /// Bitwise cross for float type chromosomes. Exponent and mantissa will be crossed independently.
 /// Use single point crossing. The sign is taken from one of parents at random.
 pub struct FloatCrossExp {
@@ -59,6 +103,44 @@ impl<G: NumCast + Num + Clone> Cross<G> for CrossMean {
     }
 }
 
+impl MultiParentMean {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let parent_1 = vec![1.0_f64, 0.0_f64, -3.0_f64];
+/// let parent_2 = vec![2.0_f64, 3.0_f64, 0.0_f64];
+/// let parent_3 = vec![6.0_f64, 0.0_f64, 0.0_f64];
+///
+/// let mut crosser = cross::MultiParentMean::new();
+/// let children = crosser.cross(&[&parent_1, &parent_2, &parent_3]);
+/// assert_eq!(children, vec![vec![3.0_f64, 1.0_f64, -1.0_f64]]);
+/// ```
+impl<G: NumCast + Num + Clone> Cross<Vec<G>> for MultiParentMean {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert!(parents.len() >= 2);
+
+        let gene_count = parents[0].len();
+        let parents_count = G::from(parents.len()).unwrap();
+
+        let child = (0..gene_count)
+            .map(|n| {
+                parents
+                    .iter()
+                    .fold(G::zero(), |sum, parent| sum + parent[n].clone())
+                    / parents_count.clone()
+            })
+            .collect();
+
+        vec![child]
+    }
+}
+
 impl FloatCrossGeometricMean {
     /// Constructor.
     pub fn new() -> Self {
@@ -94,6 +176,115 @@ impl<G: Float> Cross<G> for FloatCrossGeometricMean {
     }
 }
 
+impl FitnessBiasedCross {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `bias` - weight of the best parent (0 <= bias <= 1). The value of 1 means the child is
+    ///   copy of the best parent, the value of 0.5 for two parents means arithmetic mean.
+    pub fn new(bias: f64) -> Self {
+        assert!((0.0..=1.0).contains(&bias));
+        Self { bias }
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+/// use optlib::Objective;
+///
+/// let mut crosser = cross::FitnessBiasedCross::new(0.75);
+///
+/// // The second parent is better
+/// let parents = [&0.0_f64, &4.0_f64];
+/// let goals = [10.0, 1.0];
+/// let children = crosser.cross_with_goals(&parents, &goals, Objective::Minimize);
+/// assert_eq!(children, vec![3.0_f64]);
+///
+/// let children = crosser.cross_with_goals(&parents, &goals, Objective::Maximize);
+/// assert_eq!(children, vec![1.0_f64]);
+///
+/// // The goals are unknown
+/// assert_eq!(crosser.cross(&parents), vec![2.0_f64]);
+///
+/// // Cross of the vectors
+/// let mut crosser = cross::VecCrossAllGenes::new(Box::new(cross::FitnessBiasedCross::new(0.75)));
+/// let parent_1 = vec![0.0_f64, 8.0_f64];
+/// let parent_2 = vec![4.0_f64, 0.0_f64];
+/// let parents = [&parent_1, &parent_2];
+/// let children = crosser.cross_with_goals(&parents, &[2.0, 1.0], Objective::Minimize);
+/// assert_eq!(children, vec![vec![3.0_f64, 2.0_f64]]);
+/// ```
+impl<G: Float> Cross<G> for FitnessBiasedCross {
+    fn cross(&mut self, parents_genes: &[&G]) -> Vec<G> {
+        assert!(parents_genes.len() >= 2);
+        let sum = parents_genes.iter().fold(G::zero(), |x, y| x + **y);
+        vec![sum / G::from(parents_genes.len()).unwrap()]
+    }
+
+    fn cross_with_goals(
+        &mut self,
+        parents_genes: &[&G],
+        goals: &[f64],
+        objective: Objective,
+    ) -> Vec<G> {
+        assert!(parents_genes.len() >= 2);
+        assert_eq!(parents_genes.len(), goals.len());
+
+        let mut best_index = 0;
+        for (n, goal) in goals.iter().enumerate() {
+            if objective.is_better(*goal, goals[best_index]) {
+                best_index = n;
+            }
+        }
+
+        let others_sum = parents_genes
+            .iter()
+            .enumerate()
+            .filter(|(n, _)| *n != best_index)
+            .fold(G::zero(), |x, (_, y)| x + **y);
+        let others_mean = others_sum / G::from(parents_genes.len() - 1).unwrap();
+
+        let bias = G::from(self.bias).unwrap();
+        vec![bias * *parents_genes[best_index] + (G::one() - bias) * others_mean]
+    }
+}
+
+impl DifferentialCross {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `factor` - the differential weight `F` (0 < F <= 2).
+    pub fn new(factor: f64) -> Self {
+        assert!(factor > 0.0 && factor <= 2.0);
+        Self { factor }
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let mut crosser = cross::DifferentialCross::new(0.5);
+/// assert_eq!(crosser.cross(&[&1.0_f64, &4.0_f64, &2.0_f64]), vec![2.0_f64]);
+///
+/// // Cross of the vectors
+/// let mut crosser = cross::VecCrossAllGenes::new(Box::new(cross::DifferentialCross::new(0.5)));
+/// let parent_1 = vec![1.0_f64, 0.0_f64, -3.0_f64];
+/// let parent_2 = vec![2.0_f64, 3.0_f64, 0.0_f64];
+/// let parent_3 = vec![6.0_f64, 1.0_f64, 0.0_f64];
+/// let children = crosser.cross(&[&parent_1, &parent_2, &parent_3]);
+/// assert_eq!(children, vec![vec![-1.0_f64, 1.0_f64, -3.0_f64]]);
+/// ```
+impl<G: Float> Cross<G> for DifferentialCross {
+    fn cross(&mut self, parents_genes: &[&G]) -> Vec<G> {
+        assert_eq!(parents_genes.len(), 3);
+        let factor = G::from(self.factor).unwrap();
+        let child = *parents_genes[0] + factor * (*parents_genes[1] - *parents_genes[2]);
+        vec![child]
+    }
+}
+
 impl CrossBitwise {
     /// Constructor.
     pub fn new() -> Self {
@@ -124,32 +315,418 @@ impl Cross<f32> for CrossBitwise {
     }
 }
 
+#[cfg(feature = "half")]
+impl Cross<half::f16> for CrossBitwise {
+    fn cross(&mut self, parents_genes: &[&half::f16]) -> Vec<half::f16> {
+        assert_eq!(parents_genes.len(), 2);
+        let size = mem::size_of::<half::f16>() * 8;
+        let between = Uniform::new(1, size);
+        let pos = between.sample(&mut self.random);
+
+        vec![cross_f16(*parents_genes[0], *parents_genes[1], pos)]
+    }
+}
+
+impl<G: Float> BoundedCrossBitwise<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - vector of the tuples (minval, maxval) for every gene of the child.
+    ///   `minval` must be less or equal than `maxval`, the values must be finite.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`) or the bounds are not
+    /// finite.
+    pub fn new(intervals: Vec<(G, G)>) -> Self {
+        for (min, max) in &intervals {
+            assert!(min.is_finite() && max.is_finite());
+        }
+
+        let random = rand::thread_rng();
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            random,
+        }
+    }
+
+    /// Move the value inside [min; max] by reflection from the bounds.
+    fn reflect(&mut self, value: G, min: G, max: G) -> G {
+        if !value.is_finite() {
+            let between = Uniform::new_inclusive(min.to_f64().unwrap(), max.to_f64().unwrap());
+            return G::from(between.sample(&mut self.random)).unwrap();
+        }
+
+        if value >= min && value <= max {
+            return value;
+        }
+
+        let width = max - min;
+        if width == G::zero() {
+            return min;
+        }
+
+        let two = G::one() + G::one();
+        let mut offset = (value - min) % (two * width);
+        if offset < G::zero() {
+            offset = offset + two * width;
+        }
+
+        if offset > width {
+            offset = two * width - offset;
+        }
+
+        (min + offset).max(min).min(max)
+    }
+
+    /// Cross every gene of the parents by `cross_gene` and reflect the child genes.
+    fn cross_genes<F>(&mut self, parents: &[&Vec<G>], cross_gene: F) -> Vec<Vec<G>>
+    where
+        F: Fn(G, G, usize) -> G,
+    {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), self.intervals.len());
+        assert_eq!(parents[1].len(), self.intervals.len());
+
+        let size = mem::size_of::<G>() * 8;
+        let between = Uniform::new(1, size);
+        let mut child = Vec::with_capacity(self.intervals.len());
+        for (n, gene_1) in parents[0].iter().enumerate() {
+            let (min, max) = self.intervals.as_vec()[n];
+            let pos = between.sample(&mut self.random);
+            let gene = cross_gene(*gene_1, parents[1][n], pos);
+            child.push(self.reflect(gene, min, max));
+        }
+
+        vec![child]
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let intervals = vec![(-500.0_f64, 500.0_f64), (0.0_f64, 1.0_f64)];
+/// let mut crosser = cross::BoundedCrossBitwise::new(intervals);
+/// let parent_1 = vec![-420.0_f64, 0.1_f64];
+/// let parent_2 = vec![420.0_f64, 0.9_f64];
+/// for _ in 0..1000 {
+///     let children = crosser.cross(&[&parent_1, &parent_2]);
+///     assert_eq!(children.len(), 1);
+///     assert!(children[0][0] >= -500.0 && children[0][0] <= 500.0);
+///     assert!(children[0][1] >= 0.0 && children[0][1] <= 1.0);
+/// }
+/// ```
+impl Cross<Vec<f64>> for BoundedCrossBitwise<f64> {
+    fn cross(&mut self, parents: &[&Vec<f64>]) -> Vec<Vec<f64>> {
+        self.cross_genes(parents, cross_f64)
+    }
+}
+
+impl Cross<Vec<f32>> for BoundedCrossBitwise<f32> {
+    fn cross(&mut self, parents: &[&Vec<f32>]) -> Vec<Vec<f32>> {
+        self.cross_genes(parents, cross_f32)
+    }
+}
+
+impl<T> RepeatCross<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `cross` - inner cross algorithm.
+    /// * `count` - how many times the inner cross algorithm will be called for every family.
+    pub fn new(cross: Box<dyn Cross<T>>, count: usize) -> Self {
+        assert!(count > 0);
+        Self { cross, count }
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let mut crosser = cross::RepeatCross::new(Box::new(cross::CrossMean::new()), 3);
+/// assert_eq!(crosser.cross(&[&1.0_f64, &2.0_f64]), vec![1.5_f64, 1.5_f64, 1.5_f64]);
+///
+/// let single_cross = cross::VecCrossAllGenes::new(Box::new(cross::FloatCrossExp::new()));
+/// let mut crosser = cross::RepeatCross::new(Box::new(single_cross), 3);
+/// let parent_1 = vec![1.0_f64, 2.0_f64];
+/// let parent_2 = vec![3.0_f64, 4.0_f64];
+/// let children = crosser.cross(&[&parent_1, &parent_2]);
+/// assert_eq!(children.len(), 3);
+/// assert!(children.iter().all(|child| child.len() == 2));
+/// ```
+impl<T> Cross<T> for RepeatCross<T> {
+    fn cross(&mut self, parents: &[&T]) -> Vec<T> {
+        let mut children = vec![];
+        for _ in 0..self.count {
+            children.append(&mut self.cross.cross(parents));
+        }
+
+        children
+    }
+
+    fn cross_with_goals(&mut self, parents: &[&T], goals: &[f64], objective: Objective) -> Vec<T> {
+        let mut children = vec![];
+        for _ in 0..self.count {
+            children.append(&mut self.cross.cross_with_goals(parents, goals, objective));
+        }
+
+        children
+    }
+}
+
 impl<G> VecCrossAllGenes<G> {
     pub fn new(single_cross: Box<dyn Cross<G>>) -> Self {
         Self { single_cross }
     }
 }
 
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let parent_1 = vec![1.0_f64, 0.0_f64];
+/// let parent_2 = vec![2.0_f64, 3.0_f64];
+/// let parent_3 = vec![6.0_f64, 0.0_f64];
+///
+/// let mut crosser = cross::VecCrossAllGenes::new(Box::new(cross::CrossMean::new()));
+/// assert_eq!(crosser.cross(&[&parent_1, &parent_2]), vec![vec![1.5_f64, 1.5_f64]]);
+/// assert_eq!(
+///     crosser.cross(&[&parent_1, &parent_2, &parent_3]),
+///     vec![vec![3.0_f64, 1.0_f64]]
+/// );
+/// ```
 impl<G> Cross<Vec<G>> for VecCrossAllGenes<G> {
     fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
-        assert!(parents.len() == 2);
+        assert!(parents.len() >= 2);
+
+        let gene_count = parents[0].len();
+        let mut child = vec![];
+
+        for n in 0..gene_count {
+            let genes: Vec<&G> = parents.iter().map(|parent| &parent[n]).collect();
+            let mut new_gene = self.single_cross.cross(genes.as_slice());
+            child.append(&mut new_gene);
+        }
+        vec![child]
+    }
 
-        let parent_1 = parents[0];
-        let parent_2 = parents[1];
+    /// The goals of the parents are passed to the inner cross algorithm for every gene.
+    fn cross_with_goals(
+        &mut self,
+        parents: &[&Vec<G>],
+        goals: &[f64],
+        objective: Objective,
+    ) -> Vec<Vec<G>> {
+        assert!(parents.len() >= 2);
 
-        let gene_count = parent_1.len();
+        let gene_count = parents[0].len();
         let mut child = vec![];
 
         for n in 0..gene_count {
-            let mut new_gene = self
-                .single_cross
-                .cross(vec![&parent_1[n], &parent_2[n]].as_slice());
+            let genes: Vec<&G> = parents.iter().map(|parent| &parent[n]).collect();
+            let mut new_gene =
+                self.single_cross
+                    .cross_with_goals(genes.as_slice(), goals, objective);
             child.append(&mut new_gene);
         }
         vec![child]
     }
 }
 
+/// Single point crossing of the chromosomes of type Vec<G>. The genes are not changed, the child
+/// gets the genes before the random point from the first parent and the genes after the point
+/// from the second parent. The struct may be used for any type of genes (for example, integer
+/// genes). Result of cross is single child.
+pub struct VecCrossSinglePoint {
+    random: ThreadRng,
+}
+
+impl VecCrossSinglePoint {
+    pub fn new() -> Self {
+        let random = rand::thread_rng();
+        Self { random }
+    }
+}
+
+impl Default for VecCrossSinglePoint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let parent_1 = vec![0_i64; 5];
+/// let parent_2 = vec![1_i64; 5];
+///
+/// let mut crosser = cross::VecCrossSinglePoint::new();
+/// let children = crosser.cross(&[&parent_1, &parent_2]);
+/// assert_eq!(children.len(), 1);
+///
+/// let child = &children[0];
+/// let pos = child.iter().position(|gene| *gene == 1).unwrap();
+/// assert!(pos > 0);
+/// assert!(child[..pos].iter().all(|gene| *gene == 0));
+/// assert!(child[pos..].iter().all(|gene| *gene == 1));
+/// ```
+impl<G: Clone> Cross<Vec<G>> for VecCrossSinglePoint {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        if gene_count < 2 {
+            return vec![parents[0].clone()];
+        }
+
+        let pos = Uniform::new(1, gene_count).sample(&mut self.random);
+        let mut child = parents[0][..pos].to_vec();
+        child.extend_from_slice(&parents[1][pos..]);
+
+        vec![child]
+    }
+}
+
+/// Two point crossing of the chromosomes of type Vec<G>. The genes are not changed, the child
+/// gets the genes between two random points from the second parent and other genes from the
+/// first parent. The struct may be used for any type of genes (for example, integer genes).
+/// Result of cross is single child.
+pub struct VecCrossTwoPoint {
+    random: ThreadRng,
+}
+
+impl VecCrossTwoPoint {
+    pub fn new() -> Self {
+        let random = rand::thread_rng();
+        Self { random }
+    }
+}
+
+impl Default for VecCrossTwoPoint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let parent_1 = vec![0_i64; 10];
+/// let parent_2 = vec![1_i64; 10];
+///
+/// let mut crosser = cross::VecCrossTwoPoint::new();
+/// for _ in 0..100 {
+///     let children = crosser.cross(&[&parent_1, &parent_2]);
+///     assert_eq!(children.len(), 1);
+///
+///     // The genes of the second parent are the single segment
+///     let child = &children[0];
+///     let start = child.iter().position(|gene| *gene == 1).unwrap();
+///     let end = start + child[start..].iter().take_while(|gene| **gene == 1).count();
+///     assert!(child[end..].iter().all(|gene| *gene == 0));
+/// }
+/// ```
+impl<G: Clone> Cross<Vec<G>> for VecCrossTwoPoint {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        if gene_count < 2 {
+            return vec![parents[0].clone()];
+        }
+
+        let between = Uniform::new(0, gene_count);
+        let pos_1 = between.sample(&mut self.random);
+        let mut pos_2 = between.sample(&mut self.random);
+        while pos_2 == pos_1 {
+            pos_2 = between.sample(&mut self.random);
+        }
+
+        let (start, end) = if pos_1 < pos_2 {
+            (pos_1, pos_2 + 1)
+        } else {
+            (pos_2, pos_1 + 1)
+        };
+
+        let mut child = parents[0].clone();
+        child[start..end].clone_from_slice(&parents[1][start..end]);
+
+        vec![child]
+    }
+}
+
+/// Order crossover (OX) for permutation chromosomes (for example, order of the cities in the
+/// traveling salesman problem). The random segment of the first parent is copied to the child,
+/// the remaining positions are filled by the genes of the second parent in their order (see
+/// `cross_order()`). The second child is created in the same way with swapped parents.
+/// Result of cross is two children.
+pub struct OrderCrossover {
+    random: ThreadRng,
+}
+
+impl OrderCrossover {
+    pub fn new() -> Self {
+        let random = rand::thread_rng();
+        Self { random }
+    }
+}
+
+impl Default for OrderCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ```
+/// use optlib::genetic::cross;
+/// use optlib::genetic::Cross;
+///
+/// let parent_1: Vec<usize> = (0..8).collect();
+/// let parent_2: Vec<usize> = (0..8).rev().collect();
+///
+/// let mut crosser = cross::OrderCrossover::new();
+/// for _ in 0..100 {
+///     let children = crosser.cross(&[&parent_1, &parent_2]);
+///     assert_eq!(children.len(), 2);
+///
+///     for child in children {
+///         let mut genes = child.clone();
+///         genes.sort();
+///         assert_eq!(genes, parent_1);
+///     }
+/// }
+/// ```
+impl<G: Clone + PartialEq> Cross<Vec<G>> for OrderCrossover {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        if gene_count < 2 {
+            return vec![parents[0].clone(), parents[1].clone()];
+        }
+
+        let between = Uniform::new(0, gene_count);
+        let pos_1 = between.sample(&mut self.random);
+        let pos_2 = between.sample(&mut self.random);
+        let (start, end) = if pos_1 < pos_2 {
+            (pos_1, pos_2 + 1)
+        } else {
+            (pos_2, pos_1 + 1)
+        };
+
+        vec![
+            cross_order(parents[0], parents[1], start, end),
+            cross_order(parents[1], parents[0], start, end),
+        ]
+    }
+}
+
 impl FloatCrossExp {
     pub fn new() -> Self {
         let random = rand::thread_rng();
@@ -383,6 +960,34 @@ pub fn cross_f32(parent_1: f32, parent_2: f32, pos: usize) -> f32 {
     f32::from_bits(child_bits)
 }
 
+/// Single point crossing for the half-precision floats. Available with the `half` feature.
+///
+/// # Parameters
+/// * `parent_1`, `parent_2` - parents for crossing.
+/// * `pos` - position for bytes exchange. The position is counted from right.
+///
+/// Returns single child.
+///
+/// # Examples
+///
+/// ```
+/// use half::f16;
+/// use optlib::genetic::cross;
+///
+/// let ones = f16::from_bits(std::u16::MAX);
+/// assert_eq!(cross::cross_f16(f16::from_bits(0), ones, 1).to_bits(), 0b_0001);
+/// assert_eq!(cross::cross_f16(f16::from_bits(0), ones, 4).to_bits(), 0b_1111);
+/// assert_eq!(cross::cross_f16(f16::from_bits(0), ones, 14).to_bits(), 0b_0011_1111_1111_1111);
+/// ```
+#[cfg(feature = "half")]
+pub fn cross_f16(parent_1: half::f16, parent_2: half::f16, pos: usize) -> half::f16 {
+    let parent_1_bits = parent_1.to_bits();
+    let parent_2_bits = parent_2.to_bits();
+
+    let child_bits = cross_u16(parent_1_bits, parent_2_bits, pos);
+    half::f16::from_bits(child_bits)
+}
+
 /// Single point crossing.
 ///
 /// # Parameters
@@ -408,3 +1013,427 @@ pub fn cross_f64(parent_1: f64, parent_2: f64, pos: usize) -> f64 {
     let child_bits = cross_u64(parent_1_bits, parent_2_bits, pos);
     f64::from_bits(child_bits)
 }
+
+/// Order crossing (OX) for permutation chromosomes.
+///
+/// # Parameters
+/// * `parent_1`, `parent_2` - parents for crossing. The parents must be permutations of the same
+///   genes.
+/// * `start`, `end` - the segment `[start; end)` of `parent_1` which is copied to the child.
+///
+/// The remaining positions of the child are filled by the genes of `parent_2` which are not in
+/// the segment. The genes are taken in the order of `parent_2` starting from `end` position and
+/// placed from `end` position (cyclically). Returns single child.
+///
+/// # Examples
+///
+/// ```
+/// use optlib::genetic::cross;
+///
+/// let parent_1 = [1, 2, 3, 4, 5, 6, 7, 8];
+/// let parent_2 = [8, 6, 4, 2, 7, 5, 3, 1];
+///
+/// assert_eq!(cross::cross_order(&parent_1, &parent_2, 2, 5), vec![2, 7, 3, 4, 5, 1, 8, 6]);
+/// assert_eq!(cross::cross_order(&parent_1, &parent_2, 0, 8), parent_1.to_vec());
+/// assert_eq!(cross::cross_order(&parent_1, &parent_2, 3, 3), parent_2.to_vec());
+/// ```
+pub fn cross_order<G: Clone + PartialEq>(
+    parent_1: &[G],
+    parent_2: &[G],
+    start: usize,
+    end: usize,
+) -> Vec<G> {
+    assert_eq!(parent_1.len(), parent_2.len());
+    assert!(start <= end && end <= parent_1.len());
+
+    let gene_count = parent_1.len();
+    let segment = &parent_1[start..end];
+
+    let mut child = parent_1.to_vec();
+    let mut pos = end % gene_count.max(1);
+    for n in 0..gene_count {
+        let gene = &parent_2[(end + n) % gene_count];
+        if !segment.contains(gene) {
+            child[pos] = gene.clone();
+            pos = (pos + 1) % gene_count;
+        }
+    }
+
+    child
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_parent_mean_two_parents() {
+        let parent_1 = vec![1.0_f64, -2.0, 4.0];
+        let parent_2 = vec![3.0_f64, 2.0, 5.0];
+
+        let mut crosser = MultiParentMean::new();
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2]),
+            vec![vec![2.0, 0.0, 4.5]]
+        );
+    }
+
+    #[test]
+    fn multi_parent_mean_many_parents() {
+        let parents: Vec<Vec<i32>> = (0..5).map(|i| vec![i * 2, 10, -i]).collect();
+        let parents_refs: Vec<&Vec<i32>> = parents.iter().collect();
+
+        let mut crosser = MultiParentMean::new();
+        assert_eq!(crosser.cross(&parents_refs), vec![vec![4, 10, -2]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn multi_parent_mean_single_parent() {
+        let parent = vec![1.0_f64];
+        MultiParentMean::new().cross(&[&parent]);
+    }
+
+    #[test]
+    fn vec_cross_all_genes_many_parents() {
+        let parent_1 = vec![1.0_f64, 0.0, 4.0];
+        let parent_2 = vec![2.0_f64, 3.0, 4.0];
+        let parent_3 = vec![6.0_f64, 0.0, 4.0];
+        let parent_4 = vec![3.0_f64, 1.0, 0.0];
+
+        let mut crosser = VecCrossAllGenes::new(Box::new(CrossMean::new()));
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2, &parent_3, &parent_4]),
+            vec![vec![3.0, 1.0, 3.0]]
+        );
+
+        // The result is the same as for MultiParentMean
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2, &parent_3]),
+            MultiParentMean::new().cross(&[&parent_1, &parent_2, &parent_3])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_cross_all_genes_single_parent() {
+        let parent = vec![1.0_f64];
+        let mut crosser = VecCrossAllGenes::new(Box::new(CrossMean::new()));
+        crosser.cross(&[&parent]);
+    }
+
+    #[test]
+    fn differential_cross_genes() {
+        let mut crosser = DifferentialCross::new(0.5);
+        assert_eq!(crosser.cross(&[&1.0_f64, &4.0_f64, &2.0_f64]), vec![2.0]);
+        assert_eq!(crosser.cross(&[&1.0_f64, &2.0_f64, &4.0_f64]), vec![0.0]);
+
+        let mut crosser = DifferentialCross::new(2.0);
+        assert_eq!(crosser.cross(&[&-1.0_f32, &0.5_f32, &0.0_f32]), vec![0.0]);
+
+        // The order of the parents matters
+        let mut crosser = DifferentialCross::new(1.0);
+        assert_eq!(crosser.cross(&[&3.0_f64, &1.0_f64, &1.0_f64]), vec![3.0]);
+        assert_eq!(crosser.cross(&[&1.0_f64, &3.0_f64, &1.0_f64]), vec![3.0]);
+        assert_eq!(crosser.cross(&[&1.0_f64, &1.0_f64, &3.0_f64]), vec![-1.0]);
+    }
+
+    #[test]
+    fn differential_cross_vec() {
+        let parent_1 = vec![1.0_f64, 0.0, -3.0];
+        let parent_2 = vec![2.0_f64, 3.0, 0.0];
+        let parent_3 = vec![6.0_f64, 1.0, 0.0];
+
+        let mut crosser = VecCrossAllGenes::new(Box::new(DifferentialCross::new(0.5)));
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2, &parent_3]),
+            vec![vec![-1.0, 1.0, -3.0]]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_cross_two_parents() {
+        let mut crosser = DifferentialCross::new(0.5);
+        crosser.cross(&[&1.0_f64, &2.0_f64]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_cross_four_parents() {
+        let mut crosser = DifferentialCross::new(0.5);
+        crosser.cross(&[&1.0_f64, &2.0_f64, &3.0_f64, &4.0_f64]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_cross_zero_factor() {
+        DifferentialCross::new(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_cross_large_factor() {
+        DifferentialCross::new(2.5);
+    }
+
+    #[test]
+    fn cross_order_segment() {
+        let parent_1 = [1, 2, 3, 4, 5, 6, 7, 8];
+        let parent_2 = [8, 6, 4, 2, 7, 5, 3, 1];
+
+        assert_eq!(
+            cross_order(&parent_1, &parent_2, 2, 5),
+            vec![2, 7, 3, 4, 5, 1, 8, 6]
+        );
+        assert_eq!(
+            cross_order(&parent_2, &parent_1, 2, 5),
+            vec![3, 5, 4, 2, 7, 6, 8, 1]
+        );
+    }
+
+    #[test]
+    fn cross_order_segment_at_end() {
+        let parent_1 = [1, 2, 3, 4, 5];
+        let parent_2 = [5, 4, 3, 2, 1];
+
+        // The filling starts from the first position
+        assert_eq!(cross_order(&parent_1, &parent_2, 3, 5), vec![3, 2, 1, 4, 5]);
+        assert_eq!(cross_order(&parent_1, &parent_2, 0, 1), vec![1, 4, 3, 2, 5]);
+    }
+
+    #[test]
+    fn cross_order_bounds() {
+        let parent_1 = [1, 2, 3, 4];
+        let parent_2 = [4, 2, 1, 3];
+
+        assert_eq!(cross_order(&parent_1, &parent_2, 0, 4), parent_1.to_vec());
+        assert_eq!(cross_order(&parent_1, &parent_2, 0, 0), parent_2.to_vec());
+        assert_eq!(cross_order(&parent_1, &parent_2, 4, 4), parent_2.to_vec());
+
+        let empty: [i32; 0] = [];
+        assert!(cross_order(&empty, &empty, 0, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_order_invalid_segment() {
+        cross_order(&[1, 2, 3], &[3, 2, 1], 2, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_order_different_length() {
+        cross_order(&[1, 2, 3], &[3, 2], 0, 1);
+    }
+
+    #[test]
+    fn order_crossover_permutations() {
+        let parent_1: Vec<usize> = (0..8).collect();
+        let parent_2 = vec![3, 7, 0, 5, 1, 6, 2, 4];
+        let mut crosser = OrderCrossover::new();
+
+        for _ in 0..100 {
+            let children = crosser.cross(&[&parent_1, &parent_2]);
+            assert_eq!(children.len(), 2);
+
+            // Every child is the result of cross_order() with some segment
+            let parents = [(&parent_1, &parent_2), (&parent_2, &parent_1)];
+            for (child, (first, second)) in children.iter().zip(parents.iter()) {
+                let mut genes = child.clone();
+                genes.sort_unstable();
+                assert_eq!(genes, parent_1);
+
+                assert!((0..8).any(|start| {
+                    (start + 1..=8).any(|end| cross_order(first, second, start, end) == *child)
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn order_crossover_short_chromosomes() {
+        let mut crosser = OrderCrossover::new();
+        let parent_1 = vec![1];
+        let parent_2 = vec![2];
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2]),
+            vec![parent_1.clone(), parent_2.clone()]
+        );
+
+        let empty: Vec<i32> = vec![];
+        assert_eq!(
+            crosser.cross(&[&empty, &empty]),
+            vec![empty.clone(), empty.clone()]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn order_crossover_single_parent() {
+        let parent = vec![1, 2, 3];
+        let mut crosser = OrderCrossover::new();
+        crosser.cross(&[&parent]);
+    }
+
+    #[test]
+    fn cross_with_goals_default() {
+        // CrossMean ignores the goals
+        let mut crosser = CrossMean::new();
+        let parents = [&1.0_f64, &3.0_f64];
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &[0.0, 10.0], Objective::Minimize),
+            vec![2.0]
+        );
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &[10.0, 0.0], Objective::Maximize),
+            vec![2.0]
+        );
+    }
+
+    #[test]
+    fn fitness_biased_cross_three_parents() {
+        let mut crosser = FitnessBiasedCross::new(0.75);
+        let parents = [&0.0_f64, &6.0_f64, &3.0_f64];
+        let goals = [5.0, 1.0, 3.0];
+
+        // The best parent is 6.0, mean of others is 1.5
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &goals, Objective::Minimize),
+            vec![4.875]
+        );
+
+        // The best parent is 0.0, mean of others is 4.5
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &goals, Objective::Maximize),
+            vec![1.125]
+        );
+    }
+
+    #[test]
+    fn fitness_biased_cross_bounds() {
+        let parents = [&2.0_f32, &-2.0_f32];
+        let goals = [3.0, 1.0];
+
+        let mut crosser = FitnessBiasedCross::new(1.0);
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &goals, Objective::Minimize),
+            vec![-2.0]
+        );
+
+        let mut crosser = FitnessBiasedCross::new(0.0);
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &goals, Objective::Minimize),
+            vec![2.0]
+        );
+    }
+
+    #[test]
+    fn fitness_biased_cross_nan_goal() {
+        // The parent with NaN goal is never the best
+        let mut crosser = FitnessBiasedCross::new(1.0);
+        let parents = [&1.0_f64, &2.0_f64];
+        assert_eq!(
+            crosser.cross_with_goals(&parents, &[f64::NAN, 5.0], Objective::Minimize),
+            vec![2.0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fitness_biased_cross_invalid_bias() {
+        FitnessBiasedCross::new(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fitness_biased_cross_goals_count() {
+        let mut crosser = FitnessBiasedCross::new(0.5);
+        crosser.cross_with_goals(&[&1.0_f64, &2.0_f64], &[1.0], Objective::Minimize);
+    }
+
+    #[test]
+    fn vec_cross_all_genes_with_goals() {
+        let parent_1 = vec![0.0_f64, 8.0, 1.0];
+        let parent_2 = vec![4.0_f64, 0.0, 1.0];
+        let mut crosser = VecCrossAllGenes::new(Box::new(FitnessBiasedCross::new(1.0)));
+
+        assert_eq!(
+            crosser.cross_with_goals(&[&parent_1, &parent_2], &[2.0, 1.0], Objective::Minimize),
+            vec![parent_2.clone()]
+        );
+        assert_eq!(
+            crosser.cross_with_goals(&[&parent_1, &parent_2], &[2.0, 1.0], Objective::Maximize),
+            vec![parent_1.clone()]
+        );
+    }
+
+    #[test]
+    fn bounded_cross_bitwise_per_gene_bounds() {
+        let intervals = vec![(-500.0_f64, 500.0_f64), (0.0, 1.0), (10.0, 10.5)];
+        let mut crosser = BoundedCrossBitwise::new(intervals.clone());
+        let parent_1 = vec![-420.0_f64, 0.1, 10.1];
+        let parent_2 = vec![420.0_f64, 0.9, 10.4];
+
+        for _ in 0..10000 {
+            let children = crosser.cross(&[&parent_1, &parent_2]);
+            assert_eq!(children.len(), 1);
+            assert_eq!(children[0].len(), intervals.len());
+            for (gene, (min, max)) in children[0].iter().zip(intervals.iter()) {
+                assert!(gene >= min && gene <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_cross_bitwise_equal_parents() {
+        let intervals = vec![(-1.0_f32, 1.0_f32), (5.0, 6.0)];
+        let mut crosser = BoundedCrossBitwise::new(intervals);
+        let parent = vec![0.25_f32, 5.5];
+
+        for _ in 0..100 {
+            let children = crosser.cross(&[&parent, &parent]);
+            assert_eq!(children, vec![parent.clone()]);
+        }
+    }
+
+    #[test]
+    fn bounded_cross_bitwise_reflect() {
+        let mut crosser = BoundedCrossBitwise::new(vec![(0.0_f64, 10.0_f64)]);
+
+        assert_eq!(crosser.reflect(5.0, 0.0, 10.0), 5.0);
+        assert_eq!(crosser.reflect(12.0, 0.0, 10.0), 8.0);
+        assert_eq!(crosser.reflect(-3.0, 0.0, 10.0), 3.0);
+        assert_eq!(crosser.reflect(23.0, 0.0, 10.0), 3.0);
+        assert_eq!(crosser.reflect(7.0, 2.0, 2.0), 2.0);
+
+        let random = crosser.reflect(f64::NAN, 0.0, 10.0);
+        assert!((0.0..=10.0).contains(&random));
+        let random = crosser.reflect(f64::INFINITY, 0.0, 10.0);
+        assert!((0.0..=10.0).contains(&random));
+    }
+
+    #[test]
+    fn bounded_cross_bitwise_out_of_bounds_parents() {
+        let intervals = vec![(0.0_f64, 1.0_f64), (-2.0, -1.0)];
+        let mut crosser = BoundedCrossBitwise::new(intervals.clone());
+        let parent = vec![1.5_f64, 3.0];
+
+        let children = crosser.cross(&[&parent, &parent]);
+        assert_eq!(children, vec![vec![0.5, -1.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounded_cross_bitwise_invalid_interval() {
+        BoundedCrossBitwise::new(vec![(0.0_f64, 1.0_f64), (2.0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounded_cross_bitwise_wrong_gene_count() {
+        let mut crosser = BoundedCrossBitwise::new(vec![(0.0_f64, 1.0_f64)]);
+        let parent = vec![0.5_f64, 0.5];
+        crosser.cross(&[&parent, &parent]);
+    }
+}
@@ -16,13 +16,19 @@ pub mod pre_birth;
 pub mod selection;
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::f64;
 use std::ops;
 use std::slice;
 
+use num::Float;
+
 use crate::tools::logging::Logger;
 use crate::tools::stopchecker::StopChecker;
-use crate::{Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Optimizer, Solution};
+use crate::{
+    Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Objective, Optimizer, Solution,
+    Tunable,
+};
 
 /// Struct for single point (agent) in the search space
 ///
@@ -37,6 +43,9 @@ pub struct Individual<T> {
 
     /// True if individual will pass to text generation.
     alive: bool,
+
+    /// True if chromosomes were changed after calculation of the fitness.
+    stale: bool,
 }
 
 impl<T: Clone> Clone for Individual<T> {
@@ -45,6 +54,7 @@ impl<T: Clone> Clone for Individual<T> {
             chromosomes: self.chromosomes.clone(),
             fitness: self.fitness,
             alive: self.alive,
+            stale: self.stale,
         }
     }
 }
@@ -65,6 +75,22 @@ impl<T> Individual<T> {
         &self.chromosomes
     }
 
+    /// Return mutable reference to chromosomes.
+    ///
+    /// The method may be used to repair the individual in place (for example, to clamp genes to
+    /// bounds). The individual does not store the goal function, so the fitness is not
+    /// recalculated and becomes stale after changing of the chromosomes.
+    /// `GeneticOptimizer` recalculates stale fitness before every selection and after the last
+    /// one. Use `Population::reevaluate()` to recalculate the fitness manually.
+    ///
+    /// The individual is marked as stale even if the chromosomes are not changed, so every call
+    /// costs a call of the goal function. Check the chromosomes with `get_chromosomes()` before
+    /// the call if the repair is not always needed.
+    pub fn get_chromosomes_mut(&mut self) -> &mut T {
+        self.stale = true;
+        &mut self.chromosomes
+    }
+
     /// Return value of the goal function.
     pub fn get_fitness(&self) -> f64 {
         self.fitness
@@ -98,9 +124,23 @@ pub struct Population<'a, T> {
 
     // Generation number.
     iteration: usize,
+
+    // Search minimum or maximum of the goal function.
+    objective: Objective,
 }
 
 impl<'a, T: Clone> Population<'a, T> {
+    /// Replace the goal function and recalculate fitness for all individuals.
+    fn set_goal(&mut self, goal: Box<dyn Goal<T> + 'a>) {
+        self.goal = goal;
+        self.goal.set_generation(self.iteration);
+        self.reevaluate();
+
+        self.best_individual = None;
+        self.worst_individual = None;
+        self.update_best_worst_individuals();
+    }
+
     /// Find new the best and the worst individuals
     fn update_best_worst_individuals(&mut self) {
         // Update the best individual
@@ -150,6 +190,7 @@ impl<'a, T> Population<'a, T> {
             best_individual: None,
             worst_individual: None,
             iteration: 0,
+            objective: Objective::Minimize,
         }
     }
 
@@ -168,6 +209,7 @@ impl<'a, T> Population<'a, T> {
             chromosomes,
             fitness,
             alive: true,
+            stale: false,
         };
 
         self.individuals.push(new_individual);
@@ -181,6 +223,46 @@ impl<'a, T> Population<'a, T> {
         }
     }
 
+    /// Create new individuals for all items in `chromosomes_list` and put them in place of the
+    /// worst individuals. Individuals with NaN fitness are replaced first.
+    fn replace_worst(&mut self, chromosomes_list: Vec<T>) {
+        let mut indexes: Vec<usize> = (0..self.individuals.len()).collect();
+        indexes
+            .sort_by(|&i, &j| self.individuals_min_cmp(&self.individuals[j], &self.individuals[i]));
+
+        for (index, chromosomes) in indexes.into_iter().zip(chromosomes_list) {
+            let fitness = self.goal.get(&chromosomes);
+            self.individuals[index] = Individual {
+                chromosomes,
+                fitness,
+                alive: true,
+                stale: false,
+            };
+        }
+    }
+
+    /// Recalculate fitness for all individuals with the goal function of the population.
+    pub fn reevaluate(&mut self) {
+        let goal = &mut self.goal;
+        for individual in self.individuals.iter_mut() {
+            individual.fitness = goal.get(&individual.chromosomes);
+            individual.stale = false;
+        }
+    }
+
+    /// Recalculate fitness for alive individuals which chromosomes were changed in place.
+    fn reevaluate_stale(&mut self) {
+        let goal = &mut self.goal;
+        for individual in self
+            .individuals
+            .iter_mut()
+            .filter(|individual| individual.stale && individual.alive)
+        {
+            individual.fitness = goal.get(&individual.chromosomes);
+            individual.stale = false;
+        }
+    }
+
     /// Returns iterator for all individuals (`Individual` struct) in population.
     pub fn iter(&self) -> slice::Iter<Individual<T>> {
         self.individuals.iter()
@@ -204,6 +286,35 @@ impl<'a, T> Population<'a, T> {
             .count()
     }
 
+    /// Returns all alive individuals which fitness differs from the best fitness of the alive
+    /// individuals not more than `tolerance`. The method may be used to find symmetric optima.
+    /// The individuals with NaN fitness are not returned.
+    pub fn get_all_best(&self, tolerance: f64) -> Vec<&Individual<T>> {
+        let objective = self.objective;
+        let candidates = self
+            .individuals
+            .iter()
+            .filter(|individual| individual.is_alive() && !individual.get_fitness().is_nan());
+
+        let best_fitness = candidates
+            .clone()
+            .map(|individual| individual.get_fitness())
+            .fold(f64::NAN, |best, fitness| {
+                if objective.is_better(fitness, best) {
+                    fitness
+                } else {
+                    best
+                }
+            });
+
+        candidates
+            .filter(|individual| {
+                let fitness = individual.get_fitness();
+                fitness == best_fitness || (fitness - best_fitness).abs() <= tolerance
+            })
+            .collect()
+    }
+
     /// Returns the best individual in the population if exists or None otherwise.
     pub fn get_best(&self) -> &Option<Individual<T>> {
         &self.best_individual
@@ -219,7 +330,21 @@ impl<'a, T> Population<'a, T> {
         self.individuals.len()
     }
 
-    /// Function to find individual with minimal fitness.
+    /// Returns the objective of the algorithm. Selection and pairing algorithms must use the
+    /// objective to compare fitness of the individuals.
+    pub fn get_objective(&self) -> Objective {
+        self.objective
+    }
+
+    /// Compare not NaN goal values. `Ordering::Less` means `goal_1` is better.
+    fn goals_cmp(&self, goal_1: f64, goal_2: f64) -> Ordering {
+        match self.objective {
+            Objective::Minimize => goal_1.partial_cmp(&goal_2).unwrap(),
+            Objective::Maximize => goal_2.partial_cmp(&goal_1).unwrap(),
+        }
+    }
+
+    /// Function to find the best individual (with minimal fitness for minimization).
     ///
     /// NaN fitness greater others.
     fn individuals_min_cmp(
@@ -237,11 +362,11 @@ impl<'a, T> Population<'a, T> {
         } else if goal_2.is_nan() {
             Ordering::Less
         } else {
-            goal_1.partial_cmp(&goal_2).unwrap()
+            self.goals_cmp(goal_1, goal_2)
         }
     }
 
-    /// Function to find individual with maximal fitness.
+    /// Function to find the worst individual (with maximal fitness for minimization).
     ///
     /// NaN fitness less others.
     fn individuals_max_cmp(
@@ -259,7 +384,7 @@ impl<'a, T> Population<'a, T> {
         } else if goal_2.is_nan() {
             Ordering::Greater
         } else {
-            goal_1.partial_cmp(&goal_2).unwrap()
+            self.goals_cmp(goal_1, goal_2)
         }
     }
 
@@ -271,6 +396,56 @@ impl<'a, T> Population<'a, T> {
     fn remove_dead(&mut self) {
         self.individuals.retain(|individual| individual.is_alive());
     }
+
+    /// Returns true if the population does not contain live individuals with finite fitness.
+    fn is_degenerate(&self) -> bool {
+        !self
+            .individuals
+            .iter()
+            .any(|individual| individual.is_alive() && individual.fitness.is_finite())
+    }
+
+    /// Forget the best individual if its fitness is not finite.
+    fn drop_non_finite_best(&mut self) {
+        let non_finite = match &self.best_individual {
+            None => false,
+            Some(individual) => !individual.fitness.is_finite(),
+        };
+
+        if non_finite {
+            self.best_individual = None;
+        }
+    }
+}
+
+impl<'a, G: Float> Population<'a, Vec<G>> {
+    /// Returns the centroid of the alive individuals: the average value of every gene.
+    /// Returns None if the population has no alive individuals. All chromosomes must have the
+    /// same length.
+    pub fn centroid(&self) -> Option<Vec<f64>> {
+        let mut alive = self
+            .individuals
+            .iter()
+            .filter(|individual| individual.is_alive());
+        let first = alive.next()?;
+
+        let mut sum: Vec<f64> = first
+            .chromosomes
+            .iter()
+            .map(|gene| gene.to_f64().unwrap())
+            .collect();
+        let mut count = 1;
+
+        for individual in alive {
+            assert_eq!(individual.chromosomes.len(), sum.len());
+            for (total, gene) in sum.iter_mut().zip(individual.chromosomes.iter()) {
+                *total += gene.to_f64().unwrap();
+            }
+            count += 1;
+        }
+
+        Some(sum.iter().map(|total| total / count as f64).collect())
+    }
 }
 
 /// Index trait implementation for Population
@@ -300,6 +475,25 @@ impl<'a, T: Clone> AlgorithmState<T> for Population<'a, T> {
     fn get_iteration(&self) -> usize {
         self.iteration
     }
+
+    fn get_worst_solution(&self) -> Option<(T, f64)> {
+        self.worst_individual
+            .as_ref()
+            .map(|individual| (individual.chromosomes.clone(), individual.fitness))
+    }
+
+    fn get_agent_list(&self) -> Option<Vec<&dyn Agent<T>>> {
+        Some(
+            self.individuals
+                .iter()
+                .map(|individual| individual as &dyn Agent<T>)
+                .collect(),
+        )
+    }
+
+    fn get_objective(&self) -> Objective {
+        self.objective
+    }
 }
 
 /// The trait to create initial individuals for population.
@@ -318,6 +512,19 @@ pub trait Cross<T> {
     /// must return vector of chromosomes of children. The children will be added to population
     /// after mutation.
     fn cross(&mut self, parents: &[&T]) -> Vec<T>;
+
+    /// The method accepts slice of references to parent chromosomes (`parents`) and values of
+    /// goal function for every parent (`goals`), so the cross algorithm may take into account
+    /// which parent is better for the `objective`. `GeneticOptimizer` calls this method.
+    /// By default the method ignores the goals and calls `cross()`.
+    fn cross_with_goals(
+        &mut self,
+        parents: &[&T],
+        _goals: &[f64],
+        _objective: Objective,
+    ) -> Vec<T> {
+        self.cross(parents)
+    }
 }
 
 /// The trait with mutation algorithm.
@@ -328,6 +535,22 @@ pub trait Mutation<T> {
     /// chromosomes (possibly modified). New individuals will be created with the chromosomes after
     /// mutation.
     fn mutation(&mut self, chromosomes: &T) -> T;
+
+    /// `GeneticOptimizer` calls the method before every iteration (and with 0 before creating of
+    /// the initial population) as `Goal::set_generation()`. The method may be used by adaptive
+    /// mutations (for example, `AnnealedBitwiseMutation`). The mutations with inner mutations
+    /// must pass the generation to the inner mutation.
+    fn set_generation(&mut self, _generation: usize) {}
+
+    /// Returns the tunable parameters of the mutation (see `Tunable`). The mutations with inner
+    /// mutations must return the parameters of the inner mutation too.
+    fn get_params(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// Set the tunable parameters of the mutation (see `Tunable`). The unknown names must be
+    /// ignored.
+    fn set_params(&mut self, _params: &HashMap<String, f64>) {}
 }
 
 /// The trait may be used after mutation but before birth of the individuals.
@@ -338,6 +561,14 @@ pub trait PreBirth<T> {
     fn pre_birth(&mut self, population: &Population<T>, new_chromosomes: &mut Vec<T>);
 }
 
+/// The implementation allows to pass a `PreBirth` to the optimizer by mutable reference
+/// (`Box::new(&mut pre_birth)`) and to use it after optimization.
+impl<T, P: PreBirth<T> + ?Sized> PreBirth<T> for &mut P {
+    fn pre_birth(&mut self, population: &Population<T>, new_chromosomes: &mut Vec<T>) {
+        (**self).pre_birth(population, new_chromosomes)
+    }
+}
+
 /// The trait with selection algorithm.
 ///
 /// `T` - type of a point in the search space for goal function (chromosomes).
@@ -345,6 +576,15 @@ pub trait Selection<T> {
     /// The method kills bad individuals. The method must call `Individual::kill()` method for
     /// individuals which will not go to next generation.
     fn kill(&mut self, population: &mut Population<T>);
+
+    /// Returns the tunable parameters of the selection (see `Tunable`).
+    fn get_params(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// Set the tunable parameters of the selection (see `Tunable`). The unknown names must be
+    /// ignored.
+    fn set_params(&mut self, _params: &HashMap<String, f64>) {}
 }
 
 /// The trait to select individuals to pairing.
@@ -356,11 +596,34 @@ pub trait Pairing<T> {
     fn get_pairs(&mut self, population: &Population<T>) -> Vec<Vec<usize>>;
 }
 
+/// The strategy to insert new individuals (children) to the population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Replacement {
+    /// All children are added to the population, then the selections kill individuals.
+    #[default]
+    Generational,
+
+    /// Only `replace_count` children are created every iteration (the families are crossed
+    /// until the children count is reached). The children replace the worst individuals
+    /// directly, then the selections kill individuals as in the generational mode. The
+    /// population size does not change if the selections don't kill individuals, so use the
+    /// selections which remove invalid individuals only (for example, `KillFitnessNaN`).
+    SteadyState { replace_count: usize },
+}
+
 /// The main struct for an user. `GeneticOptimizer` implements `Optimizer` trait and keep all parts
 /// of genetic algorithm as trait objects: `Creator`, `Pairing`, `Cross`, `Mutation`, `Selection`,
 /// `StopChecker` and, if needed, `Logger`.
 /// The trait run genetic algorithm.
 ///
+/// The algorithm stops regardless of the stop checker if the population does not contain
+/// individuals with finite fitness (all individuals were killed or the goal function returns
+/// NaN or infinity for all individuals). In this case the algorithm returns None, the loggers
+/// get the state without the best solution and `GeneticOptimizer::is_population_degenerate()`
+/// returns true. The infinite fitness is treated as degenerate too, because such values are
+/// usually caused by the overflow of the goal function and the individuals with equal infinite
+/// fitness can't be ranked by the selections.
+///
 /// `T` - type of a point in the search space for goal function (chromosomes).
 pub struct GeneticOptimizer<'a, T> {
     stop_checker: Box<dyn StopChecker<T> + 'a>,
@@ -372,6 +635,7 @@ pub struct GeneticOptimizer<'a, T> {
     pre_births: Vec<Box<dyn PreBirth<T> + 'a>>,
     loggers: Vec<Box<dyn Logger<T> + 'a>>,
     population: Population<'a, T>,
+    replacement: Replacement,
 }
 
 impl<'a, T: Clone> GeneticOptimizer<'a, T> {
@@ -396,6 +660,7 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
             pre_births,
             loggers: vec![],
             population: Population::new(goal),
+            replacement: Replacement::Generational,
         }
     }
 
@@ -403,6 +668,38 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
         self.loggers = loggers;
     }
 
+    /// Returns the current population. The method may be used to read the individuals between
+    /// iterations.
+    pub fn get_population(&self) -> &Population<'a, T> {
+        &self.population
+    }
+
+    /// Returns true if the population does not contain live individuals with finite fitness.
+    /// The method may be used after `find_min()` to check if the algorithm was stopped because of
+    /// the degenerate population rather than by the stop checker.
+    pub fn is_population_degenerate(&self) -> bool {
+        self.population.is_degenerate()
+    }
+
+    /// Set the objective of the algorithm. The algorithm searches minimum of the goal function by
+    /// default.
+    pub fn set_objective(&mut self, objective: Objective) {
+        self.population.objective = objective;
+    }
+
+    /// Replace the goal function (for example, for dynamic optimization problems). Fitness of all
+    /// individuals in the current population is recalculated with the new goal function, so the
+    /// algorithm may be continued by `IterativeOptimizer::next_iterations()`.
+    pub fn set_goal(&mut self, goal: Box<dyn Goal<T> + 'a>) {
+        self.population.set_goal(goal);
+    }
+
+    /// Set the strategy to insert children to the population. `Replacement::Generational` is
+    /// used by default.
+    pub fn set_replacement(&mut self, replacement: Replacement) {
+        self.replacement = replacement;
+    }
+
     /// Replace the trait object of pairing algorithm.
     pub fn set_pairing(&mut self, pairing: Box<dyn Pairing<T>>) {
         self.pairing = pairing;
@@ -424,7 +721,7 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
     }
 
     /// Replace the trait object of selection algorithm.
-    pub fn set_pre_birth(&mut self, pre_births: Vec<Box<dyn PreBirth<T>>>) {
+    pub fn set_pre_birth(&mut self, pre_births: Vec<Box<dyn PreBirth<T> + 'a>>) {
         self.pre_births = pre_births;
     }
 
@@ -433,63 +730,102 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
         self.stop_checker = stop_checker;
     }
 
-    fn run_pairing(&mut self) -> Vec<T> {
+    /// Cross the families from the pairing. If `max_children` is not None, the families are
+    /// crossed until the children count is reached and extra children are dropped.
+    fn run_pairing(&mut self, max_children: Option<usize>) -> Vec<T> {
         let pairs: Vec<Vec<usize>> = self.pairing.get_pairs(&self.population);
         let mut new_chromosomes: Vec<T> = Vec::with_capacity(pairs.len());
 
+        let objective = self.population.get_objective();
         for pair in pairs {
+            if max_children.is_some_and(|max_children| new_chromosomes.len() >= max_children) {
+                break;
+            }
+
             let mut cross_chromosomes = Vec::with_capacity(pair.len());
+            let mut cross_goals = Vec::with_capacity(pair.len());
             for i in pair {
                 cross_chromosomes.push(self.population[i].get_chromosomes());
+                cross_goals.push(self.population[i].get_fitness());
             }
 
-            let mut child_chromosomes = self.cross.cross(&cross_chromosomes);
+            let mut child_chromosomes =
+                self.cross
+                    .cross_with_goals(&cross_chromosomes, &cross_goals, objective);
             new_chromosomes.append(&mut child_chromosomes);
         }
 
+        if let Some(max_children) = max_children {
+            new_chromosomes.truncate(max_children);
+        }
+
         new_chromosomes
     }
-}
 
-impl<'a, T: Clone> IterativeOptimizer<T> for GeneticOptimizer<'a, T> {
-    /// Do new iterations of genetic algorithm.
-    fn next_iterations(&mut self) -> Option<Solution<T>> {
-        for logger in &mut self.loggers {
-            logger.resume(&self.population);
-        }
+    /// Run single iteration of genetic algorithm and call the loggers.
+    fn do_iteration(&mut self) {
+        let iteration = self.population.iteration;
+        self.population.goal.set_generation(iteration);
+        self.mutation.set_generation(iteration);
 
-        while !self.stop_checker.can_stop(&self.population) {
-            // Pairing
-            let mut children_chromo_list = self.run_pairing();
+        // Pairing
+        let max_children = match self.replacement {
+            Replacement::Generational => None,
+            Replacement::SteadyState { replace_count } => Some(replace_count),
+        };
+        let mut children_chromo_list = self.run_pairing(max_children);
 
-            // Mutation
-            let mut children_mutants: Vec<T> = children_chromo_list
-                .iter_mut()
-                .map(|chromo| self.mutation.mutation(chromo))
-                .collect();
+        // Mutation
+        let mut children_mutants: Vec<T> = children_chromo_list
+            .iter_mut()
+            .map(|chromo| self.mutation.mutation(chromo))
+            .collect();
+
+        // May be change new chromosomes vector before birth
+        for pre_birth in &mut self.pre_births {
+            pre_birth.pre_birth(&self.population, &mut children_mutants);
+        }
 
-            // May be change new chromosomes vector before birth
-            for pre_birth in &mut self.pre_births {
-                pre_birth.pre_birth(&self.population, &mut children_mutants);
+        // Create new individuals by new chromosomes and add new individuals to population
+        match self.replacement {
+            Replacement::Generational => self.population.append(children_mutants),
+            Replacement::SteadyState { .. } => {
+                self.population.reevaluate_stale();
+                self.population.replace_worst(children_mutants);
             }
+        }
 
-            // Create new individuals by new chromosomes and add new individuals to population
-            self.population.append(children_mutants);
+        // Selection. Selections may repair individuals in place, so the next selection must
+        // rank the individuals by the recalculated fitness.
+        for selection in &mut self.selections {
+            self.population.reevaluate_stale();
+            selection.kill(&mut self.population);
+        }
 
-            // Selection
-            for selection in &mut self.selections {
-                selection.kill(&mut self.population);
-            }
+        self.population.remove_dead();
+        self.population.reevaluate_stale();
+
+        self.population.update_best_worst_individuals();
 
-            self.population.remove_dead();
+        self.population.next_iteration();
 
-            self.population.update_best_worst_individuals();
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.population);
+        }
+    }
 
-            self.population.next_iteration();
+    /// Returns true if the iterations limit `max_iterations` is reached.
+    fn is_budget_spent(&self, max_iterations: Option<usize>) -> bool {
+        match max_iterations {
+            None => false,
+            Some(max_iterations) => self.population.get_iteration() >= max_iterations,
+        }
+    }
 
-            for logger in &mut self.loggers {
-                logger.next_iteration(&self.population);
-            }
+    /// Call the loggers in the end of the running and return the best solution.
+    fn finish_iterations(&mut self) -> Option<Solution<T>> {
+        if self.population.is_degenerate() {
+            self.population.drop_non_finite_best();
         }
 
         for logger in &mut self.loggers {
@@ -501,12 +837,14 @@ impl<'a, T: Clone> IterativeOptimizer<T> for GeneticOptimizer<'a, T> {
             Some(individual) => Some((individual.chromosomes.clone(), individual.fitness)),
         }
     }
-}
 
-impl<'a, T: Clone> Optimizer<T> for GeneticOptimizer<'a, T> {
-    /// Run genetic algorithm
-    fn find_min(&mut self) -> Option<(T, f64)> {
+    /// Create the initial population and run the algorithm with the iterations limit if
+    /// `max_iterations` is not None.
+    fn run(&mut self, max_iterations: Option<usize>) -> Option<Solution<T>> {
         self.population.reset();
+        self.stop_checker.reset();
+        self.population.goal.set_generation(0);
+        self.mutation.set_generation(0);
         let start_chromo_list = self.creator.create();
 
         // Create individuals from chromosomes
@@ -516,6 +854,772 @@ impl<'a, T: Clone> Optimizer<T> for GeneticOptimizer<'a, T> {
             logger.start(&self.population);
         }
 
-        self.next_iterations()
+        self.run_iterations(max_iterations)
+    }
+
+    /// Do new iterations until the stop checker fires or the iterations limit `max_iterations`
+    /// is reached.
+    fn run_iterations(&mut self, max_iterations: Option<usize>) -> Option<Solution<T>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.population);
+        }
+
+        while !self.population.is_degenerate()
+            && !self.stop_checker.can_stop(&self.population)
+            && !self.is_budget_spent(max_iterations)
+        {
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+
+    /// Do `n` iterations of genetic algorithm regardless of the stop checker. The algorithm
+    /// stops earlier if the population is degenerate.
+    pub fn next_n_iterations(&mut self, n: usize) -> Option<Solution<T>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.population);
+        }
+
+        for _ in 0..n {
+            if self.population.is_degenerate() {
+                break;
+            }
+
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+}
+
+impl<'a, T: Clone> IterativeOptimizer<T> for GeneticOptimizer<'a, T> {
+    /// Do new iterations of genetic algorithm.
+    fn next_iterations(&mut self) -> Option<Solution<T>> {
+        self.run_iterations(None)
+    }
+}
+
+/// The parameters are collected from the mutation and the selections. For example,
+/// `VecMutation` has "mutation_probability" parameter and `LimitPopulation` has
+/// "population_size" parameter.
+impl<'a, T: Clone> Tunable for GeneticOptimizer<'a, T> {
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = self.mutation.get_params();
+        for selection in &self.selections {
+            params.extend(selection.get_params());
+        }
+
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        self.mutation.set_params(params);
+        for selection in &mut self.selections {
+            selection.set_params(params);
+        }
+    }
+}
+
+impl<'a, T: Clone> Optimizer<T> for GeneticOptimizer<'a, T> {
+    /// Run genetic algorithm
+    fn find_min(&mut self) -> Option<(T, f64)> {
+        self.run(None)
+    }
+
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<T>> {
+        self.run(Some(max_iterations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::selection::KillFitnessNaN;
+    use crate::GoalFromFunction;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Goal function is not defined outside of [0; 1] interval.
+    struct UnitCubeGoal;
+
+    impl Goal<Vec<f64>> for UnitCubeGoal {
+        fn get(&mut self, x: &Vec<f64>) -> f64 {
+            if x.iter().all(|xi| (0.0..=1.0).contains(xi)) {
+                x.iter().sum()
+            } else {
+                f64::NAN
+            }
+        }
+    }
+
+    /// Repair operator which clamps genes to [0; 1] and kills individuals with NaN fitness.
+    struct ClampRepair {
+        kill_nan: KillFitnessNaN,
+    }
+
+    impl Selection<Vec<f64>> for ClampRepair {
+        fn kill(&mut self, population: &mut Population<Vec<f64>>) {
+            for individual in population.iter_mut() {
+                for gene in individual.get_chromosomes_mut().iter_mut() {
+                    *gene = gene.clamp(0.0, 1.0);
+                }
+            }
+
+            self.kill_nan.kill(population);
+        }
+    }
+
+    /// Repair operator which clamps genes to [0; 1] without killing.
+    struct ClampOnly;
+
+    impl Selection<Vec<f64>> for ClampOnly {
+        fn kill(&mut self, population: &mut Population<Vec<f64>>) {
+            for individual in population.iter_mut() {
+                for gene in individual.get_chromosomes_mut().iter_mut() {
+                    *gene = gene.clamp(0.0, 1.0);
+                }
+            }
+        }
+    }
+
+    /// The selection checks that the fitness of the alive individuals is not stale.
+    struct CheckNotStale {
+        calls: usize,
+    }
+
+    impl Selection<Vec<f64>> for &mut CheckNotStale {
+        fn kill(&mut self, population: &mut Population<Vec<f64>>) {
+            self.calls += 1;
+            for individual in population.iter().filter(|individual| individual.is_alive()) {
+                assert!(!individual.stale);
+                assert_eq!(
+                    individual.get_fitness(),
+                    UnitCubeGoal.get(individual.get_chromosomes())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reevaluate_stale_before_selection() {
+        let mut check = CheckNotStale { calls: 0 };
+        {
+            let mut optimizer = create_optimizer(
+                Box::new(UnitCubeGoal),
+                vec![Box::new(ClampOnly), Box::new(&mut check)],
+            );
+            optimizer.find_min_with_budget(3);
+
+            for individual in optimizer.get_population().iter() {
+                assert!(!individual.stale);
+            }
+        }
+
+        assert_eq!(check.calls, 3);
+    }
+
+    /// The cross checks that the optimizer passes the goals of the parents.
+    struct CheckGoalsCross {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Cross<Vec<f64>> for CheckGoalsCross {
+        fn cross(&mut self, _parents: &[&Vec<f64>]) -> Vec<Vec<f64>> {
+            panic!("cross_with_goals() must be called");
+        }
+
+        fn cross_with_goals(
+            &mut self,
+            parents: &[&Vec<f64>],
+            goals: &[f64],
+            objective: Objective,
+        ) -> Vec<Vec<f64>> {
+            self.calls.set(self.calls.get() + 1);
+            assert_eq!(objective, Objective::Minimize);
+            assert_eq!(parents.len(), goals.len());
+            for (parent, goal) in parents.iter().zip(goals) {
+                let expected = UnitCubeGoal.get(parent);
+                assert!(*goal == expected || (goal.is_nan() && expected.is_nan()));
+            }
+
+            vec![parents[0].clone()]
+        }
+    }
+
+    #[test]
+    fn cross_with_parent_goals() {
+        let calls = Rc::new(Cell::new(0));
+        let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), vec![]);
+        optimizer.set_cross(Box::new(CheckGoalsCross {
+            calls: Rc::clone(&calls),
+        }));
+        optimizer.find_min_with_budget(3);
+
+        assert!(calls.get() > 0);
+    }
+
+    #[test]
+    fn get_all_best() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        assert!(population.get_all_best(1e-6).is_empty());
+
+        population.append(vec![
+            vec![0.5, 0.5],
+            vec![0.2, 0.3],
+            vec![0.1, 0.4],
+            vec![0.26, 0.25],
+            vec![0.3, 0.2],
+            vec![2.0, 0.0],
+            vec![0.25, 0.2500001],
+        ]);
+        population[2].kill();
+
+        let best: Vec<&Vec<f64>> = population
+            .get_all_best(1e-6)
+            .iter()
+            .map(|individual| individual.get_chromosomes())
+            .collect();
+        assert_eq!(
+            best,
+            vec![&vec![0.2, 0.3], &vec![0.3, 0.2], &vec![0.25, 0.2500001]]
+        );
+
+        population.objective = Objective::Maximize;
+        assert_eq!(population.get_all_best(1e-6).len(), 1);
+        assert_eq!(
+            population.get_all_best(1e-6)[0].get_chromosomes(),
+            &vec![0.5, 0.5]
+        );
+    }
+
+    #[test]
+    fn centroid() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        assert_eq!(population.centroid(), None);
+
+        population.append(vec![
+            vec![0.0, 1.0, 0.5],
+            vec![1.0, 0.0, 0.5],
+            vec![0.5, 0.5, 0.2],
+            vec![10.0, 10.0, 10.0],
+        ]);
+        population[3].kill();
+
+        let centroid = population.centroid().unwrap();
+        let expected = [0.5, 0.5, 0.4];
+        assert_eq!(centroid.len(), expected.len());
+        for (value, expected_value) in centroid.iter().zip(expected.iter()) {
+            assert!((value - expected_value).abs() < 1e-10);
+        }
+
+        for n in 0..3 {
+            population[n].kill();
+        }
+        assert_eq!(population.centroid(), None);
+    }
+
+    #[test]
+    fn chromosomes_mut_repair() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        population.append(vec![vec![0.5, 0.5], vec![-1.0, 0.5], vec![0.2, 2.0]]);
+
+        let mut repair = ClampRepair {
+            kill_nan: KillFitnessNaN::new(),
+        };
+        repair.kill(&mut population);
+
+        assert_eq!(population[0].get_chromosomes(), &vec![0.5, 0.5]);
+        assert_eq!(population[1].get_chromosomes(), &vec![0.0, 0.5]);
+        assert_eq!(population[2].get_chromosomes(), &vec![0.2, 1.0]);
+
+        // Fitness is stale after the repair
+        assert_eq!(population[0].get_fitness(), 1.0);
+        assert!(population[1].get_fitness().is_nan());
+
+        assert!(population[0].is_alive());
+        assert!(!population[1].is_alive());
+        assert!(!population[2].is_alive());
+
+        population.remove_dead();
+        assert_eq!(population.len(), 1);
+    }
+
+    #[test]
+    fn get_population() {
+        use crate::genetic::creation::vec_float::RandomCreator;
+        use crate::genetic::cross::{CrossMean, VecCrossAllGenes};
+        use crate::genetic::mutation::{BitwiseMutation, VecMutation};
+        use crate::genetic::pairing::RandomPairing;
+        use crate::genetic::selection::LimitPopulation;
+        use crate::tools::stopchecker::MaxIterations;
+
+        let population_size = 20;
+        let intervals = vec![(0.0, 1.0); 3];
+        let selections: Vec<Box<dyn Selection<Vec<f64>>>> = vec![
+            Box::new(KillFitnessNaN::new()),
+            Box::new(LimitPopulation::new(population_size)),
+        ];
+
+        let mut optimizer = GeneticOptimizer::new(
+            Box::new(UnitCubeGoal),
+            Box::new(MaxIterations::new(5)),
+            Box::new(RandomCreator::new(population_size, intervals)),
+            Box::new(RandomPairing::new()),
+            Box::new(VecCrossAllGenes::new(Box::new(CrossMean::new()))),
+            Box::new(VecMutation::new(10.0, Box::new(BitwiseMutation::new(1)))),
+            selections,
+            vec![],
+        );
+
+        let result = optimizer.find_min().unwrap();
+        let population = optimizer.get_population();
+
+        assert_eq!(population.get_iteration(), 5);
+        assert_eq!(population.len(), population_size);
+
+        let best = population.get_best().as_ref().unwrap();
+        assert_eq!(best.get_chromosomes(), &result.0);
+        assert_eq!(best.get_fitness(), result.1);
+        assert_eq!(population.get_best_solution(), Some(result));
+    }
+
+    #[test]
+    fn get_worst_solution() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        assert_eq!(population.get_worst_solution(), None);
+
+        population.append(vec![vec![0.5, 0.5], vec![0.75, 0.5], vec![0.25, 0.125]]);
+        population.update_best_worst_individuals();
+
+        assert_eq!(population.get_worst_solution(), Some((vec![0.75, 0.5], 1.25)));
+        assert_eq!(population.get_best_solution(), Some((vec![0.25, 0.125], 0.375)));
+    }
+
+    #[test]
+    fn reevaluate() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        population.append(vec![vec![0.5, 0.5], vec![0.1, 0.2]]);
+
+        *population[0].get_chromosomes_mut() = vec![0.25, 0.5];
+        assert_eq!(population[0].get_fitness(), 1.0);
+
+        population.reevaluate();
+        assert_eq!(population[0].get_fitness(), UnitCubeGoal.get(&vec![0.25, 0.5]));
+        assert_eq!(population[0].get_fitness(), 0.75);
+        assert!((population[1].get_fitness() - 0.3).abs() < 1e-10);
+    }
+
+    #[test]
+    fn reevaluate_stale() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        population.append(vec![vec![0.5, 0.5], vec![0.1, 0.2]]);
+
+        population[1].get_chromosomes_mut()[0] = 0.7;
+        population.reevaluate_stale();
+
+        assert_eq!(population[0].get_fitness(), 1.0);
+        assert!((population[1].get_fitness() - 0.9).abs() < 1e-10);
+        assert!(!population[1].stale);
+    }
+
+    /// Selection which kills all individuals.
+    struct KillAll;
+
+    impl<T: Clone> Selection<T> for KillAll {
+        fn kill(&mut self, population: &mut Population<T>) {
+            for individual in population.iter_mut() {
+                individual.kill();
+            }
+        }
+    }
+
+    fn create_optimizer<'a>(
+        goal: Box<dyn Goal<Vec<f64>> + 'a>,
+        selections: Vec<Box<dyn Selection<Vec<f64>> + 'a>>,
+    ) -> GeneticOptimizer<'a, Vec<f64>> {
+        use crate::genetic::creation::vec_float::RandomCreator;
+        use crate::genetic::cross::{CrossMean, VecCrossAllGenes};
+        use crate::genetic::mutation::{BitwiseMutation, VecMutation};
+        use crate::genetic::pairing::RandomPairing;
+        use crate::tools::stopchecker::MaxIterations;
+
+        GeneticOptimizer::new(
+            goal,
+            Box::new(MaxIterations::new(1000000)),
+            Box::new(RandomCreator::new(20, vec![(0.0, 1.0); 3])),
+            Box::new(RandomPairing::new()),
+            Box::new(VecCrossAllGenes::new(Box::new(CrossMean::new()))),
+            Box::new(VecMutation::new(10.0, Box::new(BitwiseMutation::new(1)))),
+            selections,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn set_goal() {
+        let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), vec![]);
+        optimizer.find_min_with_budget(3);
+
+        // The minimum is shifted
+        let shifted = |x: &Vec<f64>| x.iter().map(|xi| (xi - 0.5) * (xi - 0.5)).sum::<f64>();
+        optimizer.set_goal(Box::new(GoalFromFunction::new(shifted)));
+
+        let population = optimizer.get_population();
+        for individual in population.iter() {
+            assert_eq!(
+                individual.get_fitness(),
+                shifted(individual.get_chromosomes())
+            );
+        }
+
+        let best_fitness = population
+            .iter()
+            .map(|individual| individual.get_fitness())
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(
+            population.get_best().as_ref().unwrap().get_fitness(),
+            best_fitness
+        );
+
+        optimizer.next_n_iterations(2);
+        let (solution, goal_value) = optimizer.get_population().get_best_solution().unwrap();
+        assert_eq!(goal_value, shifted(&solution));
+    }
+
+    #[test]
+    fn tunable() {
+        use crate::genetic::selection::LimitPopulation;
+
+        let mut optimizer = create_optimizer(
+            Box::new(UnitCubeGoal),
+            vec![Box::new(LimitPopulation::new(20))],
+        );
+
+        let params = optimizer.get_params();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params["mutation_probability"], 10.0);
+        assert_eq!(params["population_size"], 20.0);
+
+        let mut new_params = HashMap::new();
+        new_params.insert("population_size".to_string(), 7.0);
+        new_params.insert("unknown".to_string(), 1.0);
+        optimizer.set_params(&new_params);
+
+        let params = optimizer.get_params();
+        assert_eq!(params["mutation_probability"], 10.0);
+        assert_eq!(params["population_size"], 7.0);
+
+        optimizer.find_min_with_budget(3);
+        assert_eq!(optimizer.get_population().len(), 7);
+    }
+
+    #[test]
+    fn stop_if_goal_nan() {
+        let goal = GoalFromFunction::new(|_x: &Vec<f64>| f64::NAN);
+        let mut optimizer = create_optimizer(Box::new(goal), vec![]);
+
+        assert_eq!(optimizer.find_min(), None);
+        assert_eq!(optimizer.get_population().get_iteration(), 0);
+        assert_eq!(optimizer.get_population().get_best_solution(), None);
+        assert!(optimizer.is_population_degenerate());
+    }
+
+    #[test]
+    fn stop_if_population_empty() {
+        let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), vec![Box::new(KillAll)]);
+
+        assert_eq!(optimizer.find_min(), None);
+        assert_eq!(optimizer.get_population().get_iteration(), 1);
+        assert_eq!(optimizer.get_population().len(), 0);
+        assert!(optimizer.is_population_degenerate());
+    }
+
+    #[test]
+    fn stop_if_goal_infinite() {
+        let goal = GoalFromFunction::new(|_x: &Vec<f64>| f64::INFINITY);
+        let mut optimizer = create_optimizer(Box::new(goal), vec![]);
+
+        assert_eq!(optimizer.find_min(), None);
+        assert_eq!(optimizer.get_population().get_iteration(), 0);
+        assert!(optimizer.is_population_degenerate());
+    }
+
+    #[test]
+    fn maximize() {
+        use crate::genetic::selection::LimitPopulation;
+        use crate::tools::stopchecker::MaxIterations;
+
+        let goal = GoalFromFunction::new(|x: &Vec<f64>| {
+            1.0 - x.iter().map(|xi| (xi - 0.5) * (xi - 0.5)).sum::<f64>()
+        });
+        let selections: Vec<Box<dyn Selection<Vec<f64>>>> = vec![
+            Box::new(KillFitnessNaN::new()),
+            Box::new(LimitPopulation::new(20)),
+        ];
+        let mut optimizer = create_optimizer(Box::new(goal), selections);
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(50)));
+        optimizer.set_objective(Objective::Maximize);
+
+        let (solution, goal) = optimizer.find_min().unwrap();
+        let population = optimizer.get_population();
+
+        assert_eq!(population.get_objective(), Objective::Maximize);
+        assert_eq!(population.len(), 20);
+        assert!(goal > 0.95);
+        assert!(solution.iter().all(|xi| (xi - 0.5).abs() < 0.25));
+        for individual in population.iter() {
+            assert!(individual.get_fitness() <= goal);
+        }
+        assert!(population.get_worst_solution().unwrap().1 <= goal);
+    }
+
+    #[test]
+    fn best_worst_maximize() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        population.objective = Objective::Maximize;
+        population.append(vec![vec![0.5, 0.5], vec![0.75, 0.5], vec![2.0, 0.0]]);
+        population.update_best_worst_individuals();
+
+        assert_eq!(
+            population.get_best_solution(),
+            Some((vec![0.75, 0.5], 1.25))
+        );
+        assert_eq!(population.get_worst_solution(), Some((vec![0.5, 0.5], 1.0)));
+    }
+
+    #[test]
+    fn replace_worst() {
+        let mut population = Population::new(Box::new(UnitCubeGoal));
+        population.append(vec![
+            vec![0.5, 0.5],
+            vec![2.0, 0.0],
+            vec![0.25, 0.0],
+            vec![1.0, 1.0],
+        ]);
+        population.replace_worst(vec![vec![0.0, 0.0], vec![0.0, 0.5]]);
+
+        let chromosomes: Vec<Vec<f64>> = population
+            .iter()
+            .map(|individual| individual.get_chromosomes().clone())
+            .collect();
+        assert_eq!(
+            chromosomes,
+            vec![
+                vec![0.5, 0.5],
+                vec![0.0, 0.0],
+                vec![0.25, 0.0],
+                vec![0.0, 0.5]
+            ]
+        );
+    }
+
+    /// The logger saves chromosomes of all individuals after every iteration.
+    struct ChromosomesLogger<'a> {
+        history: &'a mut Vec<Vec<Vec<f64>>>,
+    }
+
+    impl<'a> Logger<Vec<f64>> for ChromosomesLogger<'a> {
+        fn next_iteration(&mut self, state: &dyn AlgorithmState<Vec<f64>>) {
+            let chromosomes = state
+                .get_agent_list()
+                .unwrap()
+                .iter()
+                .map(|agent| agent.get_parameter().clone())
+                .collect();
+            self.history.push(chromosomes);
+        }
+    }
+
+    #[test]
+    fn steady_state() {
+        use crate::tools::stopchecker::MaxIterations;
+
+        let replace_count = 3;
+        let mut history = vec![];
+
+        {
+            let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), vec![]);
+            optimizer.set_stop_checker(Box::new(MaxIterations::new(30)));
+            optimizer.set_replacement(Replacement::SteadyState { replace_count });
+            optimizer.set_loggers(vec![Box::new(ChromosomesLogger {
+                history: &mut history,
+            })]);
+
+            assert!(optimizer.find_min().is_some());
+            assert_eq!(optimizer.get_population().len(), 20);
+        }
+
+        assert_eq!(history.len(), 30);
+        for (previous, current) in history.iter().zip(history.iter().skip(1)) {
+            assert_eq!(current.len(), 20);
+            let changed = previous
+                .iter()
+                .zip(current.iter())
+                .filter(|(chromo_1, chromo_2)| chromo_1 != chromo_2)
+                .count();
+            assert!(changed <= replace_count);
+        }
+        assert_ne!(history.first(), history.last());
+    }
+
+    #[test]
+    fn steady_state_selections() {
+        use crate::tools::stopchecker::MaxIterations;
+
+        // The mutation moves the children outside the unit cube often, the goal function is NaN
+        // for such children.
+        let selections: Vec<Box<dyn Selection<Vec<f64>>>> = vec![Box::new(KillFitnessNaN::new())];
+        let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), selections);
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(30)));
+        optimizer.set_replacement(Replacement::SteadyState { replace_count: 3 });
+
+        assert!(optimizer.find_min().is_some());
+        assert!(optimizer.get_population().len() <= 20);
+        assert!(optimizer
+            .get_population()
+            .iter()
+            .all(|individual| !individual.get_fitness().is_nan()));
+    }
+
+    #[test]
+    fn steady_state_cross_count() {
+        use crate::genetic::cross::{CrossMean, VecCrossAllGenes};
+        use crate::tools::stopchecker::MaxIterations;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        /// The cross counts the families.
+        struct CountCross {
+            count: Rc<Cell<usize>>,
+        }
+
+        impl Cross<Vec<f64>> for CountCross {
+            fn cross(&mut self, parents: &[&Vec<f64>]) -> Vec<Vec<f64>> {
+                self.count.set(self.count.get() + 1);
+                let mut cross = VecCrossAllGenes::new(Box::new(CrossMean::new()));
+                cross.cross(parents)
+            }
+        }
+
+        let count = Rc::new(Cell::new(0));
+        let mut optimizer = create_optimizer(Box::new(UnitCubeGoal), vec![]);
+        optimizer.set_cross(Box::new(CountCross {
+            count: Rc::clone(&count),
+        }));
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(5)));
+        optimizer.set_replacement(Replacement::SteadyState { replace_count: 3 });
+        optimizer.find_min();
+
+        // Every family has single child, so only 3 families are crossed every iteration.
+        assert_eq!(count.get(), 3 * 5);
+    }
+
+    #[test]
+    fn set_generation() {
+        use crate::tools::stopchecker::MaxIterations;
+
+        /// The goal function saves all generation numbers.
+        struct GenerationGoal<'a> {
+            generations: &'a mut Vec<usize>,
+        }
+
+        impl<'a> Goal<Vec<f64>> for GenerationGoal<'a> {
+            fn get(&mut self, x: &Vec<f64>) -> f64 {
+                UnitCubeGoal.get(x)
+            }
+
+            fn set_generation(&mut self, generation: usize) {
+                self.generations.push(generation);
+            }
+        }
+
+        let mut generations = vec![];
+        {
+            let goal = GenerationGoal {
+                generations: &mut generations,
+            };
+            let mut optimizer = create_optimizer(Box::new(goal), vec![]);
+            optimizer.set_stop_checker(Box::new(MaxIterations::new(3)));
+            optimizer.find_min();
+        }
+
+        assert_eq!(generations, vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn find_min_twice() {
+        use crate::tools::stopchecker::GoalNotChange;
+
+        /// The goal function decreases for the first 1000 calls and is constant after that.
+        struct DecreasingGoal {
+            call_count: usize,
+        }
+
+        impl Goal<Vec<f64>> for DecreasingGoal {
+            fn get(&mut self, _x: &Vec<f64>) -> f64 {
+                self.call_count += 1;
+                1000_usize.saturating_sub(self.call_count) as f64
+            }
+        }
+
+        let mut optimizer = create_optimizer(Box::new(DecreasingGoal { call_count: 0 }), vec![]);
+        optimizer.set_stop_checker(Box::new(GoalNotChange::new(5, 0.0)));
+
+        optimizer.find_min();
+        assert!(optimizer.get_population().get_iteration() > 6);
+
+        // The goal function does not change in the second running. The best individual is
+        // known after the first iteration, so the algorithm stops after 1 + 5 + 1 iterations.
+        optimizer.find_min();
+        assert_eq!(optimizer.get_population().get_iteration(), 7);
+    }
+
+    #[test]
+    fn next_n_iterations() {
+        use crate::genetic::selection::LimitPopulation;
+        use crate::tools::stopchecker::MaxIterations;
+
+        let goal = GoalFromFunction::new(|x: &Vec<f64>| x.iter().sum());
+        let selections: Vec<Box<dyn Selection<Vec<f64>>>> =
+            vec![Box::new(LimitPopulation::new(20))];
+        let mut optimizer = create_optimizer(Box::new(goal), selections);
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(5)));
+
+        optimizer.find_min();
+        assert_eq!(optimizer.get_population().get_iteration(), 5);
+        assert!(!optimizer.is_population_degenerate());
+
+        // The stop checker is ignored
+        assert!(optimizer.next_n_iterations(10).is_some());
+        assert_eq!(optimizer.get_population().get_iteration(), 15);
+
+        assert!(optimizer.next_n_iterations(0).is_some());
+        assert_eq!(optimizer.get_population().get_iteration(), 15);
+    }
+
+    #[test]
+    fn find_min_with_budget() {
+        use crate::genetic::selection::LimitPopulation;
+        use crate::tools::stopchecker::MaxIterations;
+
+        let goal = GoalFromFunction::new(|x: &Vec<f64>| x.iter().sum());
+        let selections: Vec<Box<dyn Selection<Vec<f64>>>> =
+            vec![Box::new(LimitPopulation::new(20))];
+        let mut optimizer = create_optimizer(Box::new(goal), selections);
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(1000)));
+
+        assert!(optimizer.find_min_with_budget(5).is_some());
+        assert_eq!(optimizer.get_population().get_iteration(), 5);
+
+        // The own stop checker works too
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(3)));
+        assert!(optimizer.find_min_with_budget(5).is_some());
+        assert_eq!(optimizer.get_population().get_iteration(), 3);
+
+        // The limit is removed after the call
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(8)));
+        optimizer.find_min();
+        assert_eq!(optimizer.get_population().get_iteration(), 8);
     }
 }
@@ -2,10 +2,17 @@
 //! The module contains struct which implements the `Mutation` trait to mutate
 //! chromosomes various types.
 
+use std::collections::HashMap;
+
 use crate::genetic::Mutation;
+use crate::tools::{sample_standard_normal, Intervals};
+use crate::update_param;
+use num::Float;
+use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs;
 use rand::rngs::ThreadRng;
+use rand::Rng;
 use std::mem;
 
 /// The struct to change random bits in the chromosomes.
@@ -63,6 +70,107 @@ impl Mutation<f64> for BitwiseMutation {
     }
 }
 
+#[cfg(feature = "half")]
+impl Mutation<half::f16> for BitwiseMutation {
+    fn mutation(&mut self, gene: &half::f16) -> half::f16 {
+        let size = mem::size_of::<half::f16>() * 8;
+        let between = Uniform::new(0, size);
+
+        let mut bit_value = gene.to_bits();
+        for _ in 0..self.change_gene_count {
+            let pos = between.sample(&mut self.random);
+            bit_value ^= 1 << pos;
+        }
+        half::f16::from_bits(bit_value)
+    }
+}
+
+/// The struct to change random bits in the chromosomes as `BitwiseMutation`, but the range of
+/// the bit positions narrows with generation. In generation 0 any bit may be changed. The upper
+/// bound of the range decreases linearly and in generation `generations` (and later) only
+/// `final_bits` low-order bits of mantissa may be changed. So the perturbations become smaller
+/// as the algorithm progresses.
+pub struct AnnealedBitwiseMutation {
+    random: ThreadRng,
+    change_gene_count: usize,
+    final_bits: usize,
+    generations: usize,
+    generation: usize,
+}
+
+impl AnnealedBitwiseMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `change_gene_count` - how many bits will changed by algorithm during mutation.
+    /// * `final_bits` - count of the low-order bits which may be changed in the last generations.
+    ///   The value must be greater than 0.
+    /// * `generations` - count of the generations to narrow the range of the bit positions.
+    pub fn new(change_gene_count: usize, final_bits: usize, generations: usize) -> Self {
+        assert!(final_bits > 0, "final_bits must be greater than 0");
+        let random = rand::thread_rng();
+        Self {
+            random,
+            change_gene_count,
+            final_bits,
+            generations,
+            generation: 0,
+        }
+    }
+
+    /// Returns the upper bound (exclusive) of the positions of the bits which may be changed for
+    /// the current generation.
+    ///
+    /// # Parameters
+    /// * `size` - count of the bits in the gene.
+    pub fn get_bits_range(&self, size: usize) -> usize {
+        let final_bits = self.final_bits.min(size);
+        if self.generation >= self.generations {
+            return final_bits;
+        }
+
+        let progress = self.generation as f64 / self.generations as f64;
+        let narrowing = ((size - final_bits) as f64 * progress).round() as usize;
+        size - narrowing
+    }
+}
+
+impl Mutation<f32> for AnnealedBitwiseMutation {
+    fn mutation(&mut self, gene: &f32) -> f32 {
+        let size = mem::size_of::<f32>() * 8;
+        let between = Uniform::new(0, self.get_bits_range(size));
+
+        let mut bit_value = gene.to_bits();
+        for _ in 0..self.change_gene_count {
+            let pos = between.sample(&mut self.random);
+            bit_value ^= 1 << pos;
+        }
+        f32::from_bits(bit_value)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+}
+
+impl Mutation<f64> for AnnealedBitwiseMutation {
+    fn mutation(&mut self, gene: &f64) -> f64 {
+        let size = mem::size_of::<f64>() * 8;
+        let between = Uniform::new(0, self.get_bits_range(size));
+
+        let mut bit_value = gene.to_bits();
+        for _ in 0..self.change_gene_count {
+            let pos = between.sample(&mut self.random);
+            bit_value ^= 1 << pos;
+        }
+        f64::from_bits(bit_value)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+}
+
 impl<G> VecMutation<G> {
     /// Constructor
     ///
@@ -94,4 +202,475 @@ impl<G: Clone> Mutation<Vec<G>> for VecMutation<G> {
 
         result
     }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.single_mutation.set_generation(generation);
+    }
+
+    /// The parameters: "mutation_probability" and the parameters of the single gene mutation.
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = self.single_mutation.get_params();
+        params.insert("mutation_probability".to_string(), self.probability);
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "mutation_probability", &mut self.probability);
+        self.single_mutation.set_params(params);
+    }
+}
+
+/// Mutation for integer genes. The mutation adds to the gene random step from the
+/// [-max_step; max_step] interval (the step is not 0). The mutation may be used with
+/// `VecMutation` for chromosomes of type Vec<i64>.
+pub struct IntStepMutation {
+    max_step: i64,
+    random: ThreadRng,
+}
+
+impl IntStepMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `max_step` - max absolute value of the step. The value must be greater than 0.
+    pub fn new(max_step: i64) -> Self {
+        assert!(max_step > 0);
+        let random = rand::thread_rng();
+        Self { max_step, random }
+    }
+}
+
+impl Mutation<i64> for IntStepMutation {
+    fn mutation(&mut self, gene: &i64) -> i64 {
+        let step = Uniform::new_inclusive(1, self.max_step).sample(&mut self.random);
+        if self.random.gen::<bool>() {
+            gene.saturating_add(step)
+        } else {
+            gene.saturating_sub(step)
+        }
+    }
+}
+
+/// Mutation for chromosomes of Vec<i64> which replaces a gene by random value from the interval
+/// with given probability. The mutation keeps the genes inside the intervals.
+pub struct IntResampleMutation {
+    intervals: Intervals<i64>,
+    probability: f64,
+    random: ThreadRng,
+}
+
+impl IntResampleMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `intervals` - vector of the tuples (minval, maxval) for every gene.
+    /// * `probability` - probability of resample of single gene (in percent, 0..100).
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(i64, i64)>, probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+
+        let random = rand::thread_rng();
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            probability,
+            random,
+        }
+    }
+}
+
+impl Mutation<Vec<i64>> for IntResampleMutation {
+    fn mutation(&mut self, chromosomes: &Vec<i64>) -> Vec<i64> {
+        assert_eq!(chromosomes.len(), self.intervals.len());
+
+        let mut result = chromosomes.clone();
+        let mutate = Uniform::new(0.0, 100.0);
+        for (gene, (min, max)) in result.iter_mut().zip(self.intervals.iter()) {
+            if mutate.sample(&mut self.random) < self.probability {
+                *gene = Uniform::new_inclusive(min, max).sample(&mut self.random);
+            }
+        }
+
+        result
+    }
+}
+
+/// Mutation for chromosomes of Vec<G> which replaces a gene by random value from the interval
+/// with small probability (macro-mutation). The mutation is used to keep diversity of the
+/// population. The mutation may be applied after other mutation (see `MacroMutation::inner`).
+pub struct MacroMutation<G: Float + SampleUniform> {
+    intervals: Intervals<G>,
+    probability: f64,
+    inner: Option<Box<dyn Mutation<Vec<G>>>>,
+    random: ThreadRng,
+}
+
+impl<G: Float + SampleUniform> MacroMutation<G> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `intervals` - vector of the tuples (minval, maxval) for every gene.
+    /// * `probability` - probability of reset of single gene (in percent, 0..100).
+    ///
+    /// # Panics
+    /// Panics if min > max or a bound is NaN for any interval (see `Intervals::new()`) or
+    /// `probability` is outside of 0..100.
+    pub fn new(intervals: Vec<(G, G)>, probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+
+        let random = rand::thread_rng();
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            probability,
+            inner: None,
+            random,
+        }
+    }
+
+    /// Set mutation which will be applied before the macro-mutation.
+    pub fn inner(mut self, mutation: Box<dyn Mutation<Vec<G>>>) -> Self {
+        self.inner = Some(mutation);
+        self
+    }
+}
+
+impl<G: Float + SampleUniform> Mutation<Vec<G>> for MacroMutation<G> {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        assert_eq!(chromosomes.len(), self.intervals.len());
+
+        let mut result = match &mut self.inner {
+            None => chromosomes.clone(),
+            Some(mutation) => mutation.mutation(chromosomes),
+        };
+
+        let mutate = Uniform::new(0.0, 100.0);
+        for (gene, (min, max)) in result.iter_mut().zip(self.intervals.iter()) {
+            if mutate.sample(&mut self.random) < self.probability {
+                *gene = Uniform::new_inclusive(*min, *max).sample(&mut self.random);
+            }
+        }
+
+        result
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        if let Some(mutation) = &mut self.inner {
+            mutation.set_generation(generation);
+        }
+    }
+}
+
+/// Mutation for chromosomes of Vec<G> which adds a random value from the normal distribution to
+/// every gene. Standard deviation for every gene is proportional to width of the gene interval,
+/// so the mutation changes the genes with different scales uniformly:
+/// sigma_i = factor * (max_i - min_i).
+/// The mutation does not limit the genes by the intervals.
+pub struct ScaledGaussianMutation<G: Float> {
+    sigmas: Vec<G>,
+    random: ThreadRng,
+}
+
+impl<G: Float> ScaledGaussianMutation<G> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `intervals` - vector of the tuples (minval, maxval) for every gene.
+    /// * `factor` - ratio of standard deviation to width of the interval. Must be greater or
+    ///   equal than 0.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(G, G)>, factor: G) -> Self {
+        assert!(factor >= G::zero());
+        let intervals = Intervals::new(intervals).unwrap();
+        let sigmas = (0..intervals.len())
+            .map(|i| intervals.width(i) * factor)
+            .collect();
+
+        let random = rand::thread_rng();
+        Self { sigmas, random }
+    }
+
+    /// Returns standard deviation for every gene.
+    pub fn get_sigmas(&self) -> &Vec<G> {
+        &self.sigmas
+    }
+}
+
+impl<G: Float> Mutation<Vec<G>> for ScaledGaussianMutation<G> {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        assert_eq!(chromosomes.len(), self.sigmas.len());
+
+        let random = &mut self.random;
+        chromosomes
+            .iter()
+            .zip(self.sigmas.iter())
+            .map(|(gene, sigma)| *gene + *sigma * G::from(sample_standard_normal(random)).unwrap())
+            .collect()
+    }
+}
+
+/// Mutation for permutation chromosomes (for example, order of the cities in the traveling
+/// salesman problem). The mutation swaps two random genes with given probability, so the
+/// chromosome keeps the same set of genes.
+///
+/// The usual cross algorithms do not keep the permutation, so the permutation cross algorithms
+/// (for example, `cross::OrderCrossover`) are required for such problems.
+pub struct SwapMutation {
+    probability: f64,
+    random: ThreadRng,
+}
+
+impl SwapMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `probability` - probability of the genes swap (in percent, 0..100).
+    pub fn new(probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+
+        let random = rand::thread_rng();
+        Self {
+            probability,
+            random,
+        }
+    }
+}
+
+impl<G: Clone> Mutation<Vec<G>> for SwapMutation {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        let mut result = chromosomes.clone();
+        if result.len() < 2 || Uniform::new(0.0, 100.0).sample(&mut self.random) >= self.probability
+        {
+            return result;
+        }
+
+        let first = self.random.gen_range(0, result.len());
+        let mut second = self.random.gen_range(0, result.len() - 1);
+        if second >= first {
+            second += 1;
+        }
+
+        result.swap(first, second);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn macro_mutation_frequency() {
+        let gene_count = 10;
+        let trials = 10000;
+        let intervals = vec![(0.0, 1.0); gene_count];
+        let mut mutation = MacroMutation::new(intervals, 5.0);
+
+        // The genes lie outside the interval to find reset genes
+        let chromosomes = vec![2.0_f64; gene_count];
+
+        let mut reset_count = 0;
+        for _ in 0..trials {
+            let mutant = mutation.mutation(&chromosomes);
+            assert_eq!(mutant.len(), gene_count);
+
+            for gene in mutant {
+                if gene != 2.0 {
+                    assert!((0.0..=1.0).contains(&gene));
+                    reset_count += 1;
+                }
+            }
+        }
+
+        let frequency = reset_count as f64 / (trials * gene_count) as f64;
+        assert!((frequency - 0.05).abs() < 0.01);
+    }
+
+    #[test]
+    fn macro_mutation_zero_probability() {
+        let mut mutation = MacroMutation::new(vec![(0.0, 1.0), (0.0, 1.0)], 0.0);
+        let chromosomes = vec![2.0_f32, 3.0_f32];
+
+        for _ in 0..100 {
+            assert_eq!(mutation.mutation(&chromosomes), chromosomes);
+        }
+    }
+
+    #[test]
+    fn macro_mutation_inner() {
+        let inner = VecMutation::new(100.0, Box::new(BitwiseMutation::new(1)));
+        let mut mutation = MacroMutation::new(vec![(0.0, 1.0), (0.0, 1.0)], 0.0).inner(Box::new(inner));
+        let chromosomes = vec![2.0_f64, 3.0_f64];
+
+        let mutant = mutation.mutation(&chromosomes);
+        assert_ne!(mutant[0], chromosomes[0]);
+        assert_ne!(mutant[1], chromosomes[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn macro_mutation_invalid_interval() {
+        MacroMutation::new(vec![(1.0, 0.0)], 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn macro_mutation_nan_interval() {
+        MacroMutation::new(vec![(0.0, 1.0), (f64::NAN, 1.0)], 1.0);
+    }
+
+    #[test]
+    fn int_step_mutation() {
+        let mut mutation = IntStepMutation::new(2);
+        let mut steps = vec![];
+        for _ in 0..1000 {
+            let step = mutation.mutation(&10) - 10;
+            assert!(step != 0 && step.abs() <= 2);
+            steps.push(step);
+        }
+
+        for step in &[-2, -1, 1, 2] {
+            assert!(steps.contains(step));
+        }
+    }
+
+    #[test]
+    fn int_resample_mutation() {
+        let mut mutation = IntResampleMutation::new(vec![(0, 3), (-1, 1)], 100.0);
+        for _ in 0..100 {
+            let mutant = mutation.mutation(&vec![10, 10]);
+            assert!(mutant[0] >= 0 && mutant[0] <= 3);
+            assert!(mutant[1] >= -1 && mutant[1] <= 1);
+        }
+
+        let mut mutation = IntResampleMutation::new(vec![(0, 3), (-1, 1)], 0.0);
+        assert_eq!(mutation.mutation(&vec![10, 10]), vec![10, 10]);
+    }
+
+    /// Returns histogram of the changed bit positions for single bit mutations.
+    fn changed_bits_histogram(mutation: &mut dyn Mutation<Vec<f64>>, trials: usize) -> Vec<usize> {
+        let gene = 123.456_f64;
+        let mut histogram = vec![0; 64];
+        for _ in 0..trials {
+            let mutant = mutation.mutation(&vec![gene]);
+            let changed = mutant[0].to_bits() ^ gene.to_bits();
+            assert_eq!(changed.count_ones(), 1);
+            histogram[changed.trailing_zeros() as usize] += 1;
+        }
+
+        histogram
+    }
+
+    #[test]
+    fn annealed_bitwise_bits_range() {
+        let mut mutation = AnnealedBitwiseMutation::new(1, 16, 100);
+        assert_eq!(mutation.get_bits_range(64), 64);
+        assert_eq!(mutation.get_bits_range(32), 32);
+
+        Mutation::<f64>::set_generation(&mut mutation, 50);
+        assert_eq!(mutation.get_bits_range(64), 40);
+        assert_eq!(mutation.get_bits_range(32), 24);
+
+        Mutation::<f64>::set_generation(&mut mutation, 100);
+        assert_eq!(mutation.get_bits_range(64), 16);
+
+        Mutation::<f64>::set_generation(&mut mutation, 1000);
+        assert_eq!(mutation.get_bits_range(64), 16);
+        assert_eq!(mutation.get_bits_range(8), 8);
+    }
+
+    #[test]
+    fn annealed_bitwise_late_generation() {
+        let trials = 10000;
+        let final_bits = 16;
+        let mut mutation = VecMutation::new(
+            100.0,
+            Box::new(AnnealedBitwiseMutation::new(1, final_bits, 100)),
+        );
+
+        // Generation 0: all bits may be changed
+        let histogram = changed_bits_histogram(&mut mutation, trials);
+        let high_count: usize = histogram[final_bits..].iter().sum();
+        assert!(high_count > trials / 2);
+
+        // The generation is passed to the inner mutation
+        mutation.set_generation(200);
+        let histogram = changed_bits_histogram(&mut mutation, trials);
+        let high_count: usize = histogram[final_bits..].iter().sum();
+        assert_eq!(high_count, 0);
+        assert!(histogram[..final_bits].iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn scaled_gaussian_mutation_scale() {
+        let trials = 10000;
+        let mut mutation = ScaledGaussianMutation::new(vec![(0.0, 1.0), (-500.0, 500.0)], 0.1);
+        assert_eq!(mutation.get_sigmas(), &vec![0.1, 100.0]);
+
+        let chromosomes = vec![0.5_f64, 0.0_f64];
+        let mut sum_steps = [0.0; 2];
+        for _ in 0..trials {
+            let mutant = mutation.mutation(&chromosomes);
+            sum_steps[0] += (mutant[0] - chromosomes[0]).abs();
+            sum_steps[1] += (mutant[1] - chromosomes[1]).abs();
+        }
+
+        // The average step on the wide dimension is 1000 times larger
+        let ratio = sum_steps[1] / sum_steps[0];
+        assert!(ratio > 900.0 && ratio < 1100.0);
+    }
+
+    #[test]
+    fn scaled_gaussian_mutation_degenerate_interval() {
+        let mut mutation = ScaledGaussianMutation::new(vec![(1.0, 1.0), (0.0, 1.0)], 0.1);
+        let chromosomes = vec![1.0_f32, 0.5_f32];
+
+        for _ in 0..100 {
+            let mutant = mutation.mutation(&chromosomes);
+            assert_eq!(mutant[0], 1.0);
+        }
+    }
+
+    #[test]
+    fn swap_mutation_permutation() {
+        let chromosomes: Vec<usize> = (0..10).collect();
+        let mut mutation = SwapMutation::new(100.0);
+
+        for _ in 0..100 {
+            let mutant = mutation.mutation(&chromosomes);
+
+            // Exactly two genes are changed
+            let changed: Vec<usize> = (0..chromosomes.len())
+                .filter(|&n| mutant[n] != chromosomes[n])
+                .collect();
+            assert_eq!(changed.len(), 2);
+            assert_eq!(mutant[changed[0]], chromosomes[changed[1]]);
+            assert_eq!(mutant[changed[1]], chromosomes[changed[0]]);
+
+            let mut sorted = mutant.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, chromosomes);
+        }
+    }
+
+    #[test]
+    fn swap_mutation_zero_probability() {
+        let chromosomes = vec![3, 1, 2, 0];
+        let mut mutation = SwapMutation::new(0.0);
+
+        for _ in 0..100 {
+            assert_eq!(mutation.mutation(&chromosomes), chromosomes);
+        }
+    }
+
+    #[test]
+    fn swap_mutation_short() {
+        let mut mutation = SwapMutation::new(100.0);
+
+        assert_eq!(mutation.mutation(&vec![5]), vec![5]);
+        assert_eq!(mutation.mutation(&Vec::<usize>::new()), vec![]);
+        assert_eq!(mutation.mutation(&vec![1, 2]), vec![2, 1]);
+    }
 }
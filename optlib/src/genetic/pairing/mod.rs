@@ -1,5 +1,7 @@
 //! The module with pairing algorithm traits. The pairing algorithm selects individuals for crossing.
 
+use std::cmp::Ordering;
+
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs::ThreadRng;
 
@@ -39,10 +41,16 @@ impl RandomPairing {
 /// Algorithm of tournament.
 ///
 /// Every individual to cross must be best of N others random individuals.
+///
+/// The tournament may be probabilistic (see `Tournament::win_probability()`). In this case the
+/// competitors are ranked and the best competitor wins with probability `p`, the second
+/// competitor wins with probability `p * (1 - p)`, the third with `p * (1 - p)^2` and so on.
+/// The worst competitor wins if all others lose.
 pub struct Tournament {
     families_count: usize,
     partners_count: usize,
     rounds_count: usize,
+    win_probability: f64,
     random: ThreadRng,
 }
 
@@ -57,6 +65,7 @@ impl Tournament {
             families_count,
             partners_count: 2,
             rounds_count: 1,
+            win_probability: 1.0,
             random,
         }
     }
@@ -72,6 +81,45 @@ impl Tournament {
         self.rounds_count = count;
         self
     }
+
+    /// Set probability of winning for the better competitor (0 <= p <= 1). The default is 1
+    /// (the best competitor always wins). The value of 0.5 for two competitors means random
+    /// choice.
+    pub fn win_probability(mut self, p: f64) -> Self {
+        assert!((0.0..=1.0).contains(&p));
+        self.win_probability = p;
+        self
+    }
+
+    /// Select the winner from the competitors (indices of the individuals) by rank. The sorting
+    /// is stable, so the first of the equal best competitors wins for p = 1.
+    fn select_by_rank<T>(
+        &mut self,
+        population: &Population<T>,
+        mut competitors: Vec<usize>,
+    ) -> usize {
+        let objective = population.get_objective();
+        competitors.sort_by(|&first, &second| {
+            let goal_1 = population[first].get_goal();
+            let goal_2 = population[second].get_goal();
+            if objective.is_better(goal_1, goal_2) {
+                Ordering::Less
+            } else if objective.is_better(goal_2, goal_1) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+
+        let win = Uniform::new(0.0, 1.0);
+        for &competitor in &competitors[..competitors.len() - 1] {
+            if win.sample(&mut self.random) < self.win_probability {
+                return competitor;
+            }
+        }
+
+        *competitors.last().unwrap()
+    }
 }
 
 impl<T> Pairing<T> for Tournament {
@@ -85,16 +133,11 @@ impl<T> Pairing<T> for Tournament {
 
             // Loop for partner
             for _ in 0..self.partners_count {
-                let mut best_ind_index = between.sample(&mut self.random);
-
-                // Loop for tournaments rounds
-                for _ in 0..self.rounds_count {
-                    let challenger = between.sample(&mut self.random);
-                    if population[challenger].get_goal() < population[best_ind_index].get_goal() {
-                        best_ind_index = challenger;
-                    }
-                }
-                family.push(best_ind_index);
+                // The first competitor and the challengers for every round
+                let competitors = (0..=self.rounds_count)
+                    .map(|_| between.sample(&mut self.random))
+                    .collect();
+                family.push(self.select_by_rank(population, competitors));
             }
 
             pairs.push(family);
@@ -103,3 +146,74 @@ impl<T> Pairing<T> for Tournament {
         pairs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Goal, GoalValue};
+
+    struct IdentityGoal;
+
+    impl Goal<f64> for IdentityGoal {
+        fn get(&mut self, x: &f64) -> GoalValue {
+            *x
+        }
+    }
+
+    /// Returns frequency of the individual with index 0 in the families.
+    fn get_frequency(pairs: &[Vec<usize>]) -> f64 {
+        let count = pairs.iter().flatten().filter(|index| **index == 0).count();
+        count as f64 / pairs.iter().flatten().count() as f64
+    }
+
+    #[test]
+    fn tournament_win_probability() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.append(vec![1.0, 2.0]);
+
+        // Both competitors are the best individual (index 0) with probability 1/4 and the
+        // competitors are different with probability 1/2, so the frequency of the best
+        // individual is 1/4 + p / 2.
+        let mut pairing = Tournament::new(10000).win_probability(0.8);
+        let frequency = get_frequency(&pairing.get_pairs(&population));
+        assert!((frequency - 0.65).abs() < 0.02);
+
+        let mut pairing = Tournament::new(10000).win_probability(0.5);
+        let frequency = get_frequency(&pairing.get_pairs(&population));
+        assert!((frequency - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn tournament_deterministic() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.append(vec![1.0, 2.0]);
+
+        let mut pairing = Tournament::new(10000);
+        let frequency = get_frequency(&pairing.get_pairs(&population));
+        assert!((frequency - 0.75).abs() < 0.02);
+
+        let mut pairing = Tournament::new(10000).win_probability(1.0);
+        let frequency = get_frequency(&pairing.get_pairs(&population));
+        assert!((frequency - 0.75).abs() < 0.02);
+    }
+
+    #[test]
+    fn tournament_rank() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.append(vec![3.0, 1.0, f64::NAN, 2.0]);
+        let mut pairing = Tournament::new(1);
+
+        // The best competitor always wins for p = 1 and the worst always wins for p = 0
+        pairing.win_probability = 1.0;
+        assert_eq!(pairing.select_by_rank(&population, vec![0, 2, 1, 3]), 1);
+        pairing.win_probability = 0.0;
+        assert_eq!(pairing.select_by_rank(&population, vec![0, 2, 1, 3]), 2);
+        assert_eq!(pairing.select_by_rank(&population, vec![3, 0]), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tournament_invalid_probability() {
+        Tournament::new(1).win_probability(1.5);
+    }
+}
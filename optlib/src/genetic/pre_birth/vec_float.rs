@@ -1,15 +1,19 @@
 //! The module with PreBirth trait implementations for chromosomes of Vec<T> type.
 
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
 use num::Float;
 
 use crate::genetic::{Population, PreBirth};
+use crate::tools::Intervals;
 
 /// Kill individuals if theirs gene does not lie in the specified intevals.
 ///
 /// `G` - type of gene.
 /// Returns count of the killed individuals.
 pub struct CheckChromoInterval<G: Float> {
-    intervals: Vec<(G, G)>,
+    intervals: Intervals<G>,
 }
 
 impl<G: Float> CheckChromoInterval<G> {
@@ -18,8 +22,13 @@ impl<G: Float> CheckChromoInterval<G> {
     /// # Parameters
     /// * `intervals` - allowed interval for every gene. Count of the genes and count of the
     /// interval must be equal.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(intervals: Vec<(G, G)>) -> Self {
-        Self { intervals }
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+        }
     }
 }
 
@@ -31,14 +40,188 @@ impl<G: Float> PreBirth<Vec<G>> for CheckChromoInterval<G> {
 
 impl<G: Float> CheckChromoInterval<G> {
     fn check_chromo(&mut self, chromosomes: &Vec<G>) -> bool {
-        assert_eq!(chromosomes.len(), self.intervals.len());
+        self.intervals.contains(chromosomes)
+    }
+}
+
+/// Remove new chromosomes which are bit-identical with chromosomes of the individuals in the
+/// population or with other new chromosomes. Genes are compared by `to_bits()`, so
+/// 0.0 and -0.0 are different genes, but NaN genes with the same bits are equal.
+#[derive(Default)]
+pub struct RejectBitwiseDuplicates;
+
+impl RejectBitwiseDuplicates {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn reject<G>(
+        population: &Population<Vec<G>>,
+        new_chromosomes: &mut Vec<Vec<G>>,
+        to_bits: fn(&G) -> u64,
+    ) {
+        let mut known: HashSet<Vec<u64>> = population
+            .iter()
+            .map(|individual| individual.get_chromosomes().iter().map(to_bits).collect())
+            .collect();
+
+        new_chromosomes.retain(|chromosomes| known.insert(chromosomes.iter().map(to_bits).collect()));
+    }
+}
+
+impl PreBirth<Vec<f32>> for RejectBitwiseDuplicates {
+    fn pre_birth(&mut self, population: &Population<Vec<f32>>, new_chromosomes: &mut Vec<Vec<f32>>) {
+        Self::reject(population, new_chromosomes, |gene| u64::from(gene.to_bits()));
+    }
+}
+
+impl PreBirth<Vec<f64>> for RejectBitwiseDuplicates {
+    fn pre_birth(&mut self, population: &Population<Vec<f64>>, new_chromosomes: &mut Vec<Vec<f64>>) {
+        Self::reject(population, new_chromosomes, |gene| gene.to_bits());
+    }
+}
 
-        for (chromo, interval) in chromosomes.iter().zip(self.intervals.iter()) {
-            if !chromo.is_finite() || *chromo < interval.0 || *chromo > interval.1 {
-                return false;
+/// The way to enforce the monotonic order of the genes (see `EnforceMonotonic`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonotonicRepair {
+    /// Sort the genes of the new chromosomes ascending.
+    #[default]
+    Sort,
+
+    /// Remove the new chromosomes with genes not sorted ascending.
+    Reject,
+}
+
+/// Enforce the monotonic order of the genes (x_0 <= x_1 <= ... <= x_n) for the new
+/// chromosomes. The struct is useful for problems with ordered parameters (for example, sorted
+/// breakpoints), so the goal function is not calculated for invalid chromosomes.
+/// The chromosomes with NaN genes are removed in both modes.
+///
+/// `G` - type of gene.
+pub struct EnforceMonotonic<G: Float> {
+    repair: MonotonicRepair,
+    _gene: PhantomData<G>,
+}
+
+impl<G: Float> EnforceMonotonic<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `repair` - sort the genes or reject the chromosomes with unordered genes.
+    pub fn new(repair: MonotonicRepair) -> Self {
+        Self {
+            repair,
+            _gene: PhantomData,
+        }
+    }
+}
+
+impl<G: Float> PreBirth<Vec<G>> for EnforceMonotonic<G> {
+    fn pre_birth(&mut self, _population: &Population<Vec<G>>, new_chromosomes: &mut Vec<Vec<G>>) {
+        new_chromosomes.retain(|chromosomes| chromosomes.iter().all(|gene| !gene.is_nan()));
+
+        match self.repair {
+            MonotonicRepair::Sort => {
+                for chromosomes in new_chromosomes.iter_mut() {
+                    chromosomes.sort_by(|gene_1, gene_2| gene_1.partial_cmp(gene_2).unwrap());
+                }
+            }
+            MonotonicRepair::Reject => {
+                new_chromosomes
+                    .retain(|chromosomes| chromosomes.windows(2).all(|pair| pair[0] <= pair[1]));
             }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Goal;
+
+    struct SumGoal;
+
+    impl<G: Float> Goal<Vec<G>> for SumGoal {
+        fn get(&mut self, x: &Vec<G>) -> f64 {
+            x.iter().fold(0.0, |acc, xi| acc + xi.to_f64().unwrap())
+        }
+    }
+
+    #[test]
+    fn reject_bitwise_duplicates_f64() {
+        let mut population = Population::new(Box::new(SumGoal));
+        population.append(vec![vec![1.0_f64, 2.0_f64], vec![0.0_f64, 3.0_f64]]);
+
+        let mut new_chromosomes = vec![
+            vec![1.0_f64, 2.0_f64],
+            vec![-0.0_f64, 3.0_f64],
+            vec![1.0_f64, 2.0_f64 + f64::EPSILON * 2.0],
+            vec![5.0_f64, 6.0_f64],
+            vec![5.0_f64, 6.0_f64],
+        ];
+
+        RejectBitwiseDuplicates::new().pre_birth(&population, &mut new_chromosomes);
+
+        assert_eq!(
+            new_chromosomes,
+            vec![
+                vec![-0.0_f64, 3.0_f64],
+                vec![1.0_f64, 2.0_f64 + f64::EPSILON * 2.0],
+                vec![5.0_f64, 6.0_f64],
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_bitwise_duplicates_f32() {
+        let mut population = Population::new(Box::new(SumGoal));
+        population.append(vec![vec![1.0_f32], vec![2.0_f32]]);
+
+        let mut new_chromosomes = vec![vec![2.0_f32], vec![3.0_f32], vec![1.0_f32]];
+
+        RejectBitwiseDuplicates::new().pre_birth(&population, &mut new_chromosomes);
+
+        assert_eq!(new_chromosomes, vec![vec![3.0_f32]]);
+    }
+
+    #[test]
+    fn enforce_monotonic_sort() {
+        let population = Population::new(Box::new(SumGoal));
+        let mut new_chromosomes = vec![
+            vec![3.0, 1.0, 2.0],
+            vec![1.0, 2.0, 2.0],
+            vec![1.0, f64::NAN, 2.0],
+            vec![0.5, -1.0, 4.0],
+        ];
+
+        EnforceMonotonic::new(MonotonicRepair::Sort).pre_birth(&population, &mut new_chromosomes);
+
+        assert_eq!(
+            new_chromosomes,
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![1.0, 2.0, 2.0],
+                vec![-1.0, 0.5, 4.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn enforce_monotonic_reject() {
+        let population = Population::new(Box::new(SumGoal));
+        let mut new_chromosomes = vec![
+            vec![3.0_f32, 1.0_f32, 2.0_f32],
+            vec![1.0_f32, 2.0_f32, 2.0_f32],
+            vec![1.0_f32, f32::NAN, 2.0_f32],
+            vec![5.0_f32],
+        ];
+
+        EnforceMonotonic::new(MonotonicRepair::Reject).pre_birth(&population, &mut new_chromosomes);
 
-        true
+        assert_eq!(
+            new_chromosomes,
+            vec![vec![1.0_f32, 2.0_f32, 2.0_f32], vec![5.0_f32]]
+        );
     }
 }
@@ -5,7 +5,11 @@
 
 pub mod vec_float;
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
 use crate::genetic::{Population, Selection};
+use crate::update_int_param;
 
 /// Kill individuals if value of theirs fitness (goal function) is NaN.
 /// Returns count of killed individuals.
@@ -50,47 +54,117 @@ impl<T: Clone> Selection<T> for LimitPopulation {
             kill_worst(population, alive_count - self.max_count);
         }
     }
+
+    /// The parameters: "population_size". The new value is rounded to the nearest integer.
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = HashMap::new();
+        params.insert("population_size".to_string(), self.max_count as f64);
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_int_param(params, "population_size", &mut self.max_count);
+    }
 }
 
 /// Function to kill worst individuals in population.
 /// `count` - how many individuals must be killed.
+///
+/// The result is deterministic: if several individuals have equal fitness, the individuals with
+/// the greater index in the population are killed first. Individuals with NaN fitness are
+/// considered as the worst.
 pub fn kill_worst<T: Clone>(population: &mut Population<T>, count: usize) {
-    // List of indexes of individuals in population to be kill
-    let mut kill_list: Vec<usize> = Vec::with_capacity(count);
-    kill_list.push(0);
+    let objective = population.get_objective();
 
-    // Index of the items in kill_list with best fitness
-    let mut best_index = 0;
-    let mut best_fitness = population[kill_list[best_index]].get_fitness();
+    // Indexes of the alive individuals from the worst to the best
+    let mut kill_list: Vec<usize> = (0..population.len())
+        .filter(|n| population[*n].is_alive())
+        .collect();
 
-    for n in 1..population.len() {
-        if !population[n].is_alive() {
-            continue;
-        }
+    kill_list.sort_by(|n, m| {
+        let fitness_n = population[*n].get_fitness();
+        let fitness_m = population[*m].get_fitness();
 
-        if kill_list.len() < count {
-            kill_list.push(n);
-            if population[n].get_fitness() < best_fitness {
-                best_index = kill_list.len() - 1;
-            }
+        if objective.is_better(fitness_n, fitness_m) {
+            Ordering::Greater
+        } else if objective.is_better(fitness_m, fitness_n) {
+            Ordering::Less
         } else {
-            if population[n].get_fitness() > best_fitness {
-                kill_list[best_index] = n;
-
-                // Find new best item
-                best_index = 0;
-                best_fitness = population[kill_list[best_index]].get_fitness();
-                for m in 1..kill_list.len() {
-                    if population[kill_list[m]].get_fitness() < best_fitness {
-                        best_index = m;
-                        best_fitness = population[kill_list[best_index]].get_fitness();
-                    }
-                }
-            }
+            m.cmp(n)
         }
-    }
+    });
 
-    for n in kill_list {
+    for n in kill_list.into_iter().take(count) {
         population[n].kill();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Goal, GoalValue, Objective};
+
+    struct IdentityGoal;
+
+    impl Goal<f64> for IdentityGoal {
+        fn get(&mut self, x: &f64) -> GoalValue {
+            *x
+        }
+    }
+
+    fn get_alive(population: &Population<f64>) -> Vec<usize> {
+        (0..population.len())
+            .filter(|n| population[*n].is_alive())
+            .collect()
+    }
+
+    #[test]
+    fn kill_worst_ties() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.append(vec![1.0, 5.0, 3.0, 5.0, 5.0, 0.0, 5.0]);
+
+        // Individuals with fitness 5.0 and the greater indexes are killed first
+        kill_worst(&mut population, 3);
+        assert_eq!(get_alive(&population), vec![0, 1, 2, 5]);
+
+        kill_worst(&mut population, 2);
+        assert_eq!(get_alive(&population), vec![0, 5]);
+    }
+
+    #[test]
+    fn kill_worst_repeatable() {
+        for _ in 0..10 {
+            let mut population = Population::new(Box::new(IdentityGoal));
+            population.append(vec![2.0; 10]);
+
+            kill_worst(&mut population, 4);
+            assert_eq!(get_alive(&population), vec![0, 1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn kill_worst_nan_and_dead() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.append(vec![1.0, f64::NAN, 3.0, 2.0]);
+        population[2].kill();
+
+        kill_worst(&mut population, 2);
+        assert_eq!(get_alive(&population), vec![0]);
+
+        kill_worst(&mut population, 0);
+        assert_eq!(get_alive(&population), vec![0]);
+
+        kill_worst(&mut population, 5);
+        assert!(get_alive(&population).is_empty());
+    }
+
+    #[test]
+    fn kill_worst_maximize() {
+        let mut population = Population::new(Box::new(IdentityGoal));
+        population.objective = Objective::Maximize;
+        population.append(vec![1.0, 3.0, 1.0, 2.0]);
+
+        kill_worst(&mut population, 2);
+        assert_eq!(get_alive(&population), vec![1, 3]);
+    }
+}
@@ -1,15 +1,19 @@
 //! The module with selection algorithms for type chromosomes of Vec<Float>.
 
+use std::cmp::Ordering;
+
 use num::Float;
 
 use crate::genetic::{Population, Selection};
+use crate::tools::Intervals;
+use crate::Objective;
 
 /// Kill individuals if theirs gene does not lie in the specified intevals.
 ///
 /// `G` - type of gene.
 /// Returns count of the killed individuals.
 pub struct CheckChromoInterval<G: Float> {
-    intervals: Vec<(G, G)>,
+    intervals: Intervals<G>,
 }
 
 impl<G: Float> CheckChromoInterval<G> {
@@ -18,26 +22,360 @@ impl<G: Float> CheckChromoInterval<G> {
     /// # Parameters
     /// * `intervals` - allowed interval for every gene. Count of the genes and count of the
     /// interval must be equal.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(intervals: Vec<(G, G)>) -> Self {
-        Self { intervals }
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+        }
     }
 }
 
 impl<G: Float> Selection<Vec<G>> for CheckChromoInterval<G> {
     fn kill(&mut self, population: &mut Population<Vec<G>>) {
         for individual in population.iter_mut() {
-            assert_eq!(individual.get_chromosomes().len(), self.intervals.len());
-
-            for (chromo, interval) in individual
-                .get_chromosomes()
-                .iter()
-                .zip(self.intervals.iter())
-            {
-                if !chromo.is_finite() || *chromo < interval.0 || *chromo > interval.1 {
-                    individual.kill();
-                    break;
+            if !self.intervals.contains(individual.get_chromosomes()) {
+                individual.kill();
+            }
+        }
+    }
+}
+
+/// Fitness sharing selection to keep several optima (niches) in the population.
+///
+/// The selection kills individuals while count of the live individuals greater than `max_count`.
+/// Quality of every individual is `1 / (1 + |fitness - best_fitness|)` and the quality is divided by
+/// the niche count `m_i = sum(sh(d_ij))`, where `d_ij` is euclidean distance between chromosomes
+/// of individuals, `sh(d) = 1 - (d / radius) ^ alpha` if `d < radius` and `sh(d) = 0` otherwise.
+/// The individual with the least shared quality is killed and niche counts are updated, so
+/// individuals from crowded niches are killed first. The best individual is never killed.
+///
+/// `G` - type of gene.
+pub struct FitnessSharing<G: Float> {
+    max_count: usize,
+    radius: G,
+    alpha: G,
+}
+
+impl<G: Float> FitnessSharing<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `max_count` - max count of the live individuals after selection.
+    /// * `radius` - niche radius. `radius` must be greater than 0.
+    /// * `alpha` - sharing exponent (1 is the common value).
+    pub fn new(max_count: usize, radius: G, alpha: G) -> Self {
+        assert!(radius > G::zero());
+        Self {
+            max_count,
+            radius,
+            alpha,
+        }
+    }
+
+    fn sharing(&self, chromo_1: &[G], chromo_2: &[G]) -> f64 {
+        let distance = distance(chromo_1, chromo_2);
+
+        if distance < self.radius {
+            (G::one() - (distance / self.radius).powf(self.alpha))
+                .to_f64()
+                .unwrap()
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<G: Float> Selection<Vec<G>> for FitnessSharing<G> {
+    fn kill(&mut self, population: &mut Population<Vec<G>>) {
+        let alive: Vec<usize> = (0..population.len())
+            .filter(|&n| population[n].is_alive())
+            .collect();
+
+        if alive.len() <= self.max_count {
+            return;
+        }
+
+        let objective = population.get_objective();
+
+        // Index in the alive list of the best individual. NaN is worse than others.
+        let mut best = 0;
+        for i in 1..alive.len() {
+            let fitness = population[alive[i]].get_fitness();
+            let best_fitness = population[alive[best]].get_fitness();
+            if objective.is_better(fitness, best_fitness) {
+                best = i;
+            }
+        }
+
+        let best_fitness = population[alive[best]].get_fitness();
+        let quality: Vec<f64> = alive
+            .iter()
+            .map(|&n| {
+                let fitness = population[n].get_fitness();
+                let delta = match objective {
+                    Objective::Minimize => fitness - best_fitness,
+                    Objective::Maximize => best_fitness - fitness,
+                };
+                1.0 / (1.0 + delta)
+            })
+            .collect();
+
+        let sharing: Vec<Vec<f64>> = alive
+            .iter()
+            .map(|&i| {
+                alive
+                    .iter()
+                    .map(|&j| {
+                        self.sharing(
+                            population[i].get_chromosomes(),
+                            population[j].get_chromosomes(),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut niche_count: Vec<f64> = sharing.iter().map(|row| row.iter().sum()).collect();
+        let mut killed = vec![false; alive.len()];
+        let kill_count = (alive.len() - self.max_count).min(alive.len() - 1);
+
+        for _ in 0..kill_count {
+            // Index in the alive list of the individual with the least shared quality.
+            // NaN is worse than others.
+            let mut worst: Option<(usize, f64)> = None;
+            for i in 0..alive.len() {
+                if killed[i] || i == best {
+                    continue;
                 }
+
+                let shared = quality[i] / niche_count[i];
+                worst = match worst {
+                    None => Some((i, shared)),
+                    Some((_, worst_shared))
+                        if shared < worst_shared || (shared.is_nan() && !worst_shared.is_nan()) =>
+                    {
+                        Some((i, shared))
+                    }
+                    _ => worst,
+                };
+            }
+
+            let (worst, _) = worst.unwrap();
+            killed[worst] = true;
+            population[alive[worst]].kill();
+
+            for (count, row) in niche_count.iter_mut().zip(sharing.iter()) {
+                *count -= row[worst];
+            }
+        }
+    }
+}
+
+/// Selection to limit count of the individuals in every niche (cluster in the chromosomes
+/// space) instead of the total count of the individuals, so several optima stay in the
+/// population.
+///
+/// The alive individuals are sorted from the best to the worst. Every individual joins the first
+/// niche which leader (the best individual of the niche) is closer than `radius` (euclidean
+/// distance), otherwise the individual becomes the leader of a new niche. The individuals beyond
+/// `max_niche_count` in every niche are killed, so the best individual of every niche survives.
+///
+/// `G` - type of gene.
+pub struct NicheCapSelection<G: Float> {
+    radius: G,
+    max_niche_count: usize,
+}
+
+impl<G: Float> NicheCapSelection<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `radius` - niche radius. `radius` must be greater than 0.
+    /// * `max_niche_count` - max count of the live individuals in every niche after selection.
+    ///   Must be greater than 0.
+    pub fn new(radius: G, max_niche_count: usize) -> Self {
+        assert!(radius > G::zero());
+        assert!(max_niche_count > 0);
+        Self {
+            radius,
+            max_niche_count,
+        }
+    }
+}
+
+impl<G: Float> Selection<Vec<G>> for NicheCapSelection<G> {
+    fn kill(&mut self, population: &mut Population<Vec<G>>) {
+        let objective = population.get_objective();
+
+        // Indexes of the alive individuals from the best to the worst
+        let mut alive: Vec<usize> = (0..population.len())
+            .filter(|&n| population[n].is_alive())
+            .collect();
+
+        alive.sort_by(|&n, &m| {
+            let fitness_n = population[n].get_fitness();
+            let fitness_m = population[m].get_fitness();
+
+            if objective.is_better(fitness_n, fitness_m) {
+                Ordering::Less
+            } else if objective.is_better(fitness_m, fitness_n) {
+                Ordering::Greater
+            } else {
+                n.cmp(&m)
+            }
+        });
+
+        // Tuples (index of the leader, count of the individuals) for every niche
+        let mut niches: Vec<(usize, usize)> = vec![];
+        for n in alive {
+            let niche = niches.iter_mut().find(|(leader, _)| {
+                distance(
+                    population[*leader].get_chromosomes(),
+                    population[n].get_chromosomes(),
+                ) < self.radius
+            });
+
+            match niche {
+                None => niches.push((n, 1)),
+                Some((_, count)) if *count < self.max_niche_count => *count += 1,
+                Some(_) => population[n].kill(),
+            }
+        }
+    }
+}
+
+/// Returns euclidean distance between chromosomes.
+fn distance<G: Float>(chromo_1: &[G], chromo_2: &[G]) -> G {
+    assert_eq!(chromo_1.len(), chromo_2.len());
+    chromo_1
+        .iter()
+        .zip(chromo_2.iter())
+        .fold(G::zero(), |acc, (x1, x2)| acc + (*x1 - *x2).powi(2))
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Goal;
+
+    /// The goal returns 1 for all points except the origin.
+    struct FlatGoal;
+
+    impl Goal<Vec<f64>> for FlatGoal {
+        fn get(&mut self, x: &Vec<f64>) -> f64 {
+            if x.iter().all(|xi| *xi == 0.0) {
+                0.0
+            } else {
+                1.0
             }
         }
     }
+
+    fn alive_chromosomes(population: &Population<Vec<f64>>) -> Vec<Vec<f64>> {
+        population
+            .iter()
+            .filter(|individual| individual.is_alive())
+            .map(|individual| individual.get_chromosomes().clone())
+            .collect()
+    }
+
+    #[test]
+    fn fitness_sharing_two_clusters() {
+        let mut population = Population::new(Box::new(FlatGoal));
+
+        // 8 individuals near (1, 1) and 4 individuals near (10, 10)
+        for n in 0..8 {
+            population.push(vec![1.0 + n as f64 * 0.01, 1.0]);
+        }
+        for n in 0..4 {
+            population.push(vec![10.0, 10.0 + n as f64 * 0.01]);
+        }
+
+        FitnessSharing::new(6, 1.0, 1.0).kill(&mut population);
+
+        let survivors = alive_chromosomes(&population);
+        let first_count = survivors.iter().filter(|x| x[0] < 5.0).count();
+        let second_count = survivors.len() - first_count;
+
+        assert_eq!(survivors.len(), 6);
+        assert_eq!(first_count, 3);
+        assert_eq!(second_count, 3);
+    }
+
+    #[test]
+    fn fitness_sharing_keep_best() {
+        let mut population = Population::new(Box::new(FlatGoal));
+        population.push(vec![0.1, 0.0]);
+        population.push(vec![0.0, 0.0]);
+        population.push(vec![0.0, 0.1]);
+        population.push(vec![5.0, 5.0]);
+
+        FitnessSharing::new(0, 1.0, 1.0).kill(&mut population);
+
+        assert_eq!(alive_chromosomes(&population), vec![vec![0.0, 0.0]]);
+    }
+
+    #[test]
+    fn fitness_sharing_not_enough() {
+        let mut population = Population::new(Box::new(FlatGoal));
+        population.push(vec![1.0, 1.0]);
+        population.push(vec![1.0, 1.0]);
+
+        FitnessSharing::new(2, 1.0, 1.0).kill(&mut population);
+
+        assert_eq!(population.len_alive(), 2);
+    }
+
+    /// The goal function with minimums in (0, 0) and (10, 10).
+    struct TwoMinimumsGoal;
+
+    impl Goal<Vec<f64>> for TwoMinimumsGoal {
+        fn get(&mut self, x: &Vec<f64>) -> f64 {
+            let first = x[0] * x[0] + x[1] * x[1];
+            let second = (x[0] - 10.0).powi(2) + (x[1] - 10.0).powi(2);
+            first.min(second)
+        }
+    }
+
+    #[test]
+    fn niche_cap_two_clusters() {
+        let mut population = Population::new(Box::new(TwoMinimumsGoal));
+        population.append(vec![
+            vec![0.3, 0.0],
+            vec![10.5, 10.0],
+            vec![0.0, 0.2],
+            vec![0.0, 0.0],
+            vec![10.0, 10.05],
+            vec![0.0, 0.4],
+            vec![10.2, 10.0],
+            vec![0.1, 0.0],
+        ]);
+
+        NicheCapSelection::new(1.0, 2).kill(&mut population);
+
+        assert_eq!(
+            alive_chromosomes(&population),
+            vec![
+                vec![0.0, 0.0],
+                vec![10.0, 10.05],
+                vec![10.2, 10.0],
+                vec![0.1, 0.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn niche_cap_not_enough() {
+        let mut population = Population::new(Box::new(FlatGoal));
+        population.push(vec![1.0, 1.0]);
+        population.push(vec![1.0, 1.0]);
+        population.push(vec![5.0, 5.0]);
+
+        NicheCapSelection::new(1.0, 2).kill(&mut population);
+
+        assert_eq!(population.len_alive(), 3);
+    }
 }
@@ -0,0 +1,299 @@
+//! The module with the grid search algorithm. The algorithm calculates the goal function on
+//! all nodes of a regular grid and returns the best node. The number of the nodes grows
+//! exponentially with dimension, so the algorithm is intended for low-dimensional problems and
+//! for sanity-checking of other optimizers.
+//!
+//! The nodes are enumerated row by row: a row contains all nodes with different values of the
+//! last coordinate and the same values of other coordinates. One row is one iteration of the
+//! algorithm for the loggers and `AlgorithmState`.
+
+use crate::tools::logging::Logger;
+use crate::tools::Intervals;
+use crate::{AlgorithmState, Goal, Objective, Optimizer, Solution};
+
+/// Max count of the grid nodes for `GridSearchOptimizer`.
+pub const MAX_GRID_POINTS: usize = 10_000_000;
+
+/// The state of `GridSearchOptimizer`: the best node so far and the number of the calculated
+/// rows of the grid.
+pub struct GridSearchState {
+    best_solution: Option<Solution<Vec<f64>>>,
+    iteration: usize,
+}
+
+impl AlgorithmState<Vec<f64>> for GridSearchState {
+    fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+        self.best_solution.clone()
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+}
+
+/// The optimizer calculates the goal function on all nodes of the grid and returns the node
+/// with minimal value of the goal function.
+pub struct GridSearchOptimizer<'a> {
+    goal: Box<dyn Goal<Vec<f64>> + 'a>,
+
+    /// The coordinates of the nodes for every dimension.
+    axes: Vec<Vec<f64>>,
+    loggers: Vec<Box<dyn Logger<Vec<f64>> + 'a>>,
+    state: GridSearchState,
+}
+
+impl<'a> GridSearchOptimizer<'a> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `intervals` - vector of tuples (min, max) for every dimension. The bounds are included
+    ///   in the grid.
+    /// * `points_count` - count of the nodes for every dimension. The nodes are uniformly
+    ///   distributed in the interval. If the count is 1, the node is in the middle of the
+    ///   interval. Length of the vector must be equal to length of `intervals`.
+    ///
+    /// The constructor panics if total count of the nodes is greater than `MAX_GRID_POINTS`.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(
+        goal: Box<dyn Goal<Vec<f64>> + 'a>,
+        intervals: Vec<(f64, f64)>,
+        points_count: Vec<usize>,
+    ) -> Self {
+        assert!(!intervals.is_empty());
+        assert_eq!(intervals.len(), points_count.len());
+        assert!(points_count.iter().all(|&count| count > 0));
+
+        let total = points_count
+            .iter()
+            .try_fold(1_usize, |total, &count| total.checked_mul(count));
+        assert!(
+            matches!(total, Some(total) if total <= MAX_GRID_POINTS),
+            "Too many nodes of the grid. The limit is {}",
+            MAX_GRID_POINTS
+        );
+
+        let intervals = Intervals::new(intervals).unwrap();
+        let axes = intervals
+            .iter()
+            .zip(points_count.iter())
+            .map(|(&(min, max), &count)| {
+                if count == 1 {
+                    vec![(min + max) / 2.0]
+                } else {
+                    (0..count)
+                        .map(|i| min + (max - min) * i as f64 / (count - 1) as f64)
+                        .collect()
+                }
+            })
+            .collect();
+
+        Self {
+            goal,
+            axes,
+            loggers: vec![],
+            state: GridSearchState {
+                best_solution: None,
+                iteration: 0,
+            },
+        }
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Vec<f64>> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Returns the state of the last running.
+    pub fn get_state(&self) -> &GridSearchState {
+        &self.state
+    }
+
+    /// Returns true if the iterations limit `max_iterations` is reached.
+    fn is_budget_spent(&self, max_iterations: Option<usize>) -> bool {
+        match max_iterations {
+            None => false,
+            Some(max_iterations) => self.state.iteration >= max_iterations,
+        }
+    }
+
+    /// Calculate the goal function for the nodes of the grid row by row. If `max_iterations` is
+    /// not None, no more than `max_iterations` rows are calculated.
+    fn search(&mut self, max_iterations: Option<usize>) -> Option<Solution<Vec<f64>>> {
+        self.state.best_solution = None;
+        self.state.iteration = 0;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.state);
+        }
+
+        let dimension = self.axes.len();
+        let rows_count: usize = self.axes[..dimension - 1]
+            .iter()
+            .map(|axis| axis.len())
+            .product();
+
+        for row in 0..rows_count {
+            if self.is_budget_spent(max_iterations) {
+                break;
+            }
+
+            self.goal.set_generation(row);
+            self.search_row(row);
+            self.state.iteration += 1;
+
+            for logger in &mut self.loggers {
+                logger.next_iteration(&self.state);
+            }
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.state);
+        }
+
+        self.state.best_solution.clone()
+    }
+
+    /// Calculate the goal function for the nodes of the row with index `row`.
+    fn search_row(&mut self, row: usize) {
+        let dimension = self.axes.len();
+
+        // Indexes of the node for all dimensions except the last
+        let mut rest = row;
+        let mut point = vec![0.0; dimension];
+        for n in (0..dimension - 1).rev() {
+            let count = self.axes[n].len();
+            point[n] = self.axes[n][rest % count];
+            rest /= count;
+        }
+
+        for i in 0..self.axes[dimension - 1].len() {
+            point[dimension - 1] = self.axes[dimension - 1][i];
+            let value = self.goal.get(&point);
+
+            let is_better = match &self.state.best_solution {
+                None => !value.is_nan(),
+                Some((_, best_value)) => Objective::Minimize.is_better(value, *best_value),
+            };
+
+            if is_better {
+                self.state.best_solution = Some((point.clone(), value));
+            }
+        }
+    }
+}
+
+impl<'a> Optimizer<Vec<f64>> for GridSearchOptimizer<'a> {
+    fn find_min(&mut self) -> Option<Solution<Vec<f64>>> {
+        self.search(None)
+    }
+
+    /// The limit is the count of the calculated rows of the grid.
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<Vec<f64>>> {
+        self.search(Some(max_iterations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::statistics::{Statistics, StatisticsLogger};
+    use crate::GoalFromFunction;
+
+    /// The paraboloid with minimum at (0.35, -0.62).
+    fn paraboloid(x: &[f64]) -> f64 {
+        (x[0] - 0.35) * (x[0] - 0.35) + (x[1] + 0.62) * (x[1] + 0.62)
+    }
+
+    fn paraboloid_goal() -> GoalFromFunction<Vec<f64>> {
+        GoalFromFunction::new(|x: &Vec<f64>| paraboloid(x))
+    }
+
+    #[test]
+    fn paraboloid_2d() {
+        let mut optimizer = GridSearchOptimizer::new(
+            Box::new(paraboloid_goal()),
+            vec![(-1.0, 1.0), (-1.0, 1.0)],
+            vec![11, 11],
+        );
+
+        // The nearest node is (0.4, -0.6)
+        let (solution, goal) = optimizer.find_min().unwrap();
+        assert!((solution[0] - 0.4).abs() < 1e-10);
+        assert!((solution[1] + 0.6).abs() < 1e-10);
+        assert!((goal - paraboloid(&[0.4, -0.6])).abs() < 1e-10);
+        assert_eq!(optimizer.get_state().get_iteration(), 11);
+    }
+
+    #[test]
+    fn find_min_with_budget() {
+        let mut optimizer = GridSearchOptimizer::new(
+            Box::new(paraboloid_goal()),
+            vec![(-1.0, 1.0), (-1.0, 1.0)],
+            vec![11, 11],
+        );
+
+        // The rows with the first coordinate -1.0, -0.8 and -0.6 only
+        let (solution, _) = optimizer.find_min_with_budget(3).unwrap();
+        assert!((solution[0] + 0.6).abs() < 1e-10);
+        assert!((solution[1] + 0.6).abs() < 1e-10);
+        assert_eq!(optimizer.get_state().get_iteration(), 3);
+
+        // The limit is removed after the call
+        optimizer.find_min();
+        assert_eq!(optimizer.get_state().get_iteration(), 11);
+    }
+
+    #[test]
+    fn single_node() {
+        let mut optimizer = GridSearchOptimizer::new(
+            Box::new(paraboloid_goal()),
+            vec![(0.0, 1.0), (-2.0, 2.0)],
+            vec![1, 5],
+        );
+
+        let (solution, _) = optimizer.find_min().unwrap();
+        assert_eq!(solution, vec![0.5, -1.0]);
+        assert_eq!(optimizer.get_state().get_iteration(), 1);
+    }
+
+    #[test]
+    fn loggers() {
+        let mut statistics = Statistics::new();
+        {
+            let mut optimizer = GridSearchOptimizer::new(
+                Box::new(paraboloid_goal()),
+                vec![(-1.0, 1.0), (-1.0, 1.0), (0.0, 1.0)],
+                vec![3, 4, 2],
+            );
+            optimizer.set_loggers(vec![Box::new(StatisticsLogger::new(&mut statistics))]);
+            optimizer.find_min();
+        }
+
+        // One row for every pair of the first and the second coordinates
+        assert_eq!(statistics.get_run_count(), 1);
+        assert_eq!(statistics.get_convergence()[0].len(), 12);
+    }
+
+    #[test]
+    fn nan_goal() {
+        let mut optimizer = GridSearchOptimizer::new(
+            Box::new(GoalFromFunction::new(|_x: &Vec<f64>| f64::NAN)),
+            vec![(-1.0, 1.0)],
+            vec![5],
+        );
+
+        assert_eq!(optimizer.find_min(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_points() {
+        GridSearchOptimizer::new(
+            Box::new(paraboloid_goal()),
+            vec![(-1.0, 1.0); 3],
+            vec![1000; 3],
+        );
+    }
+}
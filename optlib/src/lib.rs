@@ -2,8 +2,14 @@
 //! The crate uses common traits for easy switch between algorithms.
 extern crate num;
 
+use std::collections::HashMap;
+
+use num::NumCast;
+
 pub mod genetic;
+pub mod gridsearch;
 pub mod particleswarm;
+pub mod randomsearch;
 pub mod tools;
 
 type GoalValue = f64;
@@ -22,8 +28,47 @@ pub trait Optimizer<T> {
     /// `goal` - value of goal function. Returns `None` if an algoritm can't find minimum of a goal function.
     ///
     /// # Remarks
-    /// All algorithms with `Optimizer` must search minimum of a goal function.
+    /// All algorithms with `Optimizer` must search minimum of a goal function by default.
+    /// Algorithms may search maximum if they support `Objective::Maximize`.
     fn find_min(&mut self) -> Option<Solution<T>>;
+
+    /// Run an algorithm as `find_min()`, but stop it after `max_iterations` iterations at the
+    /// latest. The stop checker of the optimizer works as usual, so the algorithm may stop
+    /// earlier. The iterations limit is applied to the current call only.
+    ///
+    /// The optimizers which run other optimizers (`BestOfOptimizers`, `SequentialOptimizer`,
+    /// `MetaOptimizer`) pass the limit to every inner optimizer.
+    ///
+    /// By default the method ignores the limit and calls `find_min()`. All optimizers of the
+    /// crate override the method.
+    fn find_min_with_budget(&mut self, _max_iterations: usize) -> Option<Solution<T>> {
+        self.find_min()
+    }
+}
+
+/// The objective of optimization: search minimum or maximum of a goal function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Objective {
+    #[default]
+    Minimize,
+    Maximize,
+}
+
+impl Objective {
+    /// Returns true if `goal_1` is strictly better than `goal_2` (less for `Minimize` and greater
+    /// for `Maximize`). NaN is worse than other values.
+    pub fn is_better(&self, goal_1: GoalValue, goal_2: GoalValue) -> bool {
+        if goal_1.is_nan() {
+            false
+        } else if goal_2.is_nan() {
+            true
+        } else {
+            match self {
+                Objective::Minimize => goal_1 < goal_2,
+                Objective::Maximize => goal_1 > goal_2,
+            }
+        }
+    }
 }
 
 /// The trait for iterative algorithms.
@@ -34,6 +79,41 @@ pub trait IterativeOptimizer<T> {
     fn next_iterations(&mut self) -> Option<Solution<T>>;
 }
 
+/// The trait to read and write the scalar parameters of an algorithm by name. The trait may be
+/// used to tune the parameters by other (meta) optimizer.
+pub trait Tunable {
+    /// Returns the current values of the parameters.
+    fn get_params(&self) -> HashMap<String, f64>;
+
+    /// Set the values of the parameters. The unknown names and the values which are not
+    /// finite or are out of range of the parameter type are ignored.
+    fn set_params(&mut self, params: &HashMap<String, f64>);
+}
+
+/// Set `value` to the parameter `name` from `params` if the parameter exists. The value is
+/// ignored if it is not finite or can't be represented by `T`.
+fn update_param<T: NumCast>(params: &HashMap<String, f64>, name: &str, value: &mut T) {
+    if let Some(new_value) = params.get(name).and_then(|&x| cast_param(x)) {
+        *value = new_value;
+    }
+}
+
+/// Set `value` of integer type to the parameter `name` from `params` rounded to the nearest
+/// integer if the parameter exists. The value is ignored as by `update_param()`.
+fn update_int_param<T: NumCast>(params: &HashMap<String, f64>, name: &str, value: &mut T) {
+    if let Some(new_value) = params.get(name).and_then(|&x| cast_param(x.round())) {
+        *value = new_value;
+    }
+}
+
+fn cast_param<T: NumCast>(value: f64) -> Option<T> {
+    if value.is_finite() {
+        T::from(value)
+    } else {
+        None
+    }
+}
+
 /// The trait for a struct with information about current algorithm state.
 /// For example: population for genetic algorithm, swarm for particle swarm algorithm etc
 ///
@@ -41,6 +121,22 @@ pub trait IterativeOptimizer<T> {
 pub trait AlgorithmState<T> {
     fn get_best_solution(&self) -> Option<Solution<T>>;
     fn get_iteration(&self) -> usize;
+
+    /// Returns the worst solution if the algorithm tracks it or None otherwise.
+    fn get_worst_solution(&self) -> Option<Solution<T>> {
+        None
+    }
+
+    /// Returns references to all agents if the algorithm uses agents (see `AgentsState`)
+    /// or None otherwise.
+    fn get_agent_list(&self) -> Option<Vec<&dyn Agent<T>>> {
+        None
+    }
+
+    /// Returns the objective of the algorithm (minimization by default).
+    fn get_objective(&self) -> Objective {
+        Objective::Minimize
+    }
 }
 
 /// The trait for algotithms where use agents (genetic algorithm, partical swarm algorithm etc).
@@ -68,6 +164,24 @@ pub trait Agent<T> {
 pub trait Goal<T> {
     /// Must return value of goal function for the point in the search space (x).
     fn get(&mut self, x: &T) -> GoalValue;
+
+    /// The optimizers call the method before every iteration (and with 0 before creating of the
+    /// initial agents). The method may be used by goal functions which depend on iteration number
+    /// (for example, `AdaptivePenaltyGoal`). The wrappers must pass the generation to the inner
+    /// goal function.
+    fn set_generation(&mut self, _generation: usize) {}
+}
+
+/// The implementation allows to pass a goal function to an optimizer by mutable reference
+/// (`Box::new(&mut goal)`) and to use the goal function after optimization.
+impl<T, G: Goal<T> + ?Sized> Goal<T> for &mut G {
+    fn get(&mut self, x: &T) -> GoalValue {
+        (**self).get(x)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        (**self).set_generation(generation)
+    }
 }
 
 /// Struct to convert (wrap) function to `Goal` trait.
@@ -87,3 +201,103 @@ impl<T> Goal<T> for GoalFromFunction<T> {
         (self.function)(x)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The optimizer without own `find_min_with_budget()`.
+    struct ConstOptimizer {
+        calls: usize,
+    }
+
+    impl Optimizer<f64> for ConstOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.calls += 1;
+            Some((1.0, 2.0))
+        }
+    }
+
+    #[test]
+    fn find_min_with_budget_default() {
+        let mut optimizer = ConstOptimizer { calls: 0 };
+        assert_eq!(optimizer.find_min_with_budget(5), Some((1.0, 2.0)));
+        assert_eq!(optimizer.calls, 1);
+    }
+
+    fn create_params(name: &str, value: f64) -> HashMap<String, f64> {
+        let mut params = HashMap::new();
+        params.insert(name.to_string(), value);
+        params
+    }
+
+    #[test]
+    fn update_param_float() {
+        let mut value = 1.0_f64;
+        update_param(&create_params("x", 2.5), "x", &mut value);
+        assert_eq!(value, 2.5);
+
+        update_param(&create_params("y", 3.0), "x", &mut value);
+        assert_eq!(value, 2.5);
+
+        update_param(&create_params("x", f64::NAN), "x", &mut value);
+        assert_eq!(value, 2.5);
+
+        update_param(&create_params("x", f64::INFINITY), "x", &mut value);
+        assert_eq!(value, 2.5);
+    }
+
+    #[test]
+    fn update_int_param_round() {
+        let mut value = 10_usize;
+        update_int_param(&create_params("x", 19.7), "x", &mut value);
+        assert_eq!(value, 20);
+
+        update_int_param(&create_params("x", 19.2), "x", &mut value);
+        assert_eq!(value, 19);
+
+        update_int_param(&create_params("x", -0.3), "x", &mut value);
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    fn update_int_param_invalid() {
+        let mut value = 10_usize;
+        update_int_param(&create_params("x", -3.0), "x", &mut value);
+        assert_eq!(value, 10);
+
+        update_int_param(&create_params("x", f64::NAN), "x", &mut value);
+        assert_eq!(value, 10);
+
+        update_int_param(&create_params("x", 1e30), "x", &mut value);
+        assert_eq!(value, 10);
+
+        let mut value = 10_u8;
+        update_int_param(&create_params("x", 300.0), "x", &mut value);
+        assert_eq!(value, 10);
+    }
+
+    #[test]
+    fn objective_minimize() {
+        let objective = Objective::default();
+        assert_eq!(objective, Objective::Minimize);
+
+        assert!(objective.is_better(1.0, 2.0));
+        assert!(!objective.is_better(2.0, 1.0));
+        assert!(!objective.is_better(1.0, 1.0));
+        assert!(objective.is_better(1.0, f64::NAN));
+        assert!(!objective.is_better(f64::NAN, 1.0));
+        assert!(!objective.is_better(f64::NAN, f64::NAN));
+    }
+
+    #[test]
+    fn objective_maximize() {
+        let objective = Objective::Maximize;
+
+        assert!(objective.is_better(2.0, 1.0));
+        assert!(!objective.is_better(1.0, 2.0));
+        assert!(!objective.is_better(1.0, 1.0));
+        assert!(objective.is_better(1.0, f64::NAN));
+        assert!(!objective.is_better(f64::NAN, 1.0));
+    }
+}
@@ -1,36 +1,287 @@
-use num::{NumCast, Zero};
+use num::{Float, NumCast, Zero};
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::ThreadRng;
+use rand::Rng;
 
-use crate::tools::RandomVectorCreator;
+use crate::tools::statistics::Statistics;
+use crate::tools::{Intervals, RandomVectorCreator};
 use crate::particleswarm::{CoordinatesInitializer, VelocityInitializer};
 
 /// The struct to initialize particles coordinates with random value from given intervals.
 pub struct RandomCoordinatesInitializer<T> {
     // Intervals for every dimension. Size of the vector must be equal to dimension.
-    // The first value in tuple is minimum value, the second value is maximum value.
-    intervals: Vec<(T, T)>,
+    intervals: Intervals<T>,
     particles_count: usize,
     vector_creator: RandomVectorCreator,
 }
 
-impl<T> RandomCoordinatesInitializer<T> {
+impl<T: PartialOrd> RandomCoordinatesInitializer<T> {
     /// Constructor.
     ///
     /// # Parameters
     /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
     /// `particles_count` - how many particles do you need to create.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(intervals: Vec<(T, T)>, particles_count: usize) -> Self {
         Self {
-            intervals,
+            intervals: Intervals::new(intervals).unwrap(),
             particles_count,
             vector_creator: RandomVectorCreator::new(),
         }
     }
+
+    /// Set the seed of the random generator to get the same coordinates for every running.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.vector_creator = RandomVectorCreator::new_with_seed(seed);
+        self
+    }
 }
 
 impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for RandomCoordinatesInitializer<T> {
     fn get_coordinates(&mut self) -> Vec<Vec<T>> {
         (0..self.particles_count)
-            .map(|_| self.vector_creator.create_vec(&self.intervals))
+            .map(|_| self.vector_creator.create_vec(self.intervals.as_vec()))
+            .collect()
+    }
+}
+
+/// The struct to initialize particles coordinates near the solutions found by previous runnings
+/// (warm restart). Every solution from statistics gives one particle with random offset in
+/// [-radius; radius] for every coordinate. If there are more solutions than particles, the best
+/// solutions are used. The remaining particles are placed randomly within intervals.
+/// All coordinates are moved inside the intervals.
+pub struct FromStatisticsInitializer<T> {
+    solutions: Vec<Vec<T>>,
+    radius: T,
+    intervals: Intervals<T>,
+    particles_count: usize,
+    vector_creator: RandomVectorCreator,
+    random: ThreadRng,
+}
+
+impl<T: Float> FromStatisticsInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `statistics` - statistics with results of previous runnings.
+    /// `radius` - max offset of the particles from the solutions for every coordinate.
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `particles_count` - how many particles do you need to create.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(
+        statistics: &Statistics<Vec<T>>,
+        radius: T,
+        intervals: Vec<(T, T)>,
+        particles_count: usize,
+    ) -> Self {
+        assert!(radius >= T::zero());
+
+        let mut results: Vec<(Vec<T>, f64)> = statistics
+            .get_results()
+            .iter()
+            .filter_map(|result| result.clone())
+            .filter(|(_, goal)| !goal.is_nan())
+            .collect();
+        results.sort_by(|(_, goal_1), (_, goal_2)| goal_1.partial_cmp(goal_2).unwrap());
+
+        let solutions = results
+            .into_iter()
+            .take(particles_count)
+            .map(|(solution, _)| solution)
+            .collect();
+
+        Self {
+            solutions,
+            radius,
+            intervals: Intervals::new(intervals).unwrap(),
+            particles_count,
+            vector_creator: RandomVectorCreator::new(),
+            random: rand::thread_rng(),
+        }
+    }
+}
+
+impl<T: Float> CoordinatesInitializer<T> for FromStatisticsInitializer<T> {
+    fn get_coordinates(&mut self) -> Vec<Vec<T>> {
+        let radius = self.radius.to_f64().unwrap();
+        let mut coordinates = Vec::with_capacity(self.particles_count);
+
+        let random = &mut self.random;
+        for solution in self.solutions.iter() {
+            let mut point: Vec<T> = solution
+                .iter()
+                .map(|x| {
+                    let offset = if radius > 0.0 {
+                        Uniform::new_inclusive(-radius, radius).sample(random)
+                    } else {
+                        0.0
+                    };
+                    *x + T::from(offset).unwrap()
+                })
+                .collect();
+
+            self.intervals.clamp(&mut point);
+            coordinates.push(point);
+        }
+
+        while coordinates.len() < self.particles_count {
+            coordinates.push(self.vector_creator.create_vec(self.intervals.as_vec()));
+        }
+
+        coordinates
+    }
+}
+
+/// The struct to initialize particles coordinates with the quasi-random Halton sequence.
+/// Every dimension uses own prime base (2, 3, 5, 7, ...).
+/// The first `particles_count` points of the sequence (starting from index 1) are scaled to the
+/// intervals.
+///
+/// The sequence covers the search space better than random points for low dimensions
+/// (up to ~6 dimensions). For higher dimensions the points of the dimensions with large bases
+/// are correlated, so the coverage degrades.
+pub struct HaltonInitializer<T> {
+    // Intervals for every dimension. Size of the vector must be equal to dimension.
+    intervals: Intervals<T>,
+    particles_count: usize,
+}
+
+impl<T: PartialOrd> HaltonInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `particles_count` - how many particles do you need to create.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(T, T)>, particles_count: usize) -> Self {
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            particles_count,
+        }
+    }
+}
+
+impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for HaltonInitializer<T> {
+    fn get_coordinates(&mut self) -> Vec<Vec<T>> {
+        let bases = get_primes(self.intervals.len());
+
+        (1..=self.particles_count)
+            .map(|index| {
+                self.intervals
+                    .iter()
+                    .zip(bases.iter())
+                    .map(|((min, max), &base)| {
+                        let min = min.to_f64().unwrap();
+                        let max = max.to_f64().unwrap();
+                        T::from(min + halton(index, base) * (max - min)).unwrap()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Returns `index`-th item of the Halton sequence with base `base`. Result lies in (0; 1).
+fn halton(mut index: usize, base: usize) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0;
+    while index > 0 {
+        fraction /= base as f64;
+        result += fraction * (index % base) as f64;
+        index /= base;
+    }
+
+    result
+}
+
+/// Returns first `count` prime numbers.
+fn get_primes(count: usize) -> Vec<usize> {
+    let mut primes: Vec<usize> = Vec::with_capacity(count);
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes.iter().all(|&prime| candidate % prime != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+
+    primes
+}
+
+/// The struct to initialize particles coordinates with the symmetric Beta(a, a) distribution
+/// scaled to the intervals. If `a` < 1 the coordinates are concentrated near the bounds of the
+/// intervals, so the initializer is useful if the optimum lies near the bounds.
+/// If `a` = 1 the coordinates are distributed uniformly.
+pub struct EdgeBiasedInitializer<T> {
+    // Intervals for every dimension. Size of the vector must be equal to dimension.
+    intervals: Intervals<T>,
+    particles_count: usize,
+    shape: f64,
+    random: ThreadRng,
+}
+
+impl<T: Float> EdgeBiasedInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `particles_count` - how many particles do you need to create.
+    /// `shape` - the parameter `a` of the Beta(a, a) distribution. The value must be in (0; 1].
+    /// The less the value, the more particles are placed near the bounds.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(T, T)>, particles_count: usize, shape: f64) -> Self {
+        assert!(shape > 0.0 && shape <= 1.0, "shape must be in (0; 1]");
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            particles_count,
+            shape,
+            random: rand::thread_rng(),
+        }
+    }
+
+    /// Returns random value from Beta(a, a) distribution by the Johnk's algorithm.
+    /// Logarithms are used to avoid underflow for small `a`.
+    fn sample_beta(&mut self) -> f64 {
+        loop {
+            // The values lie in (0; 1]
+            let u: f64 = 1.0 - self.random.gen::<f64>();
+            let v: f64 = 1.0 - self.random.gen::<f64>();
+
+            let log_x = u.ln() / self.shape;
+            let log_y = v.ln() / self.shape;
+            if log_x.exp() + log_y.exp() <= 1.0 {
+                let log_max = log_x.max(log_y);
+                let x = (log_x - log_max).exp();
+                let y = (log_y - log_max).exp();
+                return x / (x + y);
+            }
+        }
+    }
+}
+
+impl<T: Float> CoordinatesInitializer<T> for EdgeBiasedInitializer<T> {
+    fn get_coordinates(&mut self) -> Vec<Vec<T>> {
+        let intervals: Vec<(f64, f64)> = self
+            .intervals
+            .iter()
+            .map(|(min, max)| (min.to_f64().unwrap(), max.to_f64().unwrap()))
+            .collect();
+
+        (0..self.particles_count)
+            .map(|_| {
+                intervals
+                    .iter()
+                    .map(|(min, max)| T::from(min + self.sample_beta() * (max - min)).unwrap())
+                    .collect()
+            })
             .collect()
     }
 }
@@ -55,6 +306,12 @@ impl<T> RandomVelocityInitializer<T> {
             vector_creator: RandomVectorCreator::new(),
         }
     }
+
+    /// Set the seed of the random generator to get the same velocities for every running.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.vector_creator = RandomVectorCreator::new_with_seed(seed);
+        self
+    }
 }
 
 impl<T: NumCast + PartialOrd> VelocityInitializer<T> for RandomVelocityInitializer<T> {
@@ -65,6 +322,57 @@ impl<T: NumCast + PartialOrd> VelocityInitializer<T> for RandomVelocityInitializ
     }
 }
 
+/// The struct to initialize particles velocity with random values proportional to width of the
+/// intervals of the coordinates. Velocity for every dimension is in [-scale * width;
+/// scale * width], where `width = max - min` for the interval of the coordinate.
+pub struct IntervalScaledVelocityInitializer<T> {
+    // Intervals of the velocity for every dimension.
+    velocity_intervals: Vec<(T, T)>,
+    particles_count: usize,
+    vector_creator: RandomVectorCreator,
+}
+
+impl<T: Float> IntervalScaledVelocityInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `scale` - the factor for width of the intervals. Must be greater or equal than 0.
+    /// `particles_count` - how many particles do you need to create.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(T, T)>, scale: T, particles_count: usize) -> Self {
+        assert!(scale >= T::zero());
+
+        let intervals = Intervals::new(intervals).unwrap();
+        let velocity_intervals = (0..intervals.len())
+            .map(|i| intervals.width(i) * scale)
+            .map(|max_velocity| (-max_velocity, max_velocity))
+            .collect();
+
+        Self {
+            velocity_intervals,
+            particles_count,
+            vector_creator: RandomVectorCreator::new(),
+        }
+    }
+
+    /// Set the seed of the random generator to get the same velocities for every running.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.vector_creator = RandomVectorCreator::new_with_seed(seed);
+        self
+    }
+}
+
+impl<T: Float> VelocityInitializer<T> for IntervalScaledVelocityInitializer<T> {
+    fn get_velocity(&mut self) -> Vec<Vec<T>> {
+        (0..self.particles_count)
+            .map(|_| self.vector_creator.create_vec(&self.velocity_intervals))
+            .collect()
+    }
+}
+
 /// The struct to initialize particles velocity with zeros.
 pub struct ZeroVelocityInitializer {
     dimension: usize,
@@ -84,3 +392,239 @@ impl<T: NumCast + PartialOrd + Zero + Clone> VelocityInitializer<T> for ZeroVelo
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::logging::Logger;
+    use crate::tools::statistics::StatisticsLogger;
+    use crate::{AlgorithmState, Solution};
+
+    struct StateStub {
+        solution: Option<Solution<Vec<f64>>>,
+    }
+
+    impl AlgorithmState<Vec<f64>> for StateStub {
+        fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+            self.solution.clone()
+        }
+
+        fn get_iteration(&self) -> usize {
+            0
+        }
+    }
+
+    fn create_statistics(results: Vec<Option<Solution<Vec<f64>>>>) -> Statistics<Vec<f64>> {
+        let mut statistics = Statistics::new();
+        {
+            let mut logger = StatisticsLogger::new(&mut statistics);
+            for solution in results {
+                let state = StateStub { solution };
+                logger.start(&state);
+                logger.finish(&state);
+            }
+        }
+
+        statistics
+    }
+
+    #[test]
+    fn from_statistics_cluster() {
+        let statistics = create_statistics(vec![
+            Some((vec![1.0, 2.0], 0.5)),
+            None,
+            Some((vec![-3.0, 4.0], 0.1)),
+        ]);
+        let intervals = vec![(-10.0, 10.0), (-10.0, 10.0)];
+        let radius = 0.1;
+        let particles_count = 10;
+
+        let mut initializer =
+            FromStatisticsInitializer::new(&statistics, radius, intervals, particles_count);
+
+        // Tolerance for rounding errors
+        let radius = radius + 1e-10;
+
+        for _ in 0..100 {
+            let coordinates = initializer.get_coordinates();
+            assert_eq!(coordinates.len(), particles_count);
+
+            // The best solution is the first
+            assert!((coordinates[0][0] + 3.0).abs() <= radius);
+            assert!((coordinates[0][1] - 4.0).abs() <= radius);
+
+            assert!((coordinates[1][0] - 1.0).abs() <= radius);
+            assert!((coordinates[1][1] - 2.0).abs() <= radius);
+
+            for point in &coordinates[2..] {
+                assert!(point.iter().all(|x| (-10.0..=10.0).contains(x)));
+            }
+        }
+    }
+
+    #[test]
+    fn from_statistics_more_solutions() {
+        let statistics = create_statistics(vec![
+            Some((vec![1.0], 3.0)),
+            Some((vec![2.0], 1.0)),
+            Some((vec![3.0], 2.0)),
+        ]);
+
+        let mut initializer = FromStatisticsInitializer::new(&statistics, 0.0, vec![(0.0, 10.0)], 2);
+        assert_eq!(initializer.get_coordinates(), vec![vec![2.0], vec![3.0]]);
+    }
+
+    #[test]
+    fn from_statistics_clamp() {
+        let statistics = create_statistics(vec![Some((vec![1.0], 0.0))]);
+
+        let mut initializer = FromStatisticsInitializer::new(&statistics, 5.0, vec![(0.0, 1.5)], 1);
+        for _ in 0..100 {
+            let coordinates = initializer.get_coordinates();
+            assert!((0.0..=1.5).contains(&coordinates[0][0]));
+        }
+    }
+
+    #[test]
+    fn random_coordinates_seed() {
+        let intervals = vec![(-1.0, 1.0), (0.0, 10.0)];
+        let mut initializer_1 = RandomCoordinatesInitializer::new(intervals.clone(), 10).seed(7);
+        let mut initializer_2 = RandomCoordinatesInitializer::new(intervals, 10).seed(7);
+
+        let coordinates: Vec<Vec<f64>> = initializer_1.get_coordinates();
+        assert_eq!(coordinates, initializer_2.get_coordinates());
+    }
+
+    #[test]
+    fn random_velocity_seed() {
+        let intervals = vec![(-1.0, 1.0), (0.0, 10.0)];
+        let mut initializer_1 = RandomVelocityInitializer::new(intervals.clone(), 10).seed(7);
+        let mut initializer_2 = RandomVelocityInitializer::new(intervals, 10).seed(7);
+
+        let velocity: Vec<Vec<f64>> = initializer_1.get_velocity();
+        assert_eq!(velocity, initializer_2.get_velocity());
+    }
+
+    #[test]
+    fn interval_scaled_velocity() {
+        let intervals = vec![(-1.0, 1.0), (0.0, 10.0), (5.0, 5.0)];
+        let mut initializer = IntervalScaledVelocityInitializer::new(intervals, 0.5, 1000);
+
+        let velocity: Vec<Vec<f64>> = initializer.get_velocity();
+        assert_eq!(velocity.len(), 1000);
+        for particle_velocity in &velocity {
+            assert!(particle_velocity[0] >= -1.0 && particle_velocity[0] <= 1.0);
+            assert!(particle_velocity[1] >= -5.0 && particle_velocity[1] <= 5.0);
+            assert_eq!(particle_velocity[2], 0.0);
+        }
+
+        // The velocity is not concentrated in small part of the interval
+        assert!(velocity.iter().any(|v| v[1] < -4.0));
+        assert!(velocity.iter().any(|v| v[1] > 4.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn interval_scaled_velocity_negative_scale() {
+        IntervalScaledVelocityInitializer::new(vec![(0.0, 1.0)], -1.0, 10);
+    }
+
+    #[test]
+    fn primes() {
+        assert_eq!(get_primes(0), vec![]);
+        assert_eq!(get_primes(6), vec![2, 3, 5, 7, 11, 13]);
+    }
+
+    #[test]
+    fn halton_base_2() {
+        let mut initializer = HaltonInitializer::new(vec![(0.0, 1.0)], 3);
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+
+        assert_eq!(coordinates, vec![vec![0.5], vec![0.25], vec![0.75]]);
+    }
+
+    #[test]
+    fn halton_base_3() {
+        assert!((halton(1, 3) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((halton(2, 3) - 2.0 / 3.0).abs() < 1e-12);
+        assert!((halton(3, 3) - 1.0 / 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn halton_intervals() {
+        let particles_count = 100;
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0), (100.0, 110.0)];
+        let mut initializer = HaltonInitializer::new(intervals.clone(), particles_count);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        assert_eq!(coordinates.len(), particles_count);
+
+        for point in coordinates {
+            assert_eq!(point.len(), intervals.len());
+            for (x, (min, max)) in point.iter().zip(intervals.iter()) {
+                assert!(x >= min);
+                assert!(x <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn edge_biased_intervals() {
+        let particles_count = 100;
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0), (100.0, 110.0)];
+        let mut initializer = EdgeBiasedInitializer::new(intervals.clone(), particles_count, 0.1);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        assert_eq!(coordinates.len(), particles_count);
+
+        for point in coordinates {
+            assert_eq!(point.len(), intervals.len());
+            for (x, (min, max)) in point.iter().zip(intervals.iter()) {
+                assert!(x >= min);
+                assert!(x <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn edge_biased_outer_quantiles() {
+        let particles_count = 10000;
+        let mut initializer = EdgeBiasedInitializer::new(vec![(-10.0, 10.0)], particles_count, 0.3);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+
+        // The uniform distribution places 20 % of the points in the outer deciles.
+        // Beta(0.3, 0.3) places about 55 % of the points.
+        let outer_count = coordinates
+            .iter()
+            .filter(|point| point[0] < -8.0 || point[0] > 8.0)
+            .count();
+        let outer_frequency = outer_count as f64 / particles_count as f64;
+        assert!(outer_frequency > 0.45);
+        assert!(outer_frequency < 0.65);
+
+        // The distribution is symmetric
+        let mean = coordinates.iter().map(|point| point[0]).sum::<f64>() / particles_count as f64;
+        assert!(mean.abs() < 0.5);
+    }
+
+    #[test]
+    fn edge_biased_uniform() {
+        let particles_count = 10000;
+        let mut initializer = EdgeBiasedInitializer::new(vec![(0.0, 1.0)], particles_count, 1.0);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        let outer_count = coordinates
+            .iter()
+            .filter(|point| point[0] < 0.1 || point[0] > 0.9)
+            .count();
+        let outer_frequency = outer_count as f64 / particles_count as f64;
+        assert!((outer_frequency - 0.2).abs() < 0.03);
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_biased_invalid_shape() {
+        EdgeBiasedInitializer::new(vec![(0.0, 1.0)], 10, 1.5);
+    }
+}
@@ -1,16 +1,21 @@
 pub mod initializing;
+pub mod positionupdate;
 pub mod postmove;
 pub mod postvelocitycalc;
 pub mod velocitycalc;
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::f64;
 
 use num::Float;
 
 use crate::tools::logging::Logger;
 use crate::tools::stopchecker::StopChecker;
-use crate::{Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Optimizer, Solution};
+use crate::{
+    Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Objective, Optimizer, Solution,
+    Tunable,
+};
 
 type Velocity<T> = Vec<T>;
 type Coordinate<T> = Vec<T>;
@@ -21,6 +26,15 @@ type Coordinate<T> = Vec<T>;
 pub trait CoordinatesInitializer<T> {
     /// Must return vector of the start points for a new particles.
     fn get_coordinates(&mut self) -> Vec<Coordinate<T>>;
+
+    /// May return the initial personal best coordinates and values of the goal function for
+    /// the particles created by the last call of `get_coordinates()`. The optimizer calls the
+    /// method after `get_coordinates()`. The length of the vector must equal count of the
+    /// particles. The personal best is used if it is better than the start point of the particle.
+    /// The default implementation returns None (the personal best is the start point).
+    fn get_initial_personal_best(&mut self) -> Option<Vec<(Coordinate<T>, f64)>> {
+        None
+    }
 }
 
 /// The trait to create initial particles swarm.
@@ -37,15 +51,60 @@ pub trait VelocityInitializer<T> {
 pub trait PostMove<T> {
     /// The method may modify coordinates list before calculate goal function
     fn post_move(&mut self, coordinates: &mut Coordinate<T>);
+
+    /// The method may modify coordinates list and velocity of the particle before calculate goal
+    /// function. `ParticleSwarmOptimizer` calls this method. By default the method calls
+    /// `post_move()` and does not change the velocity.
+    fn post_move_velocity(&mut self, coordinates: &mut Coordinate<T>, _velocity: &mut Velocity<T>) {
+        self.post_move(coordinates);
+    }
+}
+
+/// The implementation allows to pass a `PostMove` to the optimizer by mutable reference
+/// (`Box::new(&mut post_move)`) and to use it after optimization.
+impl<T, P: PostMove<T> + ?Sized> PostMove<T> for &mut P {
+    fn post_move(&mut self, coordinates: &mut Coordinate<T>) {
+        (**self).post_move(coordinates)
+    }
+
+    fn post_move_velocity(&mut self, coordinates: &mut Coordinate<T>, velocity: &mut Velocity<T>) {
+        (**self).post_move_velocity(coordinates, velocity)
+    }
 }
 
 /// The trait to calculate new velocity vector for every particle
 pub trait VelocityCalculator<T> {
     fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Velocity<T>;
+
+    /// Calculate new velocity for the particle with `index` in the swarm. `ParticleSwarmOptimizer`
+    /// calls the method for every particle. The default implementation calls
+    /// `calc_new_velocity()`.
+    fn calc_new_velocity_by_index(&mut self, swarm: &Swarm<T>, index: usize) -> Velocity<T> {
+        self.calc_new_velocity(swarm, &swarm.particles[index])
+    }
+
+    /// Returns the tunable parameters of the velocity calculator (see `Tunable`).
+    fn get_params(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// Set the tunable parameters of the velocity calculator (see `Tunable`). The unknown names
+    /// must be ignored.
+    fn set_params(&mut self, _params: &HashMap<String, f64>) {}
+}
+
+/// The trait to calculate new coordinates of the particle instead of the velocity calculation
+/// (see `ParticleSwarmOptimizer::set_position_updater()`).
+pub trait PositionUpdater<T> {
+    fn calc_new_coordinates(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Coordinate<T>;
 }
 
 pub trait PostVelocityCalc<T> {
     fn correct_velocity(&mut self, velocity: Velocity<T>) -> Velocity<T>;
+
+    /// Will be called before velocity calculation for every iteration.
+    /// `iteration` - current iteration number of the swarm.
+    fn next_iteration(&mut self, _iteration: usize) {}
 }
 
 /// Struct for single point (agent) in the search space
@@ -111,20 +170,40 @@ impl<T: Clone> Particle<T> {
         }
     }
 
+    /// Recalculate values of the goal function for the current, the best and the worst
+    /// personal coordinates.
+    fn reevaluate(&mut self, goal: &mut dyn Goal<Coordinate<T>>, objective: Objective) {
+        self.value = goal.get(&self.coordinates);
+        self.best_personal_value = goal.get(&self.best_personal_coordinates);
+        self.worst_personal_value = goal.get(&self.worst_personal_coordinates);
+
+        let coordinates = self.coordinates.clone();
+        let value = self.value;
+        self.move_to(coordinates, value, objective);
+    }
+
+    /// Replace the personal best if `value` is better than the current personal best.
+    fn set_best_personal(&mut self, coordinates: Coordinate<T>, value: f64, objective: Objective) {
+        if compare_goals(value, self.best_personal_value, objective) == Ordering::Less {
+            self.best_personal_coordinates = coordinates;
+            self.best_personal_value = value;
+        }
+    }
+
     fn set_velocity(&mut self, velocity: Velocity<T>) {
         self.velocity = velocity;
     }
 
-    fn move_to(&mut self, new_coordinates: Coordinate<T>, value: f64) {
+    fn move_to(&mut self, new_coordinates: Coordinate<T>, value: f64, objective: Objective) {
         self.coordinates = new_coordinates;
         self.value = value;
 
-        if compare_floats(value, self.best_personal_value) == Ordering::Less {
+        if compare_goals(value, self.best_personal_value, objective) == Ordering::Less {
             self.best_personal_coordinates = self.coordinates.clone();
             self.best_personal_value = value;
         }
 
-        if compare_floats(value, self.worst_personal_value) == Ordering::Greater {
+        if compare_goals(value, self.worst_personal_value, objective) == Ordering::Greater {
             self.worst_personal_coordinates = self.coordinates.clone();
             self.worst_personal_value = value;
         }
@@ -144,6 +223,9 @@ pub struct Swarm<T> {
     worst_particle: Option<Particle<T>>,
 
     iteration: usize,
+
+    /// Search minimum or maximum of the goal function.
+    objective: Objective,
 }
 
 impl<T: Clone> Swarm<T> {
@@ -153,6 +235,7 @@ impl<T: Clone> Swarm<T> {
             best_particle: None,
             worst_particle: None,
             iteration: 0,
+            objective: Objective::Minimize,
         }
     }
 
@@ -161,6 +244,61 @@ impl<T: Clone> Swarm<T> {
         self.particles.len()
     }
 
+    /// Returns all particles which current value of the goal function differs from the best
+    /// current value not more than `tolerance`. The method may be used to find symmetric
+    /// optima. The particles with NaN value are not returned.
+    pub fn get_all_best_particles(&self, tolerance: f64) -> Vec<&Particle<T>> {
+        let objective = self.objective;
+        let candidates = self
+            .particles
+            .iter()
+            .filter(|particle| !particle.value.is_nan());
+
+        let best_value =
+            candidates
+                .clone()
+                .map(|particle| particle.value)
+                .fold(f64::NAN, |best, value| {
+                    if objective.is_better(value, best) {
+                        value
+                    } else {
+                        best
+                    }
+                });
+
+        candidates
+            .filter(|particle| {
+                particle.value == best_value || (particle.value - best_value).abs() <= tolerance
+            })
+            .collect()
+    }
+
+    /// Returns the best personal coordinates in the ring neighborhood of the particle with
+    /// `index`. The neighborhood includes `size` particles with adjacent indexes (the particle
+    /// itself, `(size - 1) / 2` previous and `size / 2` next particles, the indexes are cyclic).
+    /// If `size` is not less than count of the particles, the neighborhood is the whole swarm.
+    pub fn get_neighborhood_best(&self, index: usize, size: usize) -> &Coordinate<T> {
+        assert!(index < self.particles.len());
+        assert!(size > 0);
+
+        let count = self.particles.len();
+        let size = size.min(count);
+        let first = index + count - (size - 1) / 2;
+
+        let best = (first..first + size)
+            .map(|n| &self.particles[n % count])
+            .min_by(|p1, p2| {
+                compare_goals(
+                    p1.best_personal_value,
+                    p2.best_personal_value,
+                    self.objective,
+                )
+            })
+            .unwrap();
+
+        &best.best_personal_coordinates
+    }
+
     /// Remove all particles and go to iteration 0.
     fn reset(&mut self) {
         self.particles.clear();
@@ -173,21 +311,53 @@ impl<T: Clone> Swarm<T> {
         self.iteration += 1;
     }
 
+    /// Append new particles to the swarm.
+    fn add_particles(&mut self, particles: Vec<Particle<T>>) {
+        self.particles.extend(particles);
+        self.update_best_particle();
+        self.update_worst_particle();
+    }
+
+    /// Remove `count` particles with the worst current goal value. Returns indexes of the
+    /// removed particles in descending order.
+    fn remove_worst_particles(&mut self, count: usize) -> Vec<usize> {
+        let objective = self.objective;
+        let mut indexes: Vec<usize> = (0..self.particles.len()).collect();
+        indexes.sort_by(|&n1, &n2| {
+            compare_goals(
+                self.particles[n2].value,
+                self.particles[n1].value,
+                objective,
+            )
+        });
+        indexes.truncate(count);
+        indexes.sort_unstable_by(|n1, n2| n2.cmp(n1));
+
+        for &n in &indexes {
+            self.particles.remove(n);
+        }
+
+        indexes
+    }
+
     fn replace_particles(&mut self, particles: Vec<Particle<T>>) {
         self.particles = particles;
-        self.best_particle = Self::find_best_particle(&self.particles);
-        self.worst_particle = Self::find_worst_particle(&self.particles);
+        self.best_particle = Self::find_best_particle(&self.particles, self.objective);
+        self.worst_particle = Self::find_worst_particle(&self.particles, self.objective);
     }
 
     fn update_best_particle(&mut self) {
-        if let Some(new_best_particle) = Self::find_best_particle(&self.particles) {
+        if let Some(new_best_particle) = Self::find_best_particle(&self.particles, self.objective) {
             match &self.best_particle {
                 None => {
                     self.best_particle = Some(new_best_particle.clone());
                 }
                 Some(old_best_particle) => {
-                    if compare_floats(new_best_particle.value, old_best_particle.value)
-                        == Ordering::Less
+                    if compare_goals(
+                        new_best_particle.value,
+                        old_best_particle.value,
+                        self.objective,
+                    ) == Ordering::Less
                     {
                         self.best_particle = Some(new_best_particle.clone());
                     }
@@ -197,14 +367,18 @@ impl<T: Clone> Swarm<T> {
     }
 
     fn update_worst_particle(&mut self) {
-        if let Some(new_worst_particle) = Self::find_worst_particle(&self.particles) {
+        if let Some(new_worst_particle) = Self::find_worst_particle(&self.particles, self.objective)
+        {
             match &self.worst_particle {
                 None => {
                     self.worst_particle = Some(new_worst_particle.clone());
                 }
                 Some(old_worst_particle) => {
-                    if compare_floats(new_worst_particle.value, old_worst_particle.value)
-                        == Ordering::Greater
+                    if compare_goals(
+                        new_worst_particle.value,
+                        old_worst_particle.value,
+                        self.objective,
+                    ) == Ordering::Greater
                     {
                         self.worst_particle = Some(new_worst_particle.clone());
                     }
@@ -213,36 +387,175 @@ impl<T: Clone> Swarm<T> {
         }
     }
 
-    fn find_best_particle(particles: &Vec<Particle<T>>) -> Option<Particle<T>> {
+    fn find_best_particle(
+        particles: &Vec<Particle<T>>,
+        objective: Objective,
+    ) -> Option<Particle<T>> {
         if particles.is_empty() {
             None
         } else {
             let particle = particles
                 .iter()
-                .min_by(|p1, p2| compare_floats(p1.value, p2.value))
+                .min_by(|p1, p2| compare_goals(p1.value, p2.value, objective))
                 .unwrap();
             Some(particle.clone())
         }
     }
 
-    fn find_worst_particle(particles: &Vec<Particle<T>>) -> Option<Particle<T>> {
+    fn find_worst_particle(
+        particles: &Vec<Particle<T>>,
+        objective: Objective,
+    ) -> Option<Particle<T>> {
         if particles.is_empty() {
             None
         } else {
             let particle = particles
                 .iter()
-                .max_by(|p1, p2| compare_floats(p1.value, p2.value))
+                .max_by(|p1, p2| compare_goals(p1.value, p2.value, objective))
                 .unwrap();
             Some(particle.clone())
         }
     }
 
     fn get_current_best_particle(&self) -> Option<Particle<T>> {
-        Self::find_best_particle(&self.particles)
+        Self::find_best_particle(&self.particles, self.objective)
     }
 
     fn get_current_worst_particle(&self) -> Option<Particle<T>> {
-        Self::find_worst_particle(&self.particles)
+        Self::find_worst_particle(&self.particles, self.objective)
+    }
+}
+
+/// The velocity calculators for heterogeneous swarm and the assignment of the calculators to
+/// the particles.
+struct HeterogeneousVelocity<'a, T> {
+    calculators: Vec<Box<dyn VelocityCalculator<T> + 'a>>,
+
+    /// Index of the calculator in `calculators` for every particle.
+    assignment: Vec<usize>,
+
+    /// The assignment for the initial swarm. It is restored by `find_min()` because count of
+    /// the particles may be changed during optimization.
+    initial_assignment: Vec<usize>,
+}
+
+/// The rules to change count of the particles in the swarm during optimization (see
+/// `ParticleSwarmOptimizer::set_adaptive_size()`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveSizePolicy {
+    stagnation_iterations: usize,
+    improvement_iterations: usize,
+    step: usize,
+}
+
+impl AdaptiveSizePolicy {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `stagnation_iterations` - new particles are added if the best goal value does not
+    ///   improve during `stagnation_iterations` iterations.
+    /// * `improvement_iterations` - the worst particles are removed if the best goal value
+    ///   improves on every iteration during `improvement_iterations` iterations.
+    /// * `step` - count of the particles to add or remove at once.
+    pub fn new(stagnation_iterations: usize, improvement_iterations: usize, step: usize) -> Self {
+        assert!(stagnation_iterations > 0);
+        assert!(improvement_iterations > 0);
+        assert!(step > 0);
+
+        Self {
+            stagnation_iterations,
+            improvement_iterations,
+            step,
+        }
+    }
+}
+
+/// Settings and state to change count of the particles in the swarm.
+struct AdaptiveSize {
+    min_size: usize,
+    max_size: usize,
+    policy: AdaptiveSizePolicy,
+
+    /// The best goal value after last improvement.
+    best_value: f64,
+
+    /// Count of the iterations without improvement.
+    stagnation_count: usize,
+
+    /// Count of the iterations with improvement in a row.
+    improvement_count: usize,
+}
+
+impl AdaptiveSize {
+    fn reset(&mut self, best_value: f64) {
+        self.best_value = best_value;
+        self.stagnation_count = 0;
+        self.improvement_count = 0;
+    }
+
+    /// Returns new count of the particles in the swarm.
+    fn check(&mut self, best_value: f64, objective: Objective, size: usize) -> usize {
+        if compare_goals(best_value, self.best_value, objective) == Ordering::Less {
+            self.best_value = best_value;
+            self.stagnation_count = 0;
+            self.improvement_count += 1;
+        } else {
+            self.stagnation_count += 1;
+            self.improvement_count = 0;
+        }
+
+        if self.stagnation_count >= self.policy.stagnation_iterations {
+            self.stagnation_count = 0;
+            (size + self.policy.step).min(self.max_size).max(size)
+        } else if self.improvement_count >= self.policy.improvement_iterations {
+            self.improvement_count = 0;
+            size.saturating_sub(self.policy.step)
+                .max(self.min_size)
+                .min(size)
+        } else {
+            size
+        }
+    }
+}
+
+/// Settings and state to reinitialize velocity of the particles after stagnation.
+struct StagnationReinit<'a, T> {
+    /// How many iterations the best goal value may not improve.
+    iterations: usize,
+    velocity_initializer: Box<dyn VelocityInitializer<T> + 'a>,
+
+    /// The best goal value after last improvement.
+    best_value: f64,
+
+    /// Count of the iterations without improvement.
+    stagnation_count: usize,
+
+    /// Iteration numbers when velocity was reinitialized.
+    reinit_iterations: Vec<usize>,
+}
+
+impl<'a, T> StagnationReinit<'a, T> {
+    fn reset(&mut self, best_value: f64) {
+        self.best_value = best_value;
+        self.stagnation_count = 0;
+        self.reinit_iterations.clear();
+    }
+
+    /// Returns true if velocity must be reinitialized.
+    fn check(&mut self, best_value: f64, objective: Objective) -> bool {
+        if compare_goals(best_value, self.best_value, objective) == Ordering::Less {
+            self.best_value = best_value;
+            self.stagnation_count = 0;
+        } else {
+            self.stagnation_count += 1;
+        }
+
+        if self.stagnation_count >= self.iterations {
+            self.stagnation_count = 0;
+            true
+        } else {
+            false
+        }
     }
 }
 
@@ -255,6 +568,10 @@ pub struct ParticleSwarmOptimizer<'a, T> {
     post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T> + 'a>>,
     post_move: Vec<Box<dyn PostMove<T> + 'a>>,
     loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>,
+    stagnation_reinit: Option<StagnationReinit<'a, T>>,
+    heterogeneous_velocity: Option<HeterogeneousVelocity<'a, T>>,
+    position_updater: Option<Box<dyn PositionUpdater<T> + 'a>>,
+    adaptive_size: Option<AdaptiveSize>,
     swarm: Swarm<T>,
 }
 
@@ -277,10 +594,50 @@ impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
             post_velocity_calc: vec![],
             post_move: vec![],
             loggers: vec![],
+            stagnation_reinit: None,
+            heterogeneous_velocity: None,
+            position_updater: None,
+            adaptive_size: None,
             swarm,
         }
     }
 
+    /// Set the objective of the algorithm. The algorithm searches minimum of the goal function by
+    /// default.
+    pub fn set_objective(&mut self, objective: Objective) {
+        self.swarm.objective = objective;
+    }
+
+    /// Replace the goal function (for example, for dynamic optimization problems). Values of the
+    /// goal function for the current, the best and the worst personal coordinates of all
+    /// particles are recalculated with the new goal function, so the algorithm may be continued
+    /// by `IterativeOptimizer::next_iterations()`.
+    pub fn set_goal(&mut self, goal: Box<dyn Goal<Coordinate<T>> + 'a>) {
+        self.goal = goal;
+        self.goal.set_generation(self.swarm.iteration);
+
+        let objective = self.swarm.objective;
+        for particle in self.swarm.particles.iter_mut() {
+            particle.reevaluate(self.goal.as_mut(), objective);
+        }
+
+        self.swarm.best_particle = Swarm::find_best_particle(&self.swarm.particles, objective);
+        self.swarm.worst_particle = Swarm::find_worst_particle(&self.swarm.particles, objective);
+
+        let best_value = match &self.swarm.best_particle {
+            None => f64::NAN,
+            Some(particle) => particle.value,
+        };
+
+        if let Some(reinit) = &mut self.stagnation_reinit {
+            reinit.best_value = best_value;
+        }
+
+        if let Some(adaptive) = &mut self.adaptive_size {
+            adaptive.best_value = best_value;
+        }
+    }
+
     pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>) {
         self.stop_checker = stop_checker;
     }
@@ -289,7 +646,13 @@ impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
         self.loggers = loggers;
     }
 
-    pub fn set_post_moves(&mut self, post_move: Vec<Box<dyn PostMove<T>>>) {
+    /// Returns the current swarm. The method may be used to read the particles between
+    /// iterations.
+    pub fn get_swarm(&self) -> &Swarm<T> {
+        &self.swarm
+    }
+
+    pub fn set_post_moves(&mut self, post_move: Vec<Box<dyn PostMove<T> + 'a>>) {
         self.post_move = post_move;
     }
 
@@ -297,18 +660,197 @@ impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
         self.post_velocity_calc = post_velocity_calc;
     }
 
-    fn renew_swarm(&mut self) {
+    /// Reinitialize velocity of all particles if the best value of goal function does not
+    /// improve during `iterations` iterations. The velocity initializer is called until velocity
+    /// for all particles in the swarm is created, extra velocity vectors are dropped.
+    ///
+    /// # Parameters
+    /// * `iterations` - how many iterations the best goal value may not improve.
+    /// * `velocity_initializer` - trait object to create new velocity for the particles.
+    pub fn set_stagnation_reinit(
+        &mut self,
+        iterations: usize,
+        velocity_initializer: Box<dyn VelocityInitializer<T> + 'a>,
+    ) {
+        assert!(iterations > 0);
+        self.stagnation_reinit = Some(StagnationReinit {
+            iterations,
+            velocity_initializer,
+            best_value: f64::NAN,
+            stagnation_count: 0,
+            reinit_iterations: vec![],
+        });
+    }
+
+    /// Use different velocity calculators for different particles (heterogeneous swarm). For
+    /// example, some particles may explore the search space and other particles may exploit the
+    /// best solutions. The velocity calculator of the constructor is not used after the call.
+    ///
+    /// # Parameters
+    /// * `calculators` - the velocity calculators.
+    /// * `assignment` - index of the calculator in `calculators` for every particle. Length of
+    ///   the vector must be equal to count of the particles.
+    pub fn set_heterogeneous_velocity_calculators(
+        &mut self,
+        calculators: Vec<Box<dyn VelocityCalculator<T> + 'a>>,
+        assignment: Vec<usize>,
+    ) {
+        assert!(assignment.iter().all(|&index| index < calculators.len()));
+        self.heterogeneous_velocity = Some(HeterogeneousVelocity {
+            calculators,
+            initial_assignment: assignment.clone(),
+            assignment,
+        });
+    }
+
+    /// Calculate new coordinates of the particles by `updater` without velocity (for example,
+    /// the bare bones particle swarm). The velocity calculators and the `PostVelocityCalc`
+    /// trait objects are not used after the call. The velocity of the particle is set to
+    /// difference between the new and the previous coordinates, so `PostMove` trait objects get
+    /// the displacement of the particle as velocity.
+    pub fn set_position_updater(&mut self, updater: Box<dyn PositionUpdater<T> + 'a>) {
+        self.position_updater = Some(updater);
+    }
+
+    /// Change count of the particles during optimization. New particles are added if the best
+    /// goal value stagnates and the worst particles are removed if the best goal value improves
+    /// rapidly. The coordinates and velocity initializers of the constructor are used to create
+    /// new particles. The initializers are called several times if they create less particles
+    /// than required. The count of the particles and the assignment of the heterogeneous velocity
+    /// calculators are restored by `find_min()`.
+    ///
+    /// If the heterogeneous swarm is used, new particles get the velocity calculators cyclically
+    /// by assignment of the first particles.
+    ///
+    /// # Parameters
+    /// * `min_size` - the particles are not removed if the count of the particles is not
+    ///   greater than `min_size`.
+    /// * `max_size` - the particles are not added if the count of the particles is not less
+    ///   than `max_size`.
+    /// * `policy` - when and how many particles to add or remove.
+    pub fn set_adaptive_size(
+        &mut self,
+        min_size: usize,
+        max_size: usize,
+        policy: AdaptiveSizePolicy,
+    ) {
+        assert!(min_size > 0);
+        assert!(min_size <= max_size);
+        self.adaptive_size = Some(AdaptiveSize {
+            min_size,
+            max_size,
+            policy,
+            best_value: f64::NAN,
+            stagnation_count: 0,
+            improvement_count: 0,
+        });
+    }
+
+    /// Returns iteration numbers when velocity of the particles was reinitialized after
+    /// stagnation.
+    pub fn get_stagnation_reinit_iterations(&self) -> Vec<usize> {
+        match &self.stagnation_reinit {
+            None => vec![],
+            Some(reinit) => reinit.reinit_iterations.clone(),
+        }
+    }
+
+    fn reinit_velocity_if_stagnation(&mut self) {
+        if let (Some(reinit), Some(best_particle)) =
+            (&mut self.stagnation_reinit, &self.swarm.best_particle)
+        {
+            if reinit.check(best_particle.value, self.swarm.objective) {
+                let velocity = create_velocity(
+                    reinit.velocity_initializer.as_mut(),
+                    self.swarm.particles.len(),
+                );
+
+                for (particle, new_velocity) in self.swarm.particles.iter_mut().zip(velocity) {
+                    particle.set_velocity(new_velocity);
+                }
+
+                reinit.reinit_iterations.push(self.swarm.iteration);
+            }
+        }
+    }
+
+    fn change_swarm_size(&mut self) {
+        let new_size = match (&mut self.adaptive_size, &self.swarm.best_particle) {
+            (Some(adaptive), Some(best_particle)) => adaptive.check(
+                best_particle.value,
+                self.swarm.objective,
+                self.swarm.particles.len(),
+            ),
+            _ => return,
+        };
+
+        let size = self.swarm.particles.len();
+        if new_size > size {
+            let particles = self.create_particles(Some(new_size - size));
+            self.swarm.add_particles(particles);
+
+            if let Some(heterogeneous) = &mut self.heterogeneous_velocity {
+                let new_size = self.swarm.particles.len();
+                for n in size..new_size {
+                    let index = heterogeneous.assignment[n % size];
+                    heterogeneous.assignment.push(index);
+                }
+            }
+        } else if new_size < size {
+            let removed = self.swarm.remove_worst_particles(size - new_size);
+
+            if let Some(heterogeneous) = &mut self.heterogeneous_velocity {
+                for n in removed {
+                    heterogeneous.assignment.remove(n);
+                }
+            }
+        }
+    }
+
+    /// Create new particles by the initializers. If `count` is None, the particles are created by
+    /// single call of the initializers. Otherwise the initializers are called until `count`
+    /// particles are created.
+    fn create_particles(&mut self, count: Option<usize>) -> Vec<Particle<T>> {
+        let count = match count {
+            None => return self.create_particles_once(None),
+            Some(count) => count,
+        };
+
+        let mut particles = Vec::with_capacity(count);
+        while particles.len() < count {
+            let mut new_particles = self.create_particles_once(Some(count - particles.len()));
+            assert!(!new_particles.is_empty());
+            particles.append(&mut new_particles);
+        }
+
+        particles
+    }
+
+    /// Create new particles by single call of the initializers. If `max_count` is not None, no
+    /// more than `max_count` particles are created.
+    fn create_particles_once(&mut self, max_count: Option<usize>) -> Vec<Particle<T>> {
         let mut coordinates = self.coordinates_initializer.get_coordinates();
-        let velocity = self.velocity_initializer.get_velocity();
+        let mut personal_best = self.coordinates_initializer.get_initial_personal_best();
+        let mut velocity = self.velocity_initializer.get_velocity();
         assert!(coordinates.len() == velocity.len());
 
-        for mut current_coordinates in &mut coordinates {
-            self.post_move
-                .iter_mut()
-                .for_each(|post_move| post_move.post_move(&mut current_coordinates));
+        if let Some(max_count) = max_count {
+            coordinates.truncate(max_count);
+            velocity.truncate(max_count);
+            if let Some(personal_best) = &mut personal_best {
+                personal_best.truncate(max_count);
+            }
+        }
+
+        for (current_coordinates, current_velocity) in
+            coordinates.iter_mut().zip(velocity.iter_mut())
+        {
+            self.post_move.iter_mut().for_each(|post_move| {
+                post_move.post_move_velocity(current_coordinates, current_velocity)
+            });
         }
 
-        let particles: Vec<Particle<T>> = coordinates
+        let mut particles: Vec<Particle<T>> = coordinates
             .iter()
             .zip(velocity.iter())
             .map(|cs| {
@@ -319,72 +861,135 @@ impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
             })
             .collect();
 
-        self.swarm.reset();
-        self.swarm.replace_particles(particles);
+        if let Some(personal_best) = personal_best {
+            assert!(personal_best.len() == particles.len());
+            for (particle, (best_coordinates, best_value)) in
+                particles.iter_mut().zip(personal_best)
+            {
+                particle.set_best_personal(best_coordinates, best_value, self.swarm.objective);
+            }
+        }
+
+        particles
     }
-}
 
-impl<'a, T: Clone + Float> Optimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
-    fn find_min(&mut self) -> Option<(Coordinate<T>, f64)> {
-        self.renew_swarm();
+    fn renew_swarm(&mut self) {
+        self.goal.set_generation(0);
+        let particles = self.create_particles(None);
 
-        for logger in &mut self.loggers {
-            logger.start(&self.swarm);
+        self.swarm.reset();
+        self.swarm.replace_particles(particles);
+
+        if let Some(heterogeneous) = &mut self.heterogeneous_velocity {
+            heterogeneous.assignment = heterogeneous.initial_assignment.clone();
         }
 
-        self.next_iterations()
-    }
-}
+        if let Some(reinit) = &mut self.stagnation_reinit {
+            let best_value = match &self.swarm.best_particle {
+                None => f64::NAN,
+                Some(particle) => particle.value,
+            };
+            reinit.reset(best_value);
+        }
 
-impl<'a, T: Clone + Float> IterativeOptimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
-    /// Main algorithm steps is here
-    fn next_iterations(&mut self) -> Option<Solution<Coordinate<T>>> {
-        for logger in &mut self.loggers {
-            logger.resume(&self.swarm);
+        if let Some(adaptive) = &mut self.adaptive_size {
+            let best_value = match &self.swarm.best_particle {
+                None => f64::NAN,
+                Some(particle) => particle.value,
+            };
+            adaptive.reset(best_value);
         }
+    }
 
-        while !self.stop_checker.can_stop(&self.swarm) {
-            for n in 0..self.swarm.particles.len() {
-                // Calculate new velocity
-                let mut new_velocity = self
-                    .velocity_calculator
-                    .calc_new_velocity(&self.swarm, &self.swarm.particles[n]);
+    /// Run single iteration of the algorithm and call the loggers.
+    fn do_iteration(&mut self) {
+        self.goal.set_generation(self.swarm.iteration);
 
-                // Correct new velocity
-                for post_velocity_calc in &mut self.post_velocity_calc {
-                    new_velocity = post_velocity_calc.correct_velocity(new_velocity);
-                }
+        for post_velocity_calc in &mut self.post_velocity_calc {
+            post_velocity_calc.next_iteration(self.swarm.iteration);
+        }
 
-                self.swarm.particles[n].set_velocity(new_velocity);
+        if let Some(heterogeneous) = &self.heterogeneous_velocity {
+            assert_eq!(heterogeneous.assignment.len(), self.swarm.particles.len());
+        }
 
-                // Calculate new coordinates
-                let mut new_coordinates: Coordinate<T> = self.swarm.particles[n]
-                    .coordinates
+        for n in 0..self.swarm.particles.len() {
+            if let Some(updater) = &mut self.position_updater {
+                let new_coordinates =
+                    updater.calc_new_coordinates(&self.swarm, &self.swarm.particles[n]);
+                let new_velocity = new_coordinates
                     .iter()
-                    .zip(self.swarm.particles[n].velocity.iter())
-                    .map(|(coord, velocity)| *coord + *velocity)
+                    .zip(self.swarm.particles[n].coordinates.iter())
+                    .map(|(new_coord, coord)| *new_coord - *coord)
                     .collect();
+                self.swarm.particles[n].set_velocity(new_velocity);
+                self.move_particle(n, new_coordinates);
+                continue;
+            }
 
-                // Correct coordinates
-                self.post_move
-                    .iter_mut()
-                    .for_each(|post_move| post_move.post_move(&mut new_coordinates));
+            let velocity_calculator = match &mut self.heterogeneous_velocity {
+                None => &mut self.velocity_calculator,
+                Some(heterogeneous) => &mut heterogeneous.calculators[heterogeneous.assignment[n]],
+            };
 
-                // Calculate new value for the particle
-                let new_value = self.goal.get(&new_coordinates);
+            // Calculate new velocity
+            let mut new_velocity = velocity_calculator.calc_new_velocity_by_index(&self.swarm, n);
 
-                self.swarm.particles[n].move_to(new_coordinates, new_value);
+            // Correct new velocity
+            for post_velocity_calc in &mut self.post_velocity_calc {
+                new_velocity = post_velocity_calc.correct_velocity(new_velocity);
             }
 
-            self.swarm.update_best_particle();
-            self.swarm.update_worst_particle();
-            self.swarm.next_iteration();
+            self.swarm.particles[n].set_velocity(new_velocity);
 
-            for logger in &mut self.loggers {
-                logger.next_iteration(&self.swarm);
-            }
+            // Calculate new coordinates
+            let new_coordinates: Coordinate<T> = self.swarm.particles[n]
+                .coordinates
+                .iter()
+                .zip(self.swarm.particles[n].velocity.iter())
+                .map(|(coord, velocity)| *coord + *velocity)
+                .collect();
+
+            self.move_particle(n, new_coordinates);
+        }
+
+        self.swarm.update_best_particle();
+        self.swarm.update_worst_particle();
+        self.swarm.next_iteration();
+        self.reinit_velocity_if_stagnation();
+        self.change_swarm_size();
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.swarm);
         }
+    }
+
+    /// Correct the new coordinates by the `PostMove` trait objects, calculate the goal function
+    /// and move the particle with index `n`.
+    fn move_particle(&mut self, n: usize, mut new_coordinates: Coordinate<T>) {
+        // Correct coordinates (and velocity)
+        let velocity = &mut self.swarm.particles[n].velocity;
+        self.post_move
+            .iter_mut()
+            .for_each(|post_move| post_move.post_move_velocity(&mut new_coordinates, velocity));
+
+        // Calculate new value for the particle
+        let new_value = self.goal.get(&new_coordinates);
+
+        let objective = self.swarm.objective;
+        self.swarm.particles[n].move_to(new_coordinates, new_value, objective);
+    }
 
+    /// Returns true if the iterations limit `max_iterations` is reached.
+    fn is_budget_spent(&self, max_iterations: Option<usize>) -> bool {
+        match max_iterations {
+            None => false,
+            Some(max_iterations) => self.swarm.iteration >= max_iterations,
+        }
+    }
+
+    /// Call the loggers in the end of the running and return the best solution.
+    fn finish_iterations(&mut self) -> Option<Solution<Coordinate<T>>> {
         for logger in &mut self.loggers {
             logger.finish(&self.swarm);
         }
@@ -394,6 +999,76 @@ impl<'a, T: Clone + Float> IterativeOptimizer<Coordinate<T>> for ParticleSwarmOp
             Some(particle) => Some((particle.coordinates.clone(), particle.value)),
         }
     }
+
+    /// Create the new swarm and run the algorithm with the iterations limit if `max_iterations`
+    /// is not None.
+    fn run(&mut self, max_iterations: Option<usize>) -> Option<Solution<Coordinate<T>>> {
+        self.stop_checker.reset();
+        self.renew_swarm();
+
+        for logger in &mut self.loggers {
+            logger.start(&self.swarm);
+        }
+
+        self.run_iterations(max_iterations)
+    }
+
+    /// Do new iterations until the stop checker fires or the iterations limit `max_iterations`
+    /// is reached.
+    fn run_iterations(&mut self, max_iterations: Option<usize>) -> Option<Solution<Coordinate<T>>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.swarm);
+        }
+
+        while !self.stop_checker.can_stop(&self.swarm) && !self.is_budget_spent(max_iterations) {
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+
+    /// Do `n` iterations of the algorithm regardless of the stop checker.
+    pub fn next_n_iterations(&mut self, n: usize) -> Option<Solution<Coordinate<T>>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.swarm);
+        }
+
+        for _ in 0..n {
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+}
+
+/// The parameters are the parameters of the velocity calculator which is passed to the
+/// constructor (for example, "phi_personal", "phi_global" and "inertia" for
+/// `InertiaVelocityCalculator` with `ConstInertia`).
+impl<'a, T: Clone + Float> Tunable for ParticleSwarmOptimizer<'a, T> {
+    fn get_params(&self) -> HashMap<String, f64> {
+        self.velocity_calculator.get_params()
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        self.velocity_calculator.set_params(params);
+    }
+}
+
+impl<'a, T: Clone + Float> Optimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<(Coordinate<T>, f64)> {
+        self.run(None)
+    }
+
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<Coordinate<T>>> {
+        self.run(Some(max_iterations))
+    }
+}
+
+impl<'a, T: Clone + Float> IterativeOptimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
+    /// Main algorithm steps is here
+    fn next_iterations(&mut self) -> Option<Solution<Coordinate<T>>> {
+        self.run_iterations(None)
+    }
 }
 
 impl<T: Clone> AlgorithmState<Coordinate<T>> for Swarm<T> {
@@ -407,6 +1082,25 @@ impl<T: Clone> AlgorithmState<Coordinate<T>> for Swarm<T> {
     fn get_iteration(&self) -> usize {
         self.iteration
     }
+
+    fn get_worst_solution(&self) -> Option<(Coordinate<T>, f64)> {
+        self.worst_particle
+            .as_ref()
+            .map(|particle| (particle.coordinates.clone(), particle.value))
+    }
+
+    fn get_agent_list(&self) -> Option<Vec<&dyn Agent<Coordinate<T>>>> {
+        Some(
+            self.particles
+                .iter()
+                .map(|particle| particle as &dyn Agent<Coordinate<T>>)
+                .collect(),
+        )
+    }
+
+    fn get_objective(&self) -> Objective {
+        self.objective
+    }
 }
 
 impl<T: Clone> AgentsState<Coordinate<T>> for Swarm<T> {
@@ -423,6 +1117,32 @@ impl<T: Clone> AgentsState<Coordinate<T>> for Swarm<T> {
     }
 }
 
+/// Create velocity for `count` particles. The initializer is called until enough velocity
+/// vectors are created, extra vectors are dropped.
+fn create_velocity<T>(
+    initializer: &mut dyn VelocityInitializer<T>,
+    count: usize,
+) -> Vec<Velocity<T>> {
+    let mut velocity = Vec::with_capacity(count);
+    while velocity.len() < count {
+        let mut new_velocity = initializer.get_velocity();
+        assert!(!new_velocity.is_empty());
+        velocity.append(&mut new_velocity);
+    }
+
+    velocity.truncate(count);
+    velocity
+}
+
+/// Compare values of the goal function. `Ordering::Less` means `x` is better than `y`.
+/// Not finite values are worse than others.
+fn compare_goals(x: f64, y: f64, objective: Objective) -> Ordering {
+    match objective {
+        Objective::Maximize if x.is_finite() && y.is_finite() => compare_floats(y, x),
+        _ => compare_floats(x, y),
+    }
+}
+
 fn compare_floats(x: f64, y: f64) -> Ordering {
     if !x.is_finite() && !y.is_finite() {
         Ordering::Equal
@@ -444,6 +1164,168 @@ fn compare_floats(x: f64, y: f64) -> Ordering {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::particleswarm::initializing::{
+        RandomCoordinatesInitializer, RandomVelocityInitializer, ZeroVelocityInitializer,
+    };
+    use crate::tools::stopchecker::MaxIterations;
+
+    /// The goal function with plateau.
+    struct PlateauGoal;
+
+    impl Goal<Vec<f64>> for PlateauGoal {
+        fn get(&mut self, _x: &Vec<f64>) -> f64 {
+            1.0
+        }
+    }
+
+    /// The particles do not move.
+    struct ZeroVelocityCalculator;
+
+    impl VelocityCalculator<f64> for ZeroVelocityCalculator {
+        fn calc_new_velocity(&mut self, _swarm: &Swarm<f64>, particle: &Particle<f64>) -> Velocity<f64> {
+            vec![0.0; particle.velocity.len()]
+        }
+    }
+
+    fn create_plateau_optimizer<'a>(max_iter: usize) -> ParticleSwarmOptimizer<'a, f64> {
+        let particles_count = 5;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(PlateauGoal),
+            Box::new(MaxIterations::new(max_iter)),
+            Box::new(RandomCoordinatesInitializer::new(intervals, particles_count)),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ZeroVelocityCalculator),
+        );
+
+        optimizer.set_stagnation_reinit(
+            3,
+            Box::new(RandomVelocityInitializer::new(
+                vec![(5.0, 6.0), (5.0, 6.0)],
+                particles_count,
+            )),
+        );
+
+        optimizer
+    }
+
+    #[test]
+    fn get_swarm() {
+        use crate::particleswarm::velocitycalc::ClassicVelocityCalculator;
+
+        let particles_count = 10;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(crate::GoalFromFunction::new(|x: &Vec<f64>| x.iter().map(|xi| xi * xi).sum())),
+            Box::new(MaxIterations::new(5)),
+            Box::new(RandomCoordinatesInitializer::new(intervals, particles_count)),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+        );
+
+        let result = optimizer.find_min();
+        let swarm = optimizer.get_swarm();
+
+        assert_eq!(swarm.len(), particles_count);
+        assert_eq!(swarm.get_iteration(), 5);
+        assert_eq!(swarm.get_best_solution(), result);
+        assert_eq!(swarm.get_agents().len(), particles_count);
+    }
+
+    #[test]
+    fn tunable() {
+        use crate::particleswarm::velocitycalc::{ConstInertia, InertiaVelocityCalculator};
+
+        let particles_count = 10;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(crate::GoalFromFunction::new(|x: &Vec<f64>| {
+                x.iter().map(|xi| xi * xi).sum()
+            })),
+            Box::new(MaxIterations::new(5)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals.clone(),
+                particles_count,
+            )),
+            Box::new(RandomVelocityInitializer::new(intervals, particles_count)),
+            Box::new(InertiaVelocityCalculator::new(
+                0.5,
+                2.0,
+                Box::new(ConstInertia::new(0.75)),
+            )),
+        );
+
+        let params = optimizer.get_params();
+        assert_eq!(params.len(), 3);
+        assert_eq!(params["phi_personal"], 0.5);
+        assert_eq!(params["phi_global"], 2.0);
+        assert_eq!(params["inertia"], 0.75);
+
+        // The particles stop after the first iteration
+        let mut new_params = HashMap::new();
+        new_params.insert("phi_personal".to_string(), 0.0);
+        new_params.insert("phi_global".to_string(), 0.0);
+        new_params.insert("inertia".to_string(), 0.0);
+        optimizer.set_params(&new_params);
+        assert_eq!(optimizer.get_params(), new_params);
+
+        optimizer.find_min();
+        for particle in &optimizer.get_swarm().particles {
+            assert_eq!(particle.velocity, vec![0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn get_worst_solution() {
+        let mut swarm: Swarm<f64> = Swarm::new();
+        assert_eq!(swarm.get_worst_solution(), None);
+
+        swarm.replace_particles(vec![
+            Particle::new(vec![1.0], vec![0.0], 1.0),
+            Particle::new(vec![3.0], vec![0.0], 9.0),
+            Particle::new(vec![2.0], vec![0.0], 4.0),
+        ]);
+
+        assert_eq!(swarm.get_worst_solution(), Some((vec![3.0], 9.0)));
+        assert_eq!(swarm.get_best_solution(), Some((vec![1.0], 1.0)));
+    }
+
+    #[test]
+    fn stagnation_reinit_iterations() {
+        let mut optimizer = create_plateau_optimizer(10);
+        optimizer.find_min();
+
+        assert_eq!(optimizer.get_stagnation_reinit_iterations(), vec![3, 6, 9]);
+    }
+
+    #[test]
+    fn stagnation_reinit_velocity() {
+        let mut optimizer = create_plateau_optimizer(9);
+        optimizer.find_min();
+
+        for particle in &optimizer.swarm.particles {
+            for &v in &particle.velocity {
+                assert!((5.0..=6.0).contains(&v));
+            }
+        }
+
+        // Restart clears the reinitialization history
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(4)));
+        optimizer.find_min();
+        assert_eq!(optimizer.get_stagnation_reinit_iterations(), vec![3]);
+    }
+
+    #[test]
+    fn stagnation_reinit_none() {
+        let mut optimizer = create_plateau_optimizer(2);
+        optimizer.find_min();
+
+        assert!(optimizer.get_stagnation_reinit_iterations().is_empty());
+        for particle in &optimizer.swarm.particles {
+            assert_eq!(particle.velocity, vec![0.0, 0.0]);
+        }
+    }
 
     #[test]
     fn test_compare_floats() {
@@ -484,7 +1366,7 @@ mod tests {
 
         let new_coordinates = vec![1.0_f32, 2.0_f32];
         let new_value = 10_f64;
-        particle.move_to(new_coordinates.clone(), new_value);
+        particle.move_to(new_coordinates.clone(), new_value, Objective::Minimize);
 
         assert_eq!(particle.coordinates, new_coordinates);
         assert_eq!(particle.best_personal_coordinates, new_coordinates);
@@ -501,7 +1383,7 @@ mod tests {
 
         let new_coordinates = vec![1.0_f32, 2.0_f32];
         let new_value = 40_f64;
-        particle.move_to(new_coordinates.clone(), new_value);
+        particle.move_to(new_coordinates.clone(), new_value, Objective::Minimize);
 
         assert_eq!(particle.coordinates, new_coordinates);
         assert_eq!(particle.best_personal_coordinates, coordinates);
@@ -511,7 +1393,7 @@ mod tests {
     #[test]
     fn test_find_best_particle_empty() {
         let particles: Vec<Particle<f32>> = vec![];
-        assert!(Swarm::find_best_particle(&particles).is_none());
+        assert!(Swarm::find_best_particle(&particles, Objective::Minimize).is_none());
     }
 
     #[test]
@@ -521,7 +1403,7 @@ mod tests {
             vec![10_f32, 20_f32],
             100_f64,
         )];
-        let best_particle = Swarm::find_best_particle(&particles);
+        let best_particle = Swarm::find_best_particle(&particles, Objective::Minimize);
         assert!(best_particle.is_some());
     }
 
@@ -531,7 +1413,7 @@ mod tests {
             Particle::new(vec![1_f32, 2_f32], vec![10_f32, 20_f32], 100_f64),
             Particle::new(vec![3_f32, 4_f32], vec![10_f32, 20_f32], 50_f64),
         ];
-        let best_particle = Swarm::find_best_particle(&particles);
+        let best_particle = Swarm::find_best_particle(&particles, Objective::Minimize);
         assert_eq!(best_particle.unwrap().value, 50_f64);
     }
 
@@ -541,7 +1423,515 @@ mod tests {
             Particle::new(vec![3_f32, 4_f32], vec![10_f32, 20_f32], 50_f64),
             Particle::new(vec![1_f32, 2_f32], vec![10_f32, 20_f32], 100_f64),
         ];
-        let best_particle = Swarm::find_best_particle(&particles);
+        let best_particle = Swarm::find_best_particle(&particles, Objective::Minimize);
         assert_eq!(best_particle.unwrap().value, 50_f64);
     }
+
+    #[test]
+    fn maximize() {
+        use crate::particleswarm::velocitycalc::CanonicalVelocityCalculator;
+
+        let particles_count = 30;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(crate::GoalFromFunction::new(|x: &Vec<f64>| {
+                1.0 - x.iter().map(|xi| (xi - 0.5) * (xi - 0.5)).sum::<f64>()
+            })),
+            Box::new(MaxIterations::new(100)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(CanonicalVelocityCalculator::new(2.05, 2.05, 0.9)),
+        );
+        optimizer.set_objective(Objective::Maximize);
+
+        let (solution, goal) = optimizer.find_min().unwrap();
+        let swarm = optimizer.get_swarm();
+
+        assert_eq!(swarm.get_objective(), Objective::Maximize);
+        assert!(goal > 0.999);
+        assert!((solution[0] - 0.5).abs() < 0.05);
+        assert!((solution[1] - 0.5).abs() < 0.05);
+        assert!(swarm.get_worst_solution().unwrap().1 < goal);
+        for particle in swarm.get_agents() {
+            assert!(particle.get_goal() <= goal);
+        }
+    }
+
+    /// The velocity calculator returns the same velocity for all particles.
+    struct ConstVelocityCalculator {
+        velocity: Vec<f64>,
+    }
+
+    impl VelocityCalculator<f64> for ConstVelocityCalculator {
+        fn calc_new_velocity(
+            &mut self,
+            _swarm: &Swarm<f64>,
+            _particle: &Particle<f64>,
+        ) -> Vec<f64> {
+            self.velocity.clone()
+        }
+    }
+
+    #[test]
+    fn absorbing_wall() {
+        use crate::particleswarm::postmove::AbsorbingWall;
+
+        let particles_count = 10;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(crate::GoalFromFunction::new(|x: &Vec<f64>| x.iter().sum())),
+            Box::new(MaxIterations::new(10)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals.clone(),
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ConstVelocityCalculator {
+                velocity: vec![-10.0, 0.5],
+            }),
+        );
+        optimizer.set_post_moves(vec![Box::new(AbsorbingWall::new(intervals))]);
+        optimizer.find_min();
+
+        for particle in optimizer.get_swarm().get_agents() {
+            assert_eq!(particle.coordinates, vec![-1.0, 1.0]);
+            assert_eq!(particle.velocity, vec![0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn find_best_particle_maximize() {
+        let particles: Vec<Particle<f32>> = vec![
+            Particle::new(vec![1_f32], vec![0_f32], f64::NAN),
+            Particle::new(vec![3_f32], vec![0_f32], 50_f64),
+            Particle::new(vec![2_f32], vec![0_f32], 100_f64),
+            Particle::new(vec![4_f32], vec![0_f32], f64::INFINITY),
+        ];
+
+        let best_particle = Swarm::find_best_particle(&particles, Objective::Maximize);
+        assert_eq!(best_particle.unwrap().value, 100_f64);
+
+        let best_particle = Swarm::find_best_particle(&particles, Objective::Minimize);
+        assert_eq!(best_particle.unwrap().value, 50_f64);
+    }
+
+    #[test]
+    fn next_n_iterations() {
+        let mut optimizer = create_plateau_optimizer(5);
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().iteration, 5);
+
+        // The stop checker is ignored
+        assert!(optimizer.next_n_iterations(10).is_some());
+        assert_eq!(optimizer.get_swarm().iteration, 15);
+
+        assert!(optimizer.next_n_iterations(0).is_some());
+        assert_eq!(optimizer.get_swarm().iteration, 15);
+    }
+
+    #[test]
+    fn find_min_with_budget() {
+        let mut optimizer = create_plateau_optimizer(1000);
+
+        assert!(optimizer.find_min_with_budget(6).is_some());
+        assert_eq!(optimizer.swarm.iteration, 6);
+
+        // The own stop checker works too
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(2)));
+        assert!(optimizer.find_min_with_budget(6).is_some());
+        assert_eq!(optimizer.swarm.iteration, 2);
+
+        // The limit is removed after the call
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(9)));
+        optimizer.find_min();
+        assert_eq!(optimizer.swarm.iteration, 9);
+    }
+
+    #[test]
+    fn heterogeneous_velocity() {
+        let particles_count = 6;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(PlateauGoal),
+            Box::new(MaxIterations::new(1)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ZeroVelocityCalculator),
+        );
+        optimizer.set_heterogeneous_velocity_calculators(
+            vec![
+                Box::new(ConstVelocityCalculator {
+                    velocity: vec![1.0, 1.0],
+                }),
+                Box::new(ConstVelocityCalculator {
+                    velocity: vec![2.0, 2.0],
+                }),
+            ],
+            vec![0, 0, 0, 1, 1, 1],
+        );
+        optimizer.find_min();
+
+        let particles = &optimizer.get_swarm().particles;
+        for particle in &particles[..3] {
+            assert_eq!(particle.velocity, vec![1.0, 1.0]);
+        }
+
+        for particle in &particles[3..] {
+            assert_eq!(particle.velocity, vec![2.0, 2.0]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn heterogeneous_velocity_invalid_assignment() {
+        let particles_count = 6;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(PlateauGoal),
+            Box::new(MaxIterations::new(1)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ZeroVelocityCalculator),
+        );
+        optimizer.set_heterogeneous_velocity_calculators(
+            vec![Box::new(ConstVelocityCalculator {
+                velocity: vec![1.0, 1.0],
+            })],
+            vec![0, 0, 0],
+        );
+        optimizer.find_min();
+    }
+
+    /// The initializer with the known start points and personal best.
+    struct WarmStartInitializer {
+        coordinates: Vec<Coordinate<f64>>,
+        personal_best: Vec<(Coordinate<f64>, f64)>,
+    }
+
+    impl CoordinatesInitializer<f64> for WarmStartInitializer {
+        fn get_coordinates(&mut self) -> Vec<Coordinate<f64>> {
+            self.coordinates.clone()
+        }
+
+        fn get_initial_personal_best(&mut self) -> Option<Vec<(Coordinate<f64>, f64)>> {
+            Some(self.personal_best.clone())
+        }
+    }
+
+    #[test]
+    fn initial_personal_best() {
+        let initializer = WarmStartInitializer {
+            coordinates: vec![vec![1.0, 1.0], vec![0.5, 0.5]],
+            personal_best: vec![(vec![0.1, 0.0], 0.01), (vec![3.0, 3.0], 18.0)],
+        };
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(crate::GoalFromFunction::new(|x: &Vec<f64>| {
+                x.iter().map(|xi| xi * xi).sum()
+            })),
+            Box::new(MaxIterations::new(1)),
+            Box::new(initializer),
+            Box::new(ZeroVelocityInitializer::new(2, 2)),
+            Box::new(ConstVelocityCalculator {
+                velocity: vec![1.0, 1.0],
+            }),
+        );
+        optimizer.find_min();
+
+        let particles = &optimizer.get_swarm().particles;
+
+        // The supplied personal best is better than the new position
+        assert_eq!(particles[0].coordinates, vec![2.0, 2.0]);
+        assert_eq!(particles[0].best_personal_coordinates, vec![0.1, 0.0]);
+        assert_eq!(particles[0].best_personal_value, 0.01);
+
+        // The supplied personal best is worse than the start point
+        assert_eq!(particles[1].coordinates, vec![1.5, 1.5]);
+        assert_eq!(particles[1].best_personal_coordinates, vec![0.5, 0.5]);
+        assert_eq!(particles[1].best_personal_value, 0.5);
+    }
+
+    /// The goal function decreases with the generation.
+    struct DecreasingGoal {
+        generation: usize,
+    }
+
+    impl Goal<Vec<f64>> for DecreasingGoal {
+        fn get(&mut self, _x: &Vec<f64>) -> f64 {
+            -(self.generation as f64)
+        }
+
+        fn set_generation(&mut self, generation: usize) {
+            self.generation = generation;
+        }
+    }
+
+    #[test]
+    fn adaptive_size_stagnation() {
+        let mut optimizer = create_plateau_optimizer(1);
+        optimizer.set_stagnation_reinit(2, Box::new(ZeroVelocityInitializer::new(2, 5)));
+        optimizer.set_adaptive_size(3, 8, AdaptiveSizePolicy::new(3, 2, 2));
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().len(), 5);
+
+        optimizer.next_n_iterations(1);
+        assert_eq!(optimizer.get_swarm().len(), 5);
+
+        optimizer.next_n_iterations(1);
+        assert_eq!(optimizer.get_swarm().len(), 7);
+
+        optimizer.next_n_iterations(3);
+        assert_eq!(optimizer.get_swarm().len(), 8);
+
+        // The max size is reached
+        optimizer.next_n_iterations(9);
+        assert_eq!(optimizer.get_swarm().len(), 8);
+
+        // The size is restored by find_min()
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().len(), 5);
+    }
+
+    #[test]
+    fn adaptive_size_improvement() {
+        let particles_count = 6;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(DecreasingGoal { generation: 0 }),
+            Box::new(MaxIterations::new(20)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ZeroVelocityCalculator),
+        );
+        optimizer.set_heterogeneous_velocity_calculators(
+            vec![
+                Box::new(ZeroVelocityCalculator),
+                Box::new(ZeroVelocityCalculator),
+            ],
+            vec![0, 1, 0, 1, 0, 1],
+        );
+        optimizer.set_adaptive_size(2, 10, AdaptiveSizePolicy::new(100, 2, 1));
+        optimizer.find_min();
+
+        assert_eq!(optimizer.get_swarm().len(), 2);
+        assert_eq!(
+            optimizer
+                .heterogeneous_velocity
+                .as_ref()
+                .unwrap()
+                .assignment
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn adaptive_size_restart() {
+        let particles_count = 6;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(DecreasingGoal { generation: 0 }),
+            Box::new(MaxIterations::new(20)),
+            Box::new(RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )),
+            Box::new(ZeroVelocityInitializer::new(2, particles_count)),
+            Box::new(ZeroVelocityCalculator),
+        );
+        optimizer.set_heterogeneous_velocity_calculators(
+            vec![
+                Box::new(ZeroVelocityCalculator),
+                Box::new(ZeroVelocityCalculator),
+            ],
+            vec![0, 1, 0, 1, 0, 1],
+        );
+        optimizer.set_adaptive_size(2, 10, AdaptiveSizePolicy::new(100, 2, 1));
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().len(), 2);
+
+        // The assignment is restored for the new swarm
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().len(), 2);
+
+        optimizer.set_stop_checker(Box::new(MaxIterations::new(0)));
+        optimizer.find_min();
+        assert_eq!(
+            optimizer
+                .heterogeneous_velocity
+                .as_ref()
+                .unwrap()
+                .assignment,
+            vec![0, 1, 0, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn adaptive_size_step_greater_than_initial_size() {
+        let mut optimizer = create_plateau_optimizer(1);
+        optimizer.set_stagnation_reinit(2, Box::new(ZeroVelocityInitializer::new(2, 5)));
+        optimizer.set_adaptive_size(5, 30, AdaptiveSizePolicy::new(3, 2, 12));
+        optimizer.find_min();
+        assert_eq!(optimizer.get_swarm().len(), 5);
+
+        optimizer.next_n_iterations(2);
+        assert_eq!(optimizer.get_swarm().len(), 17);
+
+        optimizer.next_n_iterations(3);
+        assert_eq!(optimizer.get_swarm().len(), 29);
+
+        optimizer.next_n_iterations(3);
+        assert_eq!(optimizer.get_swarm().len(), 30);
+        assert!(!optimizer.get_stagnation_reinit_iterations().is_empty());
+    }
+
+    #[test]
+    fn create_velocity_several_calls() {
+        let mut initializer = ZeroVelocityInitializer::new(2, 3);
+        let velocity: Vec<Velocity<f64>> = create_velocity(&mut initializer, 7);
+        assert_eq!(velocity, vec![vec![0.0, 0.0]; 7]);
+
+        let velocity: Vec<Velocity<f64>> = create_velocity(&mut initializer, 0);
+        assert!(velocity.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn create_velocity_empty_initializer() {
+        let mut initializer = ZeroVelocityInitializer::new(2, 0);
+        let _velocity: Vec<Velocity<f64>> = create_velocity(&mut initializer, 1);
+    }
+
+    #[test]
+    fn remove_worst_particles() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 3.0),
+            Particle::new(vec![1.0], vec![0.0], f64::NAN),
+            Particle::new(vec![2.0], vec![0.0], 1.0),
+            Particle::new(vec![3.0], vec![0.0], 5.0),
+        ]);
+
+        assert_eq!(swarm.remove_worst_particles(2), vec![3, 1]);
+
+        let coordinates: Vec<Vec<f64>> = swarm
+            .particles
+            .iter()
+            .map(|particle| particle.coordinates.clone())
+            .collect();
+        assert_eq!(coordinates, vec![vec![0.0], vec![2.0]]);
+    }
+
+    /// The position updater moves all particles to the same point.
+    struct ConstPositionUpdater {
+        coordinates: Vec<f64>,
+    }
+
+    impl PositionUpdater<f64> for ConstPositionUpdater {
+        fn calc_new_coordinates(
+            &mut self,
+            _swarm: &Swarm<f64>,
+            _particle: &Particle<f64>,
+        ) -> Vec<f64> {
+            self.coordinates.clone()
+        }
+    }
+
+    #[test]
+    fn position_updater() {
+        let initializer = WarmStartInitializer {
+            coordinates: vec![vec![1.0, 1.0], vec![0.5, 2.0]],
+            personal_best: vec![(vec![1.0, 1.0], 1.0), (vec![0.5, 2.0], 1.0)],
+        };
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(PlateauGoal),
+            Box::new(MaxIterations::new(1)),
+            Box::new(initializer),
+            Box::new(ZeroVelocityInitializer::new(2, 2)),
+            Box::new(ConstVelocityCalculator {
+                velocity: vec![100.0, 100.0],
+            }),
+        );
+        optimizer.set_post_velocity_calc(vec![Box::new(
+            crate::particleswarm::postvelocitycalc::MaxVelocityAbs::new(0.0),
+        )]);
+        optimizer.set_position_updater(Box::new(ConstPositionUpdater {
+            coordinates: vec![3.0, 3.0],
+        }));
+        optimizer.find_min();
+
+        // The velocity is the displacement of the particles
+        let particles = &optimizer.get_swarm().particles;
+        assert_eq!(particles[0].coordinates, vec![3.0, 3.0]);
+        assert_eq!(particles[0].velocity, vec![2.0, 2.0]);
+        assert_eq!(particles[1].coordinates, vec![3.0, 3.0]);
+        assert_eq!(particles[1].velocity, vec![2.5, 1.0]);
+    }
+
+    #[test]
+    fn set_goal() {
+        let mut optimizer = create_plateau_optimizer(5);
+        optimizer.find_min();
+
+        let goal = |x: &Vec<f64>| x.iter().map(|xi| (xi - 0.5) * (xi - 0.5)).sum::<f64>();
+        optimizer.set_goal(Box::new(crate::GoalFromFunction::new(goal)));
+
+        let swarm = optimizer.get_swarm();
+        for particle in &swarm.particles {
+            assert_eq!(particle.value, goal(&particle.coordinates));
+            assert_eq!(
+                particle.best_personal_value,
+                goal(&particle.best_personal_coordinates)
+            );
+            assert!(particle.best_personal_value <= particle.value);
+        }
+
+        let best_value = swarm
+            .particles
+            .iter()
+            .map(|particle| particle.value)
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(swarm.best_particle.as_ref().unwrap().value, best_value);
+
+        assert!(optimizer.next_n_iterations(2).is_some());
+    }
+
+    #[test]
+    fn get_all_best_particles() {
+        let mut swarm = Swarm::new();
+        assert!(swarm.get_all_best_particles(1e-6).is_empty());
+
+        swarm.replace_particles(vec![
+            Particle::new(vec![1.0], vec![0.0], 2.0),
+            Particle::new(vec![2.0], vec![0.0], 0.5),
+            Particle::new(vec![3.0], vec![0.0], f64::NAN),
+            Particle::new(vec![4.0], vec![0.0], 0.5000001),
+            Particle::new(vec![5.0], vec![0.0], 0.51),
+            Particle::new(vec![6.0], vec![0.0], 0.5),
+        ]);
+
+        let best: Vec<&Vec<f64>> = swarm
+            .get_all_best_particles(1e-6)
+            .iter()
+            .map(|particle| &particle.coordinates)
+            .collect();
+        assert_eq!(best, vec![&vec![2.0], &vec![4.0], &vec![6.0]]);
+    }
 }
@@ -0,0 +1,86 @@
+//! The module with the structs which implement the `PositionUpdater` trait to calculate new
+//! coordinates of the particles without velocity.
+
+use num::Float;
+use rand::rngs::ThreadRng;
+
+use crate::particleswarm::{Particle, PositionUpdater, Swarm};
+use crate::tools::sample_standard_normal;
+
+/// BareBonesUpdater implements the bare bones particle swarm from the article
+/// Kennedy, J. (2003). "Bare bones particle swarms".
+/// Proceedings of the 2003 IEEE Swarm Intelligence Symposium, pp.80-87.
+/// x_i = N((p_i + g_i) / 2, |p_i - g_i|)
+/// `x_i` - new coordinate for dimension i,
+/// `p_i` - personal best coordinate,
+/// `g_i` - global best coordinate,
+/// `N(mean, sigma)` - random value from the normal distribution.
+pub struct BareBonesUpdater {
+    random: ThreadRng,
+}
+
+impl BareBonesUpdater {
+    pub fn new() -> Self {
+        Self {
+            random: rand::thread_rng(),
+        }
+    }
+}
+
+impl Default for BareBonesUpdater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float> PositionUpdater<T> for BareBonesUpdater {
+    fn calc_new_coordinates(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let global_best_particle = swarm.best_particle.as_ref().unwrap();
+        let global_best_solution = &global_best_particle.coordinates;
+        let two = T::one() + T::one();
+
+        particle
+            .best_personal_coordinates
+            .iter()
+            .zip(global_best_solution.iter())
+            .map(|(personal, global)| {
+                let mean = (*personal + *global) / two;
+                let sigma = (*personal - *global).abs();
+                let normal = T::from(sample_standard_normal(&mut self.random)).unwrap();
+                mean + sigma * normal
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_bones_distribution() {
+        let mut swarm = Swarm::new();
+        let mut particle = Particle::new(vec![0.0, 0.0], vec![0.0, 0.0], 10.0);
+        particle.set_best_personal(vec![1.0, 5.0], 1.0, swarm.objective);
+        swarm.replace_particles(vec![Particle::new(vec![3.0, 5.0], vec![0.0, 0.0], 0.0)]);
+
+        let mut updater = BareBonesUpdater::new();
+        let count = 20000;
+        let samples: Vec<Vec<f64>> = (0..count)
+            .map(|_| updater.calc_new_coordinates(&swarm, &particle))
+            .collect();
+
+        // The first dimension: mean = 2, sigma = 2
+        let mean = samples.iter().map(|x| x[0]).sum::<f64>() / count as f64;
+        let variance = samples
+            .iter()
+            .map(|x| (x[0] - mean) * (x[0] - mean))
+            .sum::<f64>()
+            / count as f64;
+        assert!((mean - 2.0).abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+
+        // The second dimension: the bests are equal
+        assert!(samples.iter().all(|x| x[1] == 5.0));
+    }
+}
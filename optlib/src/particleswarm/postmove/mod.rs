@@ -1,4 +1,5 @@
 use crate::particleswarm::PostMove;
+use crate::tools::Intervals;
 
 use num::Float;
 use rand::distributions::uniform::SampleUniform;
@@ -7,34 +8,74 @@ use rand::rngs::ThreadRng;
 
 /// The struct to limit the coordinates of particle.
 pub struct MoveToBoundary<T> {
-    intervals: Vec<(T, T)>,
+    intervals: Intervals<T>,
 }
 
-impl<T> MoveToBoundary<T> {
+impl<T: PartialOrd> MoveToBoundary<T> {
     /// Constructor.
     ///
     /// # Parameters
     /// `intervals` - `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(intervals: Vec<(T, T)>) -> Self {
-        Self { intervals }
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+        }
     }
 }
 
 impl<T: Float> PostMove<T> for MoveToBoundary<T> {
     fn post_move(&mut self, coordinates: &mut Vec<T>) {
-        assert_eq!(coordinates.len(), self.intervals.len());
+        self.intervals.clamp(coordinates);
+    }
+}
 
-        for i in 0..coordinates.len() {
-            if !coordinates[i].is_finite() {
-                coordinates[i] = self.intervals[i].0;
-            }
+/// The struct to limit the coordinates of particle as `MoveToBoundary` ("absorbing wall").
+/// Additionally the struct zeroes the velocity components for the dimensions where the
+/// coordinates were moved to the boundary, so the particle does not push against the wall on the
+/// next iteration.
+///
+/// The velocity is changed by `PostMove::post_move_velocity()` only. `post_move()` limits the
+/// coordinates only.
+pub struct AbsorbingWall<T> {
+    intervals: Intervals<T>,
+}
 
-            if coordinates[i] < self.intervals[i].0 {
-                coordinates[i] = self.intervals[i].0;
-            }
+impl<T: PartialOrd> AbsorbingWall<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(T, T)>) -> Self {
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+        }
+    }
+}
 
-            if coordinates[i] > self.intervals[i].1 {
-                coordinates[i] = self.intervals[i].1;
+impl<T: Float> PostMove<T> for AbsorbingWall<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        self.intervals.clamp(coordinates);
+    }
+
+    fn post_move_velocity(&mut self, coordinates: &mut Vec<T>, velocity: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), velocity.len());
+
+        let old_coordinates = coordinates.clone();
+        self.intervals.clamp(coordinates);
+
+        for ((new, old), v) in coordinates
+            .iter()
+            .zip(old_coordinates.iter())
+            .zip(velocity.iter_mut())
+        {
+            if new != old {
+                *v = T::zero();
             }
         }
     }
@@ -42,7 +83,7 @@ impl<T: Float> PostMove<T> for MoveToBoundary<T> {
 
 /// The struct to move particle to random position with given probability
 pub struct RandomTeleport<T: Float + SampleUniform> {
-    intervals: Vec<(T, T)>,
+    intervals: Intervals<T>,
     probability: f32,
     random: ThreadRng,
     random_intervals: Vec<Uniform<T>>,
@@ -54,9 +95,13 @@ impl<T: Float + SampleUniform> RandomTeleport<T> {
     /// # Parameters
     /// `intervals` - `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
     /// `probability` - probability of particle teleportation. Must be in the range [0, 1].
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
     pub fn new(intervals: Vec<(T, T)>, probability: f32) -> Self {
         assert!(probability >= 0_f32);
         assert!(probability <= 1_f32);
+        let intervals = Intervals::new(intervals).unwrap();
         let random_intervals = intervals
             .iter()
             .map(|(min, max)| Uniform::new_inclusive(min, max))
@@ -84,11 +129,289 @@ impl<T: Float + SampleUniform> PostMove<T> for RandomTeleport<T> {
     }
 }
 
+/// The struct to round the coordinates of particle to the nearest node of the grid.
+/// The grid node is origin + n * step, where n is integer.
+/// If the step is 0 for a dimension, the coordinate is not changed (continuous dimension).
+///
+/// The struct may be used for mixed-integer optimization. Usually the struct should be applied
+/// after boundary correction (for example, after `MoveToBoundary`), so the snapped coordinates
+/// are not changed by the correction.
+pub struct SnapToGrid<T: Float> {
+    steps: Vec<T>,
+    origin: Vec<T>,
+}
+
+impl<T: Float> SnapToGrid<T> {
+    /// Constructor. The grid origin is zero point.
+    ///
+    /// # Parameters
+    /// `steps` - grid step for every dimension. Size of the vector must be equal to dimension. The step must not be negative.
+    pub fn new(steps: Vec<T>) -> Self {
+        for step in &steps {
+            assert!(*step >= T::zero());
+        }
+
+        let origin = vec![T::zero(); steps.len()];
+        Self { steps, origin }
+    }
+
+    /// Set the grid origin.
+    pub fn origin(mut self, origin: Vec<T>) -> Self {
+        assert_eq!(origin.len(), self.steps.len());
+        self.origin = origin;
+        self
+    }
+}
+
+impl<T: Float> PostMove<T> for SnapToGrid<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), self.steps.len());
+
+        for ((x, step), origin) in coordinates
+            .iter_mut()
+            .zip(self.steps.iter())
+            .zip(self.origin.iter())
+        {
+            if *step > T::zero() && x.is_finite() {
+                *x = *origin + ((*x - *origin) / *step).round() * *step;
+            }
+        }
+    }
+}
+
+/// The struct to project the coordinates of particle onto the hyperplane `sum(x_i) = C`
+/// (linear equality constraint, for example, for portfolio weights). The projection is the
+/// nearest point on the hyperplane (Euclidean distance).
+///
+/// If the `non_negative` flag is set, the coordinates are projected onto the simplex
+/// `sum(x_i) = C, x_i >= 0` by algorithm from the article Duchi, J.; Shalev-Shwartz, S.;
+/// Singer, Y.; Chandra, T. (2008). "Efficient projections onto the l1-ball for learning in high
+/// dimensions". Proceedings of the 25th International Conference on Machine Learning.
+pub struct ProjectToSimplex<T: Float> {
+    sum: T,
+    non_negative: bool,
+}
+
+impl<T: Float> ProjectToSimplex<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `sum` - the target sum of the coordinates (C). Must not be negative if `non_negative`
+    ///   is set. If `sum` is 0 and `non_negative` is set, all coordinates are set to 0 (the
+    ///   simplex is single point).
+    /// * `non_negative` - if true, the coordinates must also be greater or equal than 0.
+    pub fn new(sum: T, non_negative: bool) -> Self {
+        assert!(!non_negative || sum >= T::zero());
+        Self { sum, non_negative }
+    }
+
+    fn project_to_hyperplane(&self, coordinates: &mut [T]) {
+        let n = T::from(coordinates.len()).unwrap();
+        let current_sum = coordinates.iter().fold(T::zero(), |acc, x| acc + *x);
+        let shift = (self.sum - current_sum) / n;
+
+        for x in coordinates.iter_mut() {
+            *x = *x + shift;
+        }
+    }
+
+    fn project_to_simplex(&self, coordinates: &mut [T]) {
+        if self.sum == T::zero() {
+            coordinates.iter_mut().for_each(|x| *x = T::zero());
+            return;
+        }
+
+        let mut sorted = coordinates.to_vec();
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+        // Find the threshold theta: x_i = max(x_i - theta, 0)
+        let mut cumulative_sum = T::zero();
+        let mut theta = T::zero();
+        for (j, u) in sorted.iter().enumerate() {
+            cumulative_sum = cumulative_sum + *u;
+            let current_theta = (cumulative_sum - self.sum) / T::from(j + 1).unwrap();
+            if *u - current_theta > T::zero() {
+                theta = current_theta;
+            }
+        }
+
+        for x in coordinates.iter_mut() {
+            *x = (*x - theta).max(T::zero());
+        }
+    }
+}
+
+impl<T: Float> PostMove<T> for ProjectToSimplex<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        if coordinates.is_empty() || coordinates.iter().any(|x| !x.is_finite()) {
+            return;
+        }
+
+        if self.non_negative {
+            self.project_to_simplex(coordinates);
+        } else {
+            self.project_to_hyperplane(coordinates);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::particleswarm::{postmove::MoveToBoundary, PostMove};
+    use crate::particleswarm::{
+        postmove::{AbsorbingWall, MoveToBoundary, ProjectToSimplex, SnapToGrid},
+        PostMove,
+    };
     use num::abs;
 
+    #[test]
+    fn snap_to_grid() {
+        let mut postmove = SnapToGrid::new(vec![1.0_f64, 0.5_f64, 0.25_f64]);
+        let mut coordinates = vec![2.4_f64, -1.3_f64, 0.13_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![2.0_f64, -1.5_f64, 0.25_f64]);
+    }
+
+    #[test]
+    fn snap_to_grid_origin() {
+        let mut postmove = SnapToGrid::new(vec![1.0_f64, 2.0_f64]).origin(vec![0.5_f64, 1.0_f64]);
+        let mut coordinates = vec![2.2_f64, 4.1_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![2.5_f64, 5.0_f64]);
+    }
+
+    #[test]
+    fn snap_to_grid_zero_step() {
+        let mut postmove = SnapToGrid::new(vec![0.0_f64, 1.0_f64]);
+        let mut coordinates = vec![2.123_f64, 2.7_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![2.123_f64, 3.0_f64]);
+    }
+
+    #[test]
+    fn snap_to_grid_after_boundary() {
+        let mut boundary = MoveToBoundary::new(vec![(-10.0_f64, 10.0_f64)]);
+        let mut snap = SnapToGrid::new(vec![3.0_f64]);
+        let mut coordinates = vec![100.0_f64];
+
+        boundary.post_move(&mut coordinates);
+        snap.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![9.0_f64]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn snap_to_grid_negative_step() {
+        SnapToGrid::new(vec![-1.0_f64]);
+    }
+
+    #[test]
+    fn absorbing_wall() {
+        let mut postmove = AbsorbingWall::new(vec![(0.0_f64, 1.0_f64); 4]);
+        let mut coordinates = vec![1.5_f64, 0.5_f64, -2.0_f64, 1.0_f64];
+        let mut velocity = vec![0.7_f64, 0.3_f64, -1.0_f64, 0.2_f64];
+        postmove.post_move_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![1.0_f64, 0.5_f64, 0.0_f64, 1.0_f64]);
+        assert_eq!(velocity, vec![0.0_f64, 0.3_f64, 0.0_f64, 0.2_f64]);
+    }
+
+    #[test]
+    fn absorbing_wall_coordinates_only() {
+        let mut postmove = AbsorbingWall::new(vec![(0.0_f64, 1.0_f64); 2]);
+        let mut coordinates = vec![1.5_f64, 0.5_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![1.0_f64, 0.5_f64]);
+    }
+
+    #[test]
+    fn default_post_move_velocity() {
+        let mut postmove = MoveToBoundary::new(vec![(0.0_f64, 1.0_f64); 2]);
+        let mut coordinates = vec![1.5_f64, 0.5_f64];
+        let mut velocity = vec![0.7_f64, 0.3_f64];
+        postmove.post_move_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![1.0_f64, 0.5_f64]);
+        assert_eq!(velocity, vec![0.7_f64, 0.3_f64]);
+    }
+
+    #[test]
+    fn project_to_hyperplane() {
+        let mut postmove = ProjectToSimplex::new(1.0_f64, false);
+        let mut coordinates = vec![0.5_f64, -1.0_f64, 2.5_f64, 1.0_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.0_f64, -1.5_f64, 2.0_f64, 0.5_f64]);
+    }
+
+    #[test]
+    fn project_to_hyperplane_feasible() {
+        let mut postmove = ProjectToSimplex::new(2.0_f64, false);
+        let mut coordinates = vec![0.5_f64, -1.0_f64, 2.5_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.5_f64, -1.0_f64, 2.5_f64]);
+    }
+
+    #[test]
+    fn project_to_simplex() {
+        let mut postmove = ProjectToSimplex::new(1.0_f64, true);
+
+        let mut coordinates = vec![0.5_f64, -1.0_f64, 2.5_f64, 1.0_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![0.0_f64, 0.0_f64, 1.0_f64, 0.0_f64]);
+
+        let mut coordinates = vec![1.0_f64, 0.5_f64, 0.0_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![0.75_f64, 0.25_f64, 0.0_f64]);
+
+        let mut coordinates = vec![-1.0_f64, -1.0_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![0.5_f64, 0.5_f64]);
+    }
+
+    #[test]
+    fn project_to_simplex_feasible() {
+        let mut postmove = ProjectToSimplex::new(1.0_f64, true);
+        let mut coordinates = vec![0.25_f64, 0.5_f64, 0.0_f64, 0.25_f64];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.25_f64, 0.5_f64, 0.0_f64, 0.25_f64]);
+    }
+
+    #[test]
+    fn project_to_simplex_sum() {
+        let mut postmove = ProjectToSimplex::new(3.0_f64, true);
+        let mut coordinates = vec![0.3_f64, 5.7_f64, -2.2_f64, 1.9_f64, 2.1_f64];
+        postmove.post_move(&mut coordinates);
+
+        let sum: f64 = coordinates.iter().sum();
+        assert!(abs(sum - 3.0) < 1e-12);
+        assert!(coordinates.iter().all(|x| *x >= 0.0));
+    }
+
+    #[test]
+    fn project_to_simplex_zero_sum() {
+        let mut postmove = ProjectToSimplex::new(0.0_f64, true);
+        let mut coordinates = vec![0.3_f64, 5.7_f64, -2.2_f64, 1.9_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![0.0; 4]);
+
+        let mut coordinates = vec![-1.0_f64, -2.0_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![0.0; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn project_to_simplex_negative_sum() {
+        ProjectToSimplex::new(-1.0_f64, true);
+    }
+
     #[test]
     fn test_empty() {
         let intervals: Vec<(f32, f32)> = vec![];
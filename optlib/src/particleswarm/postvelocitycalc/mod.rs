@@ -1,5 +1,9 @@
 use num::Float;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::ThreadRng;
 
+use crate::particleswarm::velocitycalc::{ConstInertia, Inertia};
 use crate::particleswarm::PostVelocityCalc;
 
 /// The trait to restrict value for every dimension of velocity
@@ -62,10 +66,86 @@ impl<T: Float> PostVelocityCalc<T> for MaxVelocityAbs<T> {
     }
 }
 
+/// The struct multiplies the velocity by the damping factor `d` (0 <= d <= 1) after any velocity
+/// calculator. The damping factor may depend on the iteration number (see the `Inertia` trait).
+/// The value of the schedule is clamped to [0; 1], NaN is replaced by 0.
+pub struct DampVelocity<'a, T> {
+    schedule: Box<dyn Inertia<T> + 'a>,
+    damping: T,
+}
+
+impl<'a, T: Float + 'a> DampVelocity<'a, T> {
+    /// Constructor for the constant damping factor.
+    pub fn new(damping: T) -> Self {
+        assert!((T::zero()..=T::one()).contains(&damping));
+        Self::from_schedule(Box::new(ConstInertia::new(damping)))
+    }
+
+    /// Constructor for the damping factor which depends on the iteration number. The values of
+    /// the schedule outside of [0; 1] are clamped.
+    pub fn from_schedule(mut schedule: Box<dyn Inertia<T> + 'a>) -> Self {
+        let damping = Self::clamp_damping(schedule.get(0));
+        Self { schedule, damping }
+    }
+
+    /// Move the damping factor inside [0; 1]. NaN becomes 0.
+    fn clamp_damping(damping: T) -> T {
+        damping.max(T::zero()).min(T::one())
+    }
+}
+
+impl<'a, T: Float + 'a> PostVelocityCalc<T> for DampVelocity<'a, T> {
+    fn correct_velocity(&mut self, velocity: Vec<T>) -> Vec<T> {
+        velocity.iter().map(|vi| *vi * self.damping).collect()
+    }
+
+    fn next_iteration(&mut self, iteration: usize) {
+        self.damping = Self::clamp_damping(self.schedule.get(iteration));
+    }
+}
+
+/// The struct adds uniform random noise in [-amp, amp] to every dimension of the velocity after
+/// any velocity calculator. The noise may be used to prevent stagnation of the swarm.
+/// `VelocityJitter` should be used before `MaxVelocityAbs` and `MaxVelocityDimensions` to keep
+/// the velocity restricted.
+pub struct VelocityJitter<T: Float + SampleUniform> {
+    amp: T,
+    random: ThreadRng,
+}
+
+impl<T: Float + SampleUniform> VelocityJitter<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `amp` - the noise amplitude. Must be greater or equal than 0.
+    pub fn new(amp: T) -> Self {
+        assert!(amp >= T::zero());
+        Self {
+            amp,
+            random: rand::thread_rng(),
+        }
+    }
+}
+
+impl<T: Float + SampleUniform> PostVelocityCalc<T> for VelocityJitter<T> {
+    fn correct_velocity(&mut self, velocity: Vec<T>) -> Vec<T> {
+        if self.amp == T::zero() {
+            return velocity;
+        }
+
+        let between = Uniform::new_inclusive(-self.amp, self.amp);
+        velocity
+            .iter()
+            .map(|vi| *vi + between.sample(&mut self.random))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use num::abs;
-    use super::{MaxVelocityAbs, MaxVelocityDimensions};
+    use super::{DampVelocity, MaxVelocityAbs, MaxVelocityDimensions, VelocityJitter};
+    use crate::particleswarm::velocitycalc::{Inertia, LinearInertia};
     use crate::particleswarm::PostVelocityCalc;
 
     #[test]
@@ -145,4 +225,111 @@ mod tests {
 
         assert!(abs(new_velocity_abs - max_velocity) < 1e-3);
     }
+
+    #[test]
+    fn test_damp_velocity() {
+        let mut post_velocity = DampVelocity::new(0.5_f64);
+        let new_velocity = post_velocity.correct_velocity(vec![4.0, -2.0, 0.0]);
+
+        assert_eq!(new_velocity, vec![2.0, -1.0, 0.0]);
+
+        post_velocity.next_iteration(10);
+        let new_velocity = post_velocity.correct_velocity(vec![4.0, -2.0, 0.0]);
+
+        assert_eq!(new_velocity, vec![2.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_damp_velocity_schedule() {
+        let schedule = LinearInertia::new(0.5_f64, 1.0_f64, 10);
+        let mut post_velocity = DampVelocity::from_schedule(Box::new(schedule));
+
+        assert_eq!(post_velocity.correct_velocity(vec![4.0, -2.0]), vec![4.0, -2.0]);
+
+        post_velocity.next_iteration(5);
+        assert_eq!(post_velocity.correct_velocity(vec![4.0, -2.0]), vec![3.0, -1.5]);
+
+        post_velocity.next_iteration(10);
+        assert_eq!(post_velocity.correct_velocity(vec![4.0, -2.0]), vec![2.0, -1.0]);
+    }
+
+    /// The schedule returns the preset values.
+    struct PresetSchedule {
+        values: Vec<f64>,
+    }
+
+    impl Inertia<f64> for PresetSchedule {
+        fn get(&mut self, iteration: usize) -> f64 {
+            self.values[iteration]
+        }
+    }
+
+    #[test]
+    fn test_damp_velocity_schedule_out_of_range() {
+        let schedule = PresetSchedule {
+            values: vec![1.5, -0.5, 0.25, f64::NAN],
+        };
+        let mut post_velocity = DampVelocity::from_schedule(Box::new(schedule));
+
+        assert_eq!(
+            post_velocity.correct_velocity(vec![4.0, -2.0]),
+            vec![4.0, -2.0]
+        );
+
+        post_velocity.next_iteration(1);
+        assert_eq!(
+            post_velocity.correct_velocity(vec![4.0, -2.0]),
+            vec![0.0, 0.0]
+        );
+
+        post_velocity.next_iteration(2);
+        assert_eq!(
+            post_velocity.correct_velocity(vec![4.0, -2.0]),
+            vec![1.0, -0.5]
+        );
+
+        post_velocity.next_iteration(3);
+        assert_eq!(
+            post_velocity.correct_velocity(vec![4.0, -2.0]),
+            vec![0.0, 0.0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_damp_velocity_invalid() {
+        DampVelocity::new(1.5_f64);
+    }
+
+    #[test]
+    fn test_velocity_jitter() {
+        let velocity = vec![1.0_f64, -2.0_f64, 0.0_f64];
+        let mut post_velocity = VelocityJitter::new(0.1);
+
+        let mut changed = false;
+        for _ in 0..100 {
+            let new_velocity = post_velocity.correct_velocity(velocity.clone());
+            assert_eq!(new_velocity.len(), velocity.len());
+            for (new_vi, vi) in new_velocity.iter().zip(velocity.iter()) {
+                assert!(abs(new_vi - vi) <= 0.1 + 1e-12);
+                changed |= new_vi != vi;
+            }
+        }
+
+        assert!(changed);
+    }
+
+    #[test]
+    fn test_velocity_jitter_zero() {
+        let velocity = vec![1.0_f32, -2.0_f32];
+        let mut post_velocity = VelocityJitter::new(0.0);
+
+        assert_eq!(post_velocity.correct_velocity(velocity.clone()), velocity);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_velocity_jitter_invalid() {
+        VelocityJitter::new(-1.0_f64);
+    }
 }
@@ -1,9 +1,21 @@
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs::ThreadRng;
 
-use num::{Float, Num, NumCast};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
-use crate::particleswarm::{Particle, VelocityCalculator, Swarm};
+use num::{Float, Num, NumCast, ToPrimitive};
+
+use crate::particleswarm::{compare_goals, Particle, Swarm, VelocityCalculator};
+use crate::{update_param, Objective};
+
+/// Returns the "phi_personal" and "phi_global" parameters for `VelocityCalculator::get_params()`.
+fn get_phi_params<T: ToPrimitive>(phi_personal: &T, phi_global: &T) -> HashMap<String, f64> {
+    let mut params = HashMap::new();
+    params.insert("phi_personal".to_string(), phi_personal.to_f64().unwrap());
+    params.insert("phi_global".to_string(), phi_global.to_f64().unwrap());
+    params
+}
 
 /// ClassicVelocityCalculator implements the equation from the article
 /// Kennedy, J.; Eberhart, R. (1995). "Particle Swarm Optimization".
@@ -54,6 +66,16 @@ impl<T: NumCast + Num + Copy> VelocityCalculator<T> for ClassicVelocityCalculato
 
         new_velocity
     }
+
+    /// The parameters: "phi_personal" and "phi_global".
+    fn get_params(&self) -> HashMap<String, f64> {
+        get_phi_params(&self.phi_personal, &self.phi_global)
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "phi_personal", &mut self.phi_personal);
+        update_param(params, "phi_global", &mut self.phi_global);
+    }
 }
 
 /// CanonicalVelocityCalculator implements the "canonical" equation:
@@ -71,6 +93,7 @@ impl<T: NumCast + Num + Copy> VelocityCalculator<T> for ClassicVelocityCalculato
 pub struct CanonicalVelocityCalculator<T> {
     phi_personal: T,
     phi_global: T,
+    alpha: T,
     xi: T,
 
     random: ThreadRng,
@@ -82,18 +105,35 @@ impl<T: Float> CanonicalVelocityCalculator<T> {
         assert!(alpha > T::zero());
         assert!(alpha < T::one());
 
-        let phi = phi_global + phi_personal;
-        let xi = T::from(2.0).unwrap() * alpha / (phi - T::from(2.0).unwrap());
+        let xi = Self::calc_xi(phi_personal, phi_global, alpha);
         Self {
             phi_personal,
             phi_global,
+            alpha,
             xi,
             random: rand::thread_rng(),
         }
     }
+
+    /// Move the parameters inside the valid range. The margin from the bounds is square root of
+    /// the machine epsilon.
+    fn clamp_params(&mut self) {
+        let margin = T::epsilon().sqrt();
+        self.alpha = self.alpha.max(margin).min(T::one() - margin);
+
+        let min_phi = T::from(4.0).unwrap() + margin;
+        if self.phi_personal + self.phi_global < min_phi {
+            self.phi_global = min_phi - self.phi_personal;
+        }
+    }
+
+    fn calc_xi(phi_personal: T, phi_global: T, alpha: T) -> T {
+        let phi = phi_global + phi_personal;
+        T::from(2.0).unwrap() * alpha / (phi - T::from(2.0).unwrap())
+    }
 }
 
-impl<T: NumCast + Num + Copy> VelocityCalculator<T> for CanonicalVelocityCalculator<T> {
+impl<T: Float> VelocityCalculator<T> for CanonicalVelocityCalculator<T> {
     fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
         let dimension = particle.coordinates.len();
         let global_best_particle = swarm.best_particle.as_ref().unwrap();
@@ -118,6 +158,24 @@ impl<T: NumCast + Num + Copy> VelocityCalculator<T> for CanonicalVelocityCalcula
 
         new_velocity
     }
+
+    /// The parameters: "phi_personal", "phi_global" and "alpha". The new values are moved inside
+    /// the valid range: `alpha` to (0, 1) and `phi_global` is increased if `phi_personal +
+    /// phi_global` is not greater than 4. The `xi` coefficient is recalculated.
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = get_phi_params(&self.phi_personal, &self.phi_global);
+        params.insert("alpha".to_string(), self.alpha.to_f64().unwrap());
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "phi_personal", &mut self.phi_personal);
+        update_param(params, "phi_global", &mut self.phi_global);
+        update_param(params, "alpha", &mut self.alpha);
+
+        self.clamp_params();
+        self.xi = Self::calc_xi(self.phi_personal, self.phi_global, self.alpha);
+    }
 }
 
 /// Velocity update with negative reinforcement, global and current best and worst positions.
@@ -238,6 +296,15 @@ impl<T: NumCast + Num + Copy> VelocityCalculator<T> for NegativeReinforcement<T>
 /// The trait to calculate the inertia coefficient (w) for InertiaVelocityCalculator
 pub trait Inertia<T> {
     fn get(&mut self, iteration: usize) -> T;
+
+    /// Returns the tunable parameters of the inertia (see `Tunable`).
+    fn get_params(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// Set the tunable parameters of the inertia (see `Tunable`). The unknown names must be
+    /// ignored.
+    fn set_params(&mut self, _params: &HashMap<String, f64>) {}
 }
 
 
@@ -252,10 +319,21 @@ impl<T> ConstInertia<T> {
     }
 }
 
-impl<T: Clone> Inertia<T> for ConstInertia<T> {
+impl<T: Clone + NumCast> Inertia<T> for ConstInertia<T> {
     fn get(&mut self, _iteration: usize) -> T {
         self.w.clone()
     }
+
+    /// The parameters: "inertia".
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = HashMap::new();
+        params.insert("inertia".to_string(), self.w.to_f64().unwrap());
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "inertia", &mut self.w);
+    }
 }
 
 /// The inertia coefficient decreases linearly from w_max to w_min
@@ -280,6 +358,19 @@ impl<T: Float> Inertia<T> for LinearInertia<T> {
         self.w_max
             - (self.w_max - self.w_min) * T::from(iteration).unwrap() / T::from(self.t_max).unwrap()
     }
+
+    /// The parameters: "inertia_min" and "inertia_max".
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = HashMap::new();
+        params.insert("inertia_min".to_string(), self.w_min.to_f64().unwrap());
+        params.insert("inertia_max".to_string(), self.w_max.to_f64().unwrap());
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "inertia_min", &mut self.w_min);
+        update_param(params, "inertia_max", &mut self.w_max);
+    }
 }
 
 /// InertiaVelocityCalculator implements the equation with itertia coefficient w(t)
@@ -334,4 +425,497 @@ impl<'a, T: NumCast + Num + Copy> VelocityCalculator<T> for InertiaVelocityCalcu
 
         new_velocity
     }
+
+    /// The parameters: "phi_personal", "phi_global" and the parameters of the inertia.
+    fn get_params(&self) -> HashMap<String, f64> {
+        let mut params = self.inertia.get_params();
+        params.extend(get_phi_params(&self.phi_personal, &self.phi_global));
+        params
+    }
+
+    fn set_params(&mut self, params: &HashMap<String, f64>) {
+        update_param(params, "phi_personal", &mut self.phi_personal);
+        update_param(params, "phi_global", &mut self.phi_global);
+        self.inertia.set_params(params);
+    }
+}
+
+/// ConstrictionRingCalculator implements the equation with the Clerc's constriction coefficient
+/// and the local best in the ring neighborhood instead of the global best (see Standard PSO 2007):
+/// v_i = xi * (v_i + phi_p * r_p * (p_i - x_i) + phi_g * r_g * (l_i - x_i))
+/// `v_i` - velocity projection for dimension i,
+/// `p_i` - personal best coordinate,
+/// `l_i` - the best personal coordinate in the ring neighborhood (see
+/// `Swarm::get_neighborhood_best()`),
+/// `x_i` - current coordinate,
+/// `phi_p`, `phi_g` - parameters,
+/// `r_p`, `r_g` - random values in (0, 1),
+/// `xi` = 2 / (phi - 2 + sqrt(phi^2 - 4 * phi)),
+/// `phi` = phi_p + phi_g,
+/// `phi` must be greater than 4.
+///
+/// `ParticleSwarmOptimizer` passes index of the particle by
+/// `VelocityCalculator::calc_new_velocity_by_index()`. `calc_new_velocity()` finds index of the
+/// particle in the swarm by address, so the particle must be a reference to the particle inside
+/// the swarm.
+pub struct ConstrictionRingCalculator<T> {
+    phi_personal: T,
+    phi_global: T,
+    xi: T,
+    neighborhood_size: usize,
+
+    random: ThreadRng,
+}
+
+impl<T: Float> ConstrictionRingCalculator<T> {
+    pub fn new(phi_personal: T, phi_global: T, neighborhood_size: usize) -> Self {
+        assert!(phi_personal + phi_global > T::from(4.0).unwrap());
+        assert!(neighborhood_size > 0);
+
+        let phi = phi_global + phi_personal;
+        let two = T::from(2.0).unwrap();
+        let xi = two / (phi - two + (phi * phi - T::from(4.0).unwrap() * phi).sqrt());
+        Self {
+            phi_personal,
+            phi_global,
+            xi,
+            neighborhood_size,
+            random: rand::thread_rng(),
+        }
+    }
+}
+
+impl<T: Float> VelocityCalculator<T> for ConstrictionRingCalculator<T> {
+    /// # Panics
+    /// Panics if `particle` is not a reference to the particle inside `swarm`.
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let index = swarm
+            .particles
+            .iter()
+            .position(|item| std::ptr::eq(item, particle))
+            .expect("The particle must be inside the swarm");
+        self.calc_new_velocity_by_index(swarm, index)
+    }
+
+    fn calc_new_velocity_by_index(&mut self, swarm: &Swarm<T>, index: usize) -> Vec<T> {
+        let particle = &swarm.particles[index];
+        let dimension = particle.coordinates.len();
+        let local_best_solution = swarm.get_neighborhood_best(index, self.neighborhood_size);
+
+        let between = Uniform::new_inclusive(0.0_f32, 1.0_f32);
+        let mut new_velocity = Vec::with_capacity(dimension);
+        for (i, local_best) in local_best_solution.iter().enumerate() {
+            let r_personal = T::from(between.sample(&mut self.random)).unwrap();
+            let r_global = T::from(between.sample(&mut self.random)).unwrap();
+
+            let velocity_item = self.xi
+                * (particle.velocity[i]
+                    + self.phi_personal
+                        * r_personal
+                        * (particle.best_personal_coordinates[i] - particle.coordinates[i])
+                    + self.phi_global * r_global * (*local_best - particle.coordinates[i]));
+            new_velocity.push(velocity_item);
+        }
+
+        new_velocity
+    }
+}
+
+/// GcpsoVelocityCalculator implements the Guaranteed Convergence PSO (GCPSO) from the article
+/// van den Bergh, F.; Engelbrecht, A.P. (2002). "A new locally convergent particle swarm
+/// optimiser". IEEE International Conference on Systems, Man and Cybernetics.
+/// The particle which is the global best is moved to the random point near the global best:
+/// v_i = -x_i + g_i + w * v_i + rho * (1 - 2 * r)
+/// Other particles use the equation with the inertia coefficient:
+/// v_i = w * v_i + phi_p * r_p * (p_i - x_i) + phi_g * r_g * (g_i - x_i)
+/// `v_i` - velocity projection for dimension i,
+/// `p_i` - personal best coordinate,
+/// `g_i` - global best coordinate,
+/// `x_i` - current coordinate,
+/// `w`, `phi_p`, `phi_g` - parameters,
+/// `r`, `r_p`, `r_g` - random values in (0, 1),
+/// `rho` - the search radius around the global best.
+///
+/// The particle is the global best if its coordinates and goal function value equal to the
+/// swarm's best particle. `rho` is updated once per iteration of the swarm: `rho` is doubled if
+/// the global best value improved more than `success_threshold` iterations in a row and it is
+/// halved if the global best value did not improve more than `failure_threshold` iterations in
+/// a row.
+pub struct GcpsoVelocityCalculator<T> {
+    phi_personal: T,
+    phi_global: T,
+    w: T,
+    rho: T,
+    success_threshold: usize,
+    failure_threshold: usize,
+
+    successes: usize,
+    failures: usize,
+    best_value: Option<f64>,
+
+    // The iteration of the swarm when `rho` was updated last time
+    last_iteration: Option<usize>,
+
+    random: ThreadRng,
+}
+
+impl<T: Float> GcpsoVelocityCalculator<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `phi_personal`, `phi_global` - the parameters for the classic rule.
+    /// * `w` - inertia coefficient.
+    /// * `rho` - initial search radius around the global best. Must be greater than 0.
+    /// * `success_threshold` - count of the consecutive successes to double `rho`.
+    /// * `failure_threshold` - count of the consecutive failures to halve `rho`.
+    pub fn new(
+        phi_personal: T,
+        phi_global: T,
+        w: T,
+        rho: T,
+        success_threshold: usize,
+        failure_threshold: usize,
+    ) -> Self {
+        assert!(rho > T::zero());
+
+        Self {
+            phi_personal,
+            phi_global,
+            w,
+            rho,
+            success_threshold,
+            failure_threshold,
+            successes: 0,
+            failures: 0,
+            best_value: None,
+            last_iteration: None,
+            random: rand::thread_rng(),
+        }
+    }
+
+    /// Returns the current search radius around the global best.
+    pub fn get_rho(&self) -> T {
+        self.rho
+    }
+
+    /// Update counters of the successes and failures and adapt `rho`.
+    fn update_rho(&mut self, best_value: f64, objective: Objective) {
+        if let Some(old_best_value) = self.best_value {
+            if compare_goals(best_value, old_best_value, objective) == Ordering::Less {
+                self.successes += 1;
+                self.failures = 0;
+            } else {
+                self.failures += 1;
+                self.successes = 0;
+            }
+
+            if self.successes > self.success_threshold {
+                self.rho = self.rho * T::from(2.0).unwrap();
+            } else if self.failures > self.failure_threshold {
+                self.rho = self.rho * T::from(0.5).unwrap();
+            }
+        }
+
+        self.best_value = Some(best_value);
+    }
+}
+
+impl<T: Float> VelocityCalculator<T> for GcpsoVelocityCalculator<T> {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let dimension = particle.coordinates.len();
+        let global_best_particle = swarm.best_particle.as_ref().unwrap();
+        let global_best_solution = &global_best_particle.coordinates;
+
+        if self.last_iteration != Some(swarm.iteration) {
+            self.last_iteration = Some(swarm.iteration);
+            self.update_rho(global_best_particle.value, swarm.objective);
+        }
+
+        let between = Uniform::new_inclusive(0.0_f32, 1.0_f32);
+        let mut new_velocity = Vec::with_capacity(dimension);
+
+        if particle.coordinates == *global_best_solution
+            && particle.value == global_best_particle.value
+        {
+            let one = T::one();
+            let two = T::from(2.0).unwrap();
+            for (i, global_best) in global_best_solution.iter().enumerate() {
+                let r = T::from(between.sample(&mut self.random)).unwrap();
+                let velocity_item = *global_best - particle.coordinates[i]
+                    + self.w * particle.velocity[i]
+                    + self.rho * (one - two * r);
+                new_velocity.push(velocity_item);
+            }
+        } else {
+            for (i, global_best) in global_best_solution.iter().enumerate() {
+                let r_personal = T::from(between.sample(&mut self.random)).unwrap();
+                let r_global = T::from(between.sample(&mut self.random)).unwrap();
+
+                let velocity_item = self.w * particle.velocity[i]
+                    + self.phi_personal
+                        * r_personal
+                        * (particle.best_personal_coordinates[i] - particle.coordinates[i])
+                    + self.phi_global * r_global * (*global_best - particle.coordinates[i]);
+                new_velocity.push(velocity_item);
+            }
+        }
+
+        new_velocity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Particles on the line with the goal function value equal to the coordinate except the
+    /// last particle which is the global best.
+    fn create_swarm() -> Swarm<f64> {
+        let mut swarm = Swarm::new();
+        let particles = vec![
+            Particle::new(vec![1.0], vec![0.0], 1.0),
+            Particle::new(vec![2.0], vec![0.0], 2.0),
+            Particle::new(vec![3.0], vec![0.0], 3.0),
+            Particle::new(vec![4.0], vec![0.0], 4.0),
+            Particle::new(vec![5.0], vec![0.0], 5.0),
+            Particle::new(vec![10.0], vec![0.0], -1.0),
+        ];
+        swarm.replace_particles(particles);
+        swarm
+    }
+
+    #[test]
+    fn neighborhood_best() {
+        let swarm = create_swarm();
+
+        assert_eq!(swarm.get_neighborhood_best(2, 1), &vec![3.0]);
+        assert_eq!(swarm.get_neighborhood_best(2, 3), &vec![2.0]);
+        assert_eq!(swarm.get_neighborhood_best(0, 3), &vec![10.0]);
+        assert_eq!(swarm.get_neighborhood_best(3, 2), &vec![4.0]);
+        assert_eq!(swarm.get_neighborhood_best(4, 2), &vec![10.0]);
+    }
+
+    #[test]
+    fn neighborhood_best_whole_swarm() {
+        let swarm = create_swarm();
+        let global_best = &swarm.best_particle.as_ref().unwrap().coordinates;
+
+        for index in 0..swarm.len() {
+            assert_eq!(swarm.get_neighborhood_best(index, swarm.len()), global_best);
+            assert_eq!(swarm.get_neighborhood_best(index, 100), global_best);
+        }
+    }
+
+    #[test]
+    fn constriction_ring_local_best() {
+        let swarm = create_swarm();
+        let mut calculator = ConstrictionRingCalculator::new(2.05, 2.05, 3);
+
+        // The local best (2.0) is on the left, the global best (10.0) is on the right.
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[2]);
+            assert!(velocity[0] <= 0.0);
+        }
+    }
+
+    #[test]
+    fn constriction_ring_whole_swarm() {
+        let swarm = create_swarm();
+        let mut calculator = ConstrictionRingCalculator::new(2.05, 2.05, swarm.len());
+
+        // The particle moves to the global best as for the global best calculator.
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[2]);
+            assert!(velocity[0] >= 0.0);
+            assert!(velocity[0] <= calculator.xi * 2.05 * 7.0);
+        }
+    }
+
+    #[test]
+    fn constriction_ring_by_index() {
+        let swarm = create_swarm();
+        let mut calculator = ConstrictionRingCalculator::new(2.05, 2.05, 3);
+
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity_by_index(&swarm, 2);
+            assert!(velocity[0] <= 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn constriction_ring_outside_particle() {
+        let swarm = create_swarm();
+        let mut calculator = ConstrictionRingCalculator::new(2.05, 2.05, 3);
+        let particle = swarm.particles[2].clone();
+        calculator.calc_new_velocity(&swarm, &particle);
+    }
+
+    #[test]
+    fn constriction_ring_as_canonical() {
+        // The ring with the whole swarm is the canonical equation with the same xi.
+        let swarm = create_swarm();
+        let mut ring = ConstrictionRingCalculator::new(2.05, 2.05, swarm.len());
+        let phi: f64 = 4.1;
+        let alpha = (phi - 2.0) / (phi - 2.0 + (phi * phi - 4.0 * phi).sqrt());
+        let mut canonical = CanonicalVelocityCalculator::new(2.05, 2.05, alpha);
+        assert!((ring.xi - canonical.xi).abs() < 1e-12);
+
+        let count = 10000;
+        let max_velocity = ring.xi * 2.05 * 7.0;
+        let mut ring_sum = 0.0;
+        let mut canonical_sum = 0.0;
+        for _ in 0..count {
+            let ring_velocity = ring.calc_new_velocity_by_index(&swarm, 2)[0];
+            let canonical_velocity = canonical.calc_new_velocity(&swarm, &swarm.particles[2])[0];
+            assert!((0.0..=max_velocity).contains(&ring_velocity));
+            assert!((0.0..=max_velocity).contains(&canonical_velocity));
+            ring_sum += ring_velocity;
+            canonical_sum += canonical_velocity;
+        }
+
+        // The average velocity is a half of the max velocity for the both calculators.
+        let expected = max_velocity / 2.0;
+        assert!((ring_sum / count as f64 - expected).abs() < expected * 0.05);
+        assert!((canonical_sum / count as f64 - expected).abs() < expected * 0.05);
+    }
+
+    #[test]
+    fn gcpso_best_particle() {
+        let swarm = create_swarm();
+        let mut calculator = GcpsoVelocityCalculator::new(2.0, 2.0, 0.5, 1.0, 5, 5);
+
+        // The global best particle is moved at random inside the rho radius.
+        // Its velocity would be zero for the classic rule.
+        let velocities: Vec<f64> = (0..100)
+            .map(|_| calculator.calc_new_velocity(&swarm, &swarm.particles[5])[0])
+            .collect();
+        assert!(velocities.iter().all(|v| *v >= -1.0 && *v <= 1.0));
+        assert!(velocities.iter().any(|v| *v < 0.0));
+        assert!(velocities.iter().any(|v| *v > 0.0));
+    }
+
+    #[test]
+    fn gcpso_other_particles() {
+        let swarm = create_swarm();
+        let mut calculator = GcpsoVelocityCalculator::new(2.0, 2.0, 0.5, 1.0, 5, 5);
+
+        // The other particles move to the global best by the classic rule.
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[2]);
+            assert!(velocity[0] >= 0.0);
+            assert!(velocity[0] <= 2.0 * 7.0);
+        }
+
+        // The first call for the iteration remembers the best value only.
+        assert_eq!(calculator.get_rho(), 1.0);
+    }
+
+    #[test]
+    fn gcpso_rho_failures() {
+        let mut swarm = create_swarm();
+        let mut calculator = GcpsoVelocityCalculator::new(2.0, 2.0, 0.5, 1.0, 2, 3);
+
+        // The first iteration remembers the best value.
+        calculator.calc_new_velocity(&swarm, &swarm.particles[5]);
+        for _ in 0..3 {
+            swarm.next_iteration();
+            calculator.calc_new_velocity(&swarm, &swarm.particles[5]);
+            assert_eq!(calculator.get_rho(), 1.0);
+        }
+
+        swarm.next_iteration();
+        calculator.calc_new_velocity(&swarm, &swarm.particles[5]);
+        assert_eq!(calculator.get_rho(), 0.5);
+
+        swarm.next_iteration();
+        calculator.calc_new_velocity(&swarm, &swarm.particles[5]);
+        assert_eq!(calculator.get_rho(), 0.25);
+    }
+
+    #[test]
+    fn gcpso_rho_once_per_iteration() {
+        let mut swarm = create_swarm();
+        let mut calculator = GcpsoVelocityCalculator::new(2.0, 2.0, 0.5, 1.0, 2, 1);
+
+        // Many calls for the same iteration are counted as single failure.
+        for _ in 0..10 {
+            for index in 0..swarm.len() {
+                calculator.calc_new_velocity(&swarm, &swarm.particles[index]);
+            }
+        }
+        assert_eq!(calculator.get_rho(), 1.0);
+
+        // rho is updated for the other particles too.
+        for _ in 0..2 {
+            swarm.next_iteration();
+            calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+        }
+        assert_eq!(calculator.get_rho(), 0.5);
+    }
+
+    #[test]
+    fn gcpso_rho_successes() {
+        let mut calculator = GcpsoVelocityCalculator::new(2.0, 2.0, 0.5, 1.0, 2, 3);
+
+        for n in 0..5 {
+            let value = -(n as f64);
+            let mut swarm = Swarm::new();
+            swarm.replace_particles(vec![
+                Particle::new(vec![1.0], vec![0.0], 1.0),
+                Particle::new(vec![value], vec![0.0], value),
+            ]);
+            swarm.iteration = n;
+            calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+        }
+
+        // The first iteration remembers the best value, the fourth and fifth iterations double
+        // rho.
+        assert_eq!(calculator.get_rho(), 4.0);
+    }
+
+    #[test]
+    fn canonical_set_params() {
+        let mut calculator = CanonicalVelocityCalculator::new(2.05, 2.05, 0.9);
+        let mut params = HashMap::new();
+        params.insert("phi_personal".to_string(), 3.0);
+        params.insert("phi_global".to_string(), 2.0);
+        params.insert("alpha".to_string(), 0.5);
+        calculator.set_params(&params);
+
+        assert_eq!(calculator.get_params(), params);
+        assert_eq!(calculator.xi, 1.0 / 3.0);
+    }
+
+    #[test]
+    fn canonical_set_params_clamp() {
+        let mut calculator = CanonicalVelocityCalculator::new(2.05, 2.05, 0.9);
+        let mut params = HashMap::new();
+        params.insert("phi_personal".to_string(), 1.0);
+        params.insert("phi_global".to_string(), 1.0);
+        params.insert("alpha".to_string(), 1.5);
+        calculator.set_params(&params);
+
+        assert_eq!(calculator.phi_personal, 1.0);
+        assert!(calculator.phi_personal + calculator.phi_global > 4.0);
+        assert!(calculator.alpha < 1.0);
+        assert!(calculator.xi.is_finite() && calculator.xi > 0.0);
+
+        params.insert("alpha".to_string(), -1.0);
+        calculator.set_params(&params);
+        assert!(calculator.alpha > 0.0);
+        assert!(calculator.xi.is_finite() && calculator.xi > 0.0);
+    }
+
+    #[test]
+    fn canonical_set_params_nan() {
+        let mut calculator = CanonicalVelocityCalculator::new(2.05, 2.05, 0.9);
+        let mut params = HashMap::new();
+        params.insert("phi_personal".to_string(), f64::NAN);
+        params.insert("alpha".to_string(), f64::INFINITY);
+        calculator.set_params(&params);
+
+        assert_eq!(calculator.phi_personal, 2.05);
+        assert_eq!(calculator.alpha, 0.9);
+    }
 }
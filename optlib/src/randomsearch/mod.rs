@@ -0,0 +1,255 @@
+//! The module with the random search algorithm. Every iteration the algorithm calculates the
+//! goal function for a random point from the intervals and keeps the best point. The algorithm
+//! is a baseline for comparison with other optimizers.
+
+use num::NumCast;
+
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::tools::{Intervals, RandomVectorCreator};
+use crate::{AlgorithmState, Goal, IterativeOptimizer, Objective, Optimizer, Solution};
+
+/// The state of `RandomSearchOptimizer`: the best point so far and the iteration number.
+pub struct RandomSearchState<T> {
+    best_solution: Option<Solution<Vec<T>>>,
+    iteration: usize,
+}
+
+impl<T: Clone> AlgorithmState<Vec<T>> for RandomSearchState<T> {
+    fn get_best_solution(&self) -> Option<Solution<Vec<T>>> {
+        self.best_solution.clone()
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+}
+
+/// The optimizer calculates the goal function for one random point from the intervals every
+/// iteration and returns the best point.
+///
+/// `T` - type of the coordinates of a point in the search space.
+pub struct RandomSearchOptimizer<'a, T> {
+    goal: Box<dyn Goal<Vec<T>> + 'a>,
+    stop_checker: Box<dyn StopChecker<Vec<T>> + 'a>,
+    intervals: Intervals<T>,
+    vector_creator: RandomVectorCreator,
+    loggers: Vec<Box<dyn Logger<Vec<T>> + 'a>>,
+    state: RandomSearchState<T>,
+}
+
+impl<'a, T: NumCast + PartialOrd + Clone> RandomSearchOptimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop checker.
+    /// * `intervals` - vector of tuples (min, max) for every dimension of the search space.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(
+        goal: Box<dyn Goal<Vec<T>> + 'a>,
+        stop_checker: Box<dyn StopChecker<Vec<T>> + 'a>,
+        intervals: Vec<(T, T)>,
+    ) -> Self {
+        Self {
+            goal,
+            stop_checker,
+            intervals: Intervals::new(intervals).unwrap(),
+            vector_creator: RandomVectorCreator::new(),
+            loggers: vec![],
+            state: RandomSearchState {
+                best_solution: None,
+                iteration: 0,
+            },
+        }
+    }
+
+    /// Set the seed of the random generator to get the same points for every running.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.vector_creator = RandomVectorCreator::new_with_seed(seed);
+        self
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Vec<T>> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<Vec<T>> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    /// Returns the current state of the algorithm.
+    pub fn get_state(&self) -> &RandomSearchState<T> {
+        &self.state
+    }
+
+    /// Calculate the goal function for a random point and call the loggers.
+    fn do_iteration(&mut self) {
+        self.goal.set_generation(self.state.iteration);
+
+        let point = self.vector_creator.create_vec(self.intervals.as_vec());
+        let value = self.goal.get(&point);
+
+        let is_better = match &self.state.best_solution {
+            None => !value.is_nan(),
+            Some((_, best_value)) => Objective::Minimize.is_better(value, *best_value),
+        };
+
+        if is_better {
+            self.state.best_solution = Some((point, value));
+        }
+
+        self.state.iteration += 1;
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.state);
+        }
+    }
+
+    /// Returns true if the iterations limit `max_iterations` is reached.
+    fn is_budget_spent(&self, max_iterations: Option<usize>) -> bool {
+        match max_iterations {
+            None => false,
+            Some(max_iterations) => self.state.iteration >= max_iterations,
+        }
+    }
+
+    /// Call the loggers in the end of the running and return the best solution.
+    fn finish_iterations(&mut self) -> Option<Solution<Vec<T>>> {
+        for logger in &mut self.loggers {
+            logger.finish(&self.state);
+        }
+
+        self.state.best_solution.clone()
+    }
+
+    /// Start new running with the iterations limit if `max_iterations` is not None.
+    fn run(&mut self, max_iterations: Option<usize>) -> Option<Solution<Vec<T>>> {
+        self.stop_checker.reset();
+        self.state.best_solution = None;
+        self.state.iteration = 0;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.state);
+        }
+
+        self.run_iterations(max_iterations)
+    }
+
+    /// Do new iterations until the stop checker fires or the iterations limit `max_iterations`
+    /// is reached.
+    fn run_iterations(&mut self, max_iterations: Option<usize>) -> Option<Solution<Vec<T>>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.state);
+        }
+
+        while !self.stop_checker.can_stop(&self.state) && !self.is_budget_spent(max_iterations) {
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+
+    /// Do `n` iterations of the algorithm regardless of the stop checker.
+    pub fn next_n_iterations(&mut self, n: usize) -> Option<Solution<Vec<T>>> {
+        for logger in &mut self.loggers {
+            logger.resume(&self.state);
+        }
+
+        for _ in 0..n {
+            self.do_iteration();
+        }
+
+        self.finish_iterations()
+    }
+}
+
+impl<'a, T: NumCast + PartialOrd + Clone> Optimizer<Vec<T>> for RandomSearchOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<Solution<Vec<T>>> {
+        self.run(None)
+    }
+
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<Vec<T>>> {
+        self.run(Some(max_iterations))
+    }
+}
+
+impl<'a, T: NumCast + PartialOrd + Clone> IterativeOptimizer<Vec<T>>
+    for RandomSearchOptimizer<'a, T>
+{
+    fn next_iterations(&mut self) -> Option<Solution<Vec<T>>> {
+        self.run_iterations(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::stopchecker::MaxIterations;
+    use crate::GoalFromFunction;
+
+    /// The paraboloid with minimum at (0.3, -0.2).
+    fn paraboloid(x: &[f64]) -> f64 {
+        (x[0] - 0.3) * (x[0] - 0.3) + (x[1] + 0.2) * (x[1] + 0.2)
+    }
+
+    fn create_optimizer<'a>(max_iterations: usize) -> RandomSearchOptimizer<'a, f64> {
+        RandomSearchOptimizer::new(
+            Box::new(GoalFromFunction::new(|x: &Vec<f64>| paraboloid(x))),
+            Box::new(MaxIterations::new(max_iterations)),
+            vec![(-1.0, 1.0), (-1.0, 1.0)],
+        )
+    }
+
+    #[test]
+    fn paraboloid_2d() {
+        let mut optimizer = create_optimizer(5000);
+
+        let (solution, goal) = optimizer.find_min().unwrap();
+        assert!(goal < 0.01);
+        assert_eq!(goal, paraboloid(&solution));
+        assert!(solution.iter().all(|x| (-1.0..=1.0).contains(x)));
+
+        // The state contains the best point
+        assert_eq!(
+            optimizer.get_state().get_best_solution(),
+            Some((solution, goal))
+        );
+        assert_eq!(optimizer.get_state().get_iteration(), 5000);
+    }
+
+    #[test]
+    fn next_n_iterations() {
+        let mut optimizer = create_optimizer(10);
+        let (_, goal) = optimizer.find_min().unwrap();
+
+        // The best point is not lost
+        let (_, new_goal) = optimizer.next_n_iterations(20).unwrap();
+        assert!(new_goal <= goal);
+        assert_eq!(optimizer.get_state().get_iteration(), 30);
+    }
+
+    #[test]
+    fn seed() {
+        let mut optimizer_1 = create_optimizer(100).seed(3);
+        let mut optimizer_2 = create_optimizer(100).seed(3);
+
+        assert_eq!(optimizer_1.find_min(), optimizer_2.find_min());
+    }
+
+    #[test]
+    fn find_min_with_budget() {
+        let mut optimizer = create_optimizer(100);
+        optimizer.find_min_with_budget(7);
+        assert_eq!(optimizer.get_state().get_iteration(), 7);
+
+        // The limit is applied to the single call only
+        optimizer.next_iterations();
+        assert_eq!(optimizer.get_state().get_iteration(), 100);
+
+        optimizer.find_min();
+        assert_eq!(optimizer.get_state().get_iteration(), 100);
+    }
+}
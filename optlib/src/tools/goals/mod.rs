@@ -0,0 +1,1036 @@
+//! The module with the wrappers for goal functions. The wrappers implements the `Goal` trait
+//! and change behavior of the inner goal function.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use num::Float;
+
+use crate::tools::Intervals;
+use crate::{Goal, GoalValue};
+
+/// The goal function wrapper to reduce noise of the noisy goal functions.
+/// The wrapper calls inner goal function `n` times for every point
+/// and returns average value.
+pub struct ResamplingGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    n: usize,
+}
+
+impl<'a, T> ResamplingGoal<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `n` - count of the inner goal function calls for every point. `n` must be greater than 0.
+    pub fn new(goal: &'a mut dyn Goal<T>, n: usize) -> Self {
+        assert!(n > 0, "n must be greater than 0");
+        Self { goal, n }
+    }
+}
+
+impl<'a, T> Goal<T> for ResamplingGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let mut sum = 0.0;
+        for _ in 0..self.n {
+            sum += self.goal.get(x);
+        }
+
+        sum / (self.n as GoalValue)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to record all evaluated points and values of the goal function.
+/// The history may be limited by length. Points after the limit are not recorded.
+pub struct RecordingGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    history: Vec<(T, GoalValue)>,
+    max_length: Option<usize>,
+}
+
+impl<'a, T> RecordingGoal<'a, T> {
+    /// Constructor. The history length is not limited.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    pub fn new(goal: &'a mut dyn Goal<T>) -> Self {
+        Self {
+            goal,
+            history: vec![],
+            max_length: None,
+        }
+    }
+
+    /// Set max length of the history.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Returns evaluated points and values of the goal function in order of evaluation.
+    pub fn get_history(&self) -> &Vec<(T, GoalValue)> {
+        &self.history
+    }
+
+    /// Remove all records from the history.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl<'a, T: Clone> Goal<T> for RecordingGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let value = self.goal.get(x);
+
+        let full = match self.max_length {
+            None => false,
+            Some(max_length) => self.history.len() >= max_length,
+        };
+
+        if !full {
+            self.history.push((x.clone(), value));
+        }
+
+        value
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to limit count of the inner goal function calls (budget).
+/// After the budget is spent the wrapper does not call the inner goal function, returns
+/// infinity and sets the exhausted flag (see `is_exhausted()`).
+pub struct BudgetedGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    budget: usize,
+    call_count: usize,
+    exhausted: bool,
+}
+
+impl<'a, T> BudgetedGoal<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `budget` - max count of the inner goal function calls.
+    pub fn new(goal: &'a mut dyn Goal<T>, budget: usize) -> Self {
+        Self {
+            goal,
+            budget,
+            call_count: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Returns true if the goal function was called after the budget had been spent.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Returns count of the inner goal function calls.
+    pub fn get_call_count(&self) -> usize {
+        self.call_count
+    }
+}
+
+impl<'a, T> Goal<T> for BudgetedGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        if self.call_count >= self.budget {
+            self.exhausted = true;
+            return GoalValue::INFINITY;
+        }
+
+        self.call_count += 1;
+        self.goal.get(x)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to move the point inside the intervals before the inner goal
+/// function calculation. The wrapper may be used for goal functions which are defined on the
+/// domain only (for example, for positive arguments), so the inner goal function never gets
+/// points outside of the domain. Unlike `PostMove` and `PreBirth`, the wrapper does not change
+/// the agents of the algorithm.
+///
+/// The optimizer stores the original (not clamped) point, so the reported solution may lie
+/// outside of the intervals. Value of the goal function for the point is value for the nearest
+/// point on the boundary of the intervals.
+pub struct ClampedInputGoal<'a, G> {
+    goal: &'a mut dyn Goal<Vec<G>>,
+    intervals: Intervals<G>,
+}
+
+impl<'a, G: Float> ClampedInputGoal<'a, G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `intervals` - vector of tuples (min, max) for every dimension.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(goal: &'a mut dyn Goal<Vec<G>>, intervals: Vec<(G, G)>) -> Self {
+        Self {
+            goal,
+            intervals: Intervals::new(intervals).unwrap(),
+        }
+    }
+}
+
+impl<'a, G: Float> Goal<Vec<G>> for ClampedInputGoal<'a, G> {
+    fn get(&mut self, x: &Vec<G>) -> GoalValue {
+        let mut clamped = x.clone();
+        self.intervals.clamp(&mut clamped);
+        self.goal.get(&clamped)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The function for `AdaptivePenaltyGoal` and `ConstraintDominanceGoal` to calculate measure of
+/// the constraints violation.
+pub type Violation<'a, T> = dyn Fn(&T) -> GoalValue + 'a;
+
+/// The goal function wrapper to solve problems with constraints by penalty which increases with
+/// iteration number (generation). The wrapper returns
+/// `goal(x) + initial_weight * growth^generation * violation(x)`, where `violation(x)` is a
+/// measure of the constraints violation. `violation(x)` must return 0 for feasible points and
+/// positive value otherwise. The penalty is loose in the first generations and tightens in the
+/// last generations, so the final agents become feasible. The wrapper is intended for
+/// minimization of the goal function.
+///
+/// `Goal::get()` does not know the iteration number, so the optimizers call
+/// `Goal::set_generation()` before every iteration. Fitness of agents which were calculated in
+/// previous generations is not updated.
+pub struct AdaptivePenaltyGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    violation: Box<Violation<'a, T>>,
+    initial_weight: GoalValue,
+    growth: GoalValue,
+    generation: usize,
+}
+
+impl<'a, T> AdaptivePenaltyGoal<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `violation` - the function returns measure of the constraints violation (0 for feasible
+    ///   points).
+    /// * `initial_weight` - weight of the penalty for generation 0. The value must not be negative.
+    /// * `growth` - the weight is multiplied by `growth` every generation. The value must not be
+    ///   less than 1.
+    pub fn new(
+        goal: &'a mut dyn Goal<T>,
+        violation: Box<Violation<'a, T>>,
+        initial_weight: GoalValue,
+        growth: GoalValue,
+    ) -> Self {
+        assert!(initial_weight >= 0.0, "initial_weight must not be negative");
+        assert!(growth >= 1.0, "growth must not be less than 1");
+        Self {
+            goal,
+            violation,
+            initial_weight,
+            growth,
+            generation: 0,
+        }
+    }
+
+    /// Returns weight of the penalty for the current generation.
+    pub fn get_weight(&self) -> GoalValue {
+        self.initial_weight * self.growth.powi(self.generation as i32)
+    }
+}
+
+impl<'a, T> Goal<T> for AdaptivePenaltyGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let violation = (self.violation)(x);
+        let value = self.goal.get(x);
+
+        // The weight may be infinite for large generation (0 * inf is NaN)
+        if violation == 0.0 {
+            value
+        } else {
+            value + self.get_weight() * violation
+        }
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to solve problems with constraints by the constraint dominance
+/// rules (Deb's rules) without penalty weights:
+/// * a feasible point is better than an infeasible point;
+/// * feasible points are compared by the goal function;
+/// * infeasible points are compared by measure of the constraints violation.
+///
+/// The optimizers compare scalar values, so the wrapper returns `goal(x)` for feasible points
+/// and `offset + violation(x)` for infeasible points, where `offset` is the worst (max) value of
+/// the goal function of the feasible points calculated before or the bound (see
+/// `ConstraintDominanceGoal::bound()`), whichever is greater. `violation(x)` must return 0 for
+/// feasible points and positive value otherwise. The inner goal function is not calculated for
+/// the infeasible points. The wrapper is intended for minimization of the goal function.
+///
+/// The offset may grow during optimization, so the value of an infeasible point calculated
+/// earlier may be less than the value of a feasible point calculated later. Set the bound to
+/// upper bound of the goal function for feasible points to get the stable ranking.
+/// The offset is 0 if no feasible points were calculated and the bound is not set.
+pub struct ConstraintDominanceGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    violation: Box<Violation<'a, T>>,
+    bound: Option<GoalValue>,
+    worst_feasible: Option<GoalValue>,
+}
+
+impl<'a, T> ConstraintDominanceGoal<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `violation` - the function returns measure of the constraints violation (0 for feasible
+    ///   points).
+    pub fn new(goal: &'a mut dyn Goal<T>, violation: Box<Violation<'a, T>>) -> Self {
+        Self {
+            goal,
+            violation,
+            bound: None,
+            worst_feasible: None,
+        }
+    }
+
+    /// Set upper bound of the goal function for the feasible points.
+    pub fn bound(mut self, bound: GoalValue) -> Self {
+        self.bound = Some(bound);
+        self
+    }
+
+    /// Returns the worst (max) value of the goal function of the feasible points or None if
+    /// no feasible points were calculated.
+    pub fn get_worst_feasible(&self) -> Option<GoalValue> {
+        self.worst_feasible
+    }
+
+    fn get_offset(&self) -> GoalValue {
+        match (self.bound, self.worst_feasible) {
+            (None, None) => 0.0,
+            (Some(bound), None) => bound,
+            (None, Some(worst)) => worst,
+            (Some(bound), Some(worst)) => bound.max(worst),
+        }
+    }
+}
+
+impl<'a, T> Goal<T> for ConstraintDominanceGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let violation = (self.violation)(x);
+        if violation > 0.0 {
+            return self.get_offset() + violation;
+        }
+
+        let value = self.goal.get(x);
+        if value.is_finite() {
+            self.worst_feasible = Some(match self.worst_feasible {
+                None => value,
+                Some(worst) => worst.max(value),
+            });
+        }
+
+        value
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to cache values of the goal function for the cells of the grid.
+/// Every coordinate is rounded to the nearest multiple of the grid resolution for the dimension
+/// to get the cell. The inner goal function is calculated for the first point in the cell only,
+/// other points in the cell get the cached value.
+///
+/// The wrapper is approximation: the goal function is constant inside the cell, so the
+/// landscape is biased and the accuracy of the solution is limited by the resolution. Larger
+/// resolution gives more cache hits (less calls of the inner goal function) and worse accuracy.
+/// The wrapper may be useful for expensive goal functions. Points with non-finite coordinates
+/// are not cached. The cache is not cleared on a new generation, so the wrapper should not be
+/// used with goal functions which depend on the generation.
+pub struct GridCachingGoal<'a, G> {
+    goal: &'a mut dyn Goal<Vec<G>>,
+    resolution: Vec<G>,
+    cache: HashMap<Vec<i64>, GoalValue>,
+}
+
+impl<'a, G: Float> GridCachingGoal<'a, G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `resolution` - size of the grid cell for every dimension. All values must be greater
+    ///   than 0.
+    pub fn new(goal: &'a mut dyn Goal<Vec<G>>, resolution: Vec<G>) -> Self {
+        assert!(resolution.iter().all(|step| *step > G::zero()));
+        Self {
+            goal,
+            resolution,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns count of the cached cells.
+    pub fn get_cache_size(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Remove all cached values.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Returns indices of the grid cell for the point or None if the point can't be cached.
+    fn get_key(&self, x: &[G]) -> Option<Vec<i64>> {
+        assert_eq!(x.len(), self.resolution.len());
+
+        x.iter()
+            .zip(self.resolution.iter())
+            .map(|(xi, step)| (*xi / *step).round().to_i64())
+            .collect()
+    }
+}
+
+impl<'a, G: Float> Goal<Vec<G>> for GridCachingGoal<'a, G> {
+    fn get(&mut self, x: &Vec<G>) -> GoalValue {
+        match self.get_key(x) {
+            None => self.goal.get(x),
+            Some(key) => match self.cache.get(&key) {
+                Some(value) => *value,
+                None => {
+                    let value = self.goal.get(x);
+                    self.cache.insert(key, value);
+                    value
+                }
+            },
+        }
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The goal function wrapper to map the point from the unit box [0, 1]^n to the real intervals
+/// before the inner goal function calculation. The optimizer may be configured for the unit box
+/// for every problem, so all dimensions have the same scale. The solution of the optimizer must
+/// be converted to the real intervals by `NormalizingGoal::denormalize()`.
+///
+/// x_real_i = min_i + x_i * (max_i - min_i)
+pub struct NormalizingGoal<'a, G> {
+    goal: &'a mut dyn Goal<Vec<G>>,
+    intervals: Intervals<G>,
+}
+
+impl<'a, G: Float> NormalizingGoal<'a, G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    /// * `intervals` - vector of tuples (min, max) with the real intervals for every dimension.
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(goal: &'a mut dyn Goal<Vec<G>>, intervals: Vec<(G, G)>) -> Self {
+        Self {
+            goal,
+            intervals: Intervals::new(intervals).unwrap(),
+        }
+    }
+
+    /// Convert the point from the unit box to the real intervals.
+    pub fn denormalize(&self, x: &[G]) -> Vec<G> {
+        assert_eq!(x.len(), self.intervals.len());
+
+        x.iter()
+            .zip(self.intervals.iter())
+            .map(|(xi, (min, max))| *min + *xi * (*max - *min))
+            .collect()
+    }
+
+    /// Convert the point from the real intervals to the unit box. Degenerate intervals
+    /// (min == max) are mapped to 0.
+    pub fn normalize(&self, x: &[G]) -> Vec<G> {
+        assert_eq!(x.len(), self.intervals.len());
+
+        x.iter()
+            .zip(self.intervals.iter())
+            .map(|(xi, (min, max))| {
+                if max > min {
+                    (*xi - *min) / (*max - *min)
+                } else {
+                    G::zero()
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, G: Float> Goal<Vec<G>> for NormalizingGoal<'a, G> {
+    fn get(&mut self, x: &Vec<G>) -> GoalValue {
+        let real = self.denormalize(x);
+        self.goal.get(&real)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// Count of the buckets in the histogram of `TimingReport`.
+pub const TIMING_BUCKETS_COUNT: usize = 8;
+
+/// The statistics of the goal function calculation time collected by `TimingGoal`.
+#[derive(Debug, Clone)]
+pub struct TimingReport {
+    /// Count of the goal function calls.
+    pub count: usize,
+
+    /// Total time of the goal function calls.
+    pub total: Duration,
+
+    /// Minimal time of single call. None if the goal function was not called.
+    pub min: Option<Duration>,
+
+    /// Maximal time of single call. None if the goal function was not called.
+    pub max: Option<Duration>,
+
+    /// Count of the calls by decade buckets: the bucket 0 contains calls shorter than 1 µs,
+    /// the bucket `i` contains calls from 10^(i-1) µs to 10^i µs, the last bucket contains
+    /// all calls longer than 10^(TIMING_BUCKETS_COUNT - 2) µs.
+    pub histogram: [usize; TIMING_BUCKETS_COUNT],
+}
+
+impl TimingReport {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            total: Duration::from_secs(0),
+            min: None,
+            max: None,
+            histogram: [0; TIMING_BUCKETS_COUNT],
+        }
+    }
+
+    fn add(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+
+        let mut bucket = 0;
+        let mut bound: u128 = 1;
+        while bucket < TIMING_BUCKETS_COUNT - 1 && duration.as_micros() >= bound {
+            bucket += 1;
+            bound *= 10;
+        }
+        self.histogram[bucket] += 1;
+    }
+
+    /// Returns average time of single call. None if the goal function was not called.
+    pub fn get_average(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.total / self.count as u32)
+        }
+    }
+}
+
+/// The goal function wrapper to measure time of every inner goal function call.
+/// The statistics are available by `TimingGoal::get_timing_report()`. `TimeLogger` measures
+/// time of the whole running only.
+pub struct TimingGoal<'a, T> {
+    goal: &'a mut dyn Goal<T>,
+    report: TimingReport,
+}
+
+impl<'a, T> TimingGoal<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - inner goal function.
+    pub fn new(goal: &'a mut dyn Goal<T>) -> Self {
+        Self {
+            goal,
+            report: TimingReport::new(),
+        }
+    }
+
+    /// Returns the statistics of the inner goal function calls.
+    pub fn get_timing_report(&self) -> &TimingReport {
+        &self.report
+    }
+
+    /// Reset the statistics.
+    pub fn clear(&mut self) {
+        self.report = TimingReport::new();
+    }
+}
+
+impl<'a, T> Goal<T> for TimingGoal<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let start = Instant::now();
+        let value = self.goal.get(x);
+        self.report.add(start.elapsed());
+        value
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::particleswarm::initializing::{
+        RandomCoordinatesInitializer, ZeroVelocityInitializer,
+    };
+    use crate::particleswarm::velocitycalc::ClassicVelocityCalculator;
+    use crate::particleswarm::ParticleSwarmOptimizer;
+    use crate::tools::statistics::{CallCountData, GoalCalcStatistics};
+    use crate::tools::stopchecker::MaxIterations;
+    use crate::{GoalFromFunction, Optimizer};
+
+    struct SquareGoal;
+
+    impl Goal<Vec<f64>> for SquareGoal {
+        fn get(&mut self, x: &Vec<f64>) -> GoalValue {
+            x.iter().map(|xi| xi * xi).sum()
+        }
+    }
+
+    /// The goal returns 0, 1, 2, ... for sequential calls.
+    struct CounterGoal {
+        count: usize,
+    }
+
+    impl Goal<f64> for CounterGoal {
+        fn get(&mut self, _x: &f64) -> GoalValue {
+            let result = self.count as GoalValue;
+            self.count += 1;
+            result
+        }
+    }
+
+    #[test]
+    fn resampling_average() {
+        let mut inner = CounterGoal { count: 0 };
+        let mut goal = ResamplingGoal::new(&mut inner, 4);
+
+        assert_eq!(goal.get(&0.0), 1.5);
+        assert_eq!(goal.get(&0.0), 5.5);
+    }
+
+    #[test]
+    fn resampling_call_count() {
+        let mut inner = CounterGoal { count: 0 };
+        let mut call_count = CallCountData::new();
+        call_count.next_run();
+
+        {
+            let mut statistics = GoalCalcStatistics::new(&mut inner, &mut call_count);
+            let mut goal = ResamplingGoal::new(&mut statistics, 3);
+            for _ in 0..5 {
+                goal.get(&0.0);
+            }
+        }
+
+        assert_eq!(call_count.get_call_count(), vec![15]);
+    }
+
+    #[test]
+    fn resampling_single() {
+        let mut inner = CounterGoal { count: 10 };
+        let mut goal = ResamplingGoal::new(&mut inner, 1);
+
+        assert_eq!(goal.get(&0.0), 10.0);
+        assert_eq!(goal.get(&0.0), 11.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn resampling_zero() {
+        let mut inner = CounterGoal { count: 0 };
+        ResamplingGoal::new(&mut inner, 0);
+    }
+
+    #[test]
+    fn recording_optimization() {
+        let particles_count = 5;
+        let iterations = 3;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut inner = SquareGoal;
+        let mut goal = RecordingGoal::new(&mut inner);
+
+        {
+            let mut optimizer = ParticleSwarmOptimizer::new(
+                Box::new(&mut goal),
+                Box::new(MaxIterations::new(iterations)),
+                Box::new(RandomCoordinatesInitializer::new(intervals.clone(), particles_count)),
+                Box::new(ZeroVelocityInitializer::new(intervals.len(), particles_count)),
+                Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+            );
+            optimizer.find_min();
+        }
+
+        let history = goal.get_history();
+        assert_eq!(history.len(), particles_count * (iterations + 1));
+        for (x, value) in history {
+            assert_eq!(*value, SquareGoal.get(x));
+        }
+    }
+
+    #[test]
+    fn recording_max_length() {
+        let mut inner = CounterGoal { count: 0 };
+        let mut goal = RecordingGoal::new(&mut inner).max_length(3);
+
+        for n in 0..5 {
+            assert_eq!(goal.get(&(n as f64)), n as GoalValue);
+        }
+
+        assert_eq!(
+            goal.get_history(),
+            &vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]
+        );
+
+        goal.clear();
+        assert!(goal.get_history().is_empty());
+    }
+
+    #[test]
+    fn budgeted() {
+        let mut inner = CounterGoal { count: 0 };
+        let mut goal = BudgetedGoal::new(&mut inner, 3);
+
+        for n in 0..3 {
+            assert_eq!(goal.get(&0.0), n as GoalValue);
+            assert!(!goal.is_exhausted());
+        }
+
+        assert_eq!(goal.get(&0.0), GoalValue::INFINITY);
+        assert!(goal.is_exhausted());
+        assert_eq!(goal.get(&0.0), GoalValue::INFINITY);
+        assert_eq!(goal.get_call_count(), 3);
+    }
+
+    #[test]
+    fn budgeted_zero() {
+        let mut inner = CounterGoal { count: 0 };
+        let mut goal = BudgetedGoal::new(&mut inner, 0);
+
+        assert_eq!(goal.get(&0.0), GoalValue::INFINITY);
+        assert!(goal.is_exhausted());
+        assert_eq!(goal.get_call_count(), 0);
+    }
+
+    /// The goal function saves all generation numbers.
+    struct GenerationGoal {
+        generations: Vec<usize>,
+    }
+
+    impl Goal<Vec<f64>> for GenerationGoal {
+        fn get(&mut self, x: &Vec<f64>) -> GoalValue {
+            SquareGoal.get(x)
+        }
+
+        fn set_generation(&mut self, generation: usize) {
+            self.generations.push(generation);
+        }
+    }
+
+    /// The constraint is x[0] >= 1.
+    fn violation_x_positive<'a>() -> Box<Violation<'a, Vec<f64>>> {
+        Box::new(|x: &Vec<f64>| if x[0] >= 1.0 { 0.0 } else { 1.0 - x[0] })
+    }
+
+    #[test]
+    fn adaptive_penalty_grows() {
+        let mut inner = SquareGoal;
+        let mut goal = AdaptivePenaltyGoal::new(&mut inner, violation_x_positive(), 0.5, 2.0);
+
+        let infeasible = vec![0.0, 0.0];
+        let feasible = vec![2.0, 0.0];
+
+        let mut penalties = vec![];
+        for generation in 0..5 {
+            goal.set_generation(generation);
+            penalties.push(goal.get(&infeasible) - SquareGoal.get(&infeasible));
+            assert_eq!(goal.get(&feasible), 4.0);
+        }
+
+        assert_eq!(penalties, vec![0.5, 1.0, 2.0, 4.0, 8.0]);
+    }
+
+    #[test]
+    fn adaptive_penalty_infinite_weight() {
+        let mut inner = SquareGoal;
+        let mut goal = AdaptivePenaltyGoal::new(&mut inner, violation_x_positive(), 1.0, 10.0);
+        goal.set_generation(1000);
+
+        assert_eq!(goal.get(&vec![2.0, 0.0]), 4.0);
+        assert_eq!(goal.get(&vec![0.0, 0.0]), GoalValue::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adaptive_penalty_growth_less_one() {
+        let mut inner = SquareGoal;
+        AdaptivePenaltyGoal::new(&mut inner, violation_x_positive(), 1.0, 0.5);
+    }
+
+    #[test]
+    fn constraint_dominance_ranking() {
+        let mut inner = SquareGoal;
+        let mut recording = RecordingGoal::new(&mut inner);
+
+        {
+            let mut goal = ConstraintDominanceGoal::new(&mut recording, violation_x_positive());
+
+            let feasible: Vec<GoalValue> = [1.0, 3.0, 5.0, 2.0]
+                .iter()
+                .map(|x| goal.get(&vec![*x, 0.0]))
+                .collect();
+            assert_eq!(feasible, vec![1.0, 9.0, 25.0, 4.0]);
+            assert_eq!(goal.get_worst_feasible(), Some(25.0));
+
+            let infeasible: Vec<GoalValue> = [0.5, -3.0, 0.0]
+                .iter()
+                .map(|x| goal.get(&vec![*x, 0.0]))
+                .collect();
+            assert_eq!(infeasible, vec![25.5, 29.0, 26.0]);
+
+            // Any feasible point is better than any infeasible point
+            let max_feasible = feasible
+                .iter()
+                .cloned()
+                .fold(GoalValue::MIN, GoalValue::max);
+            assert!(infeasible.iter().all(|value| *value > max_feasible));
+        }
+
+        // The inner goal function is not calculated for the infeasible points
+        assert_eq!(recording.get_history().len(), 4);
+    }
+
+    #[test]
+    fn constraint_dominance_bound() {
+        let mut inner = SquareGoal;
+        let mut goal = ConstraintDominanceGoal::new(&mut inner, violation_x_positive());
+        assert_eq!(goal.get(&vec![0.5, 0.0]), 0.5);
+
+        let mut inner = SquareGoal;
+        let mut goal =
+            ConstraintDominanceGoal::new(&mut inner, violation_x_positive()).bound(100.0);
+        assert_eq!(goal.get(&vec![0.5, 0.0]), 100.5);
+        assert_eq!(goal.get(&vec![20.0, 0.0]), 400.0);
+        assert_eq!(goal.get(&vec![0.5, 0.0]), 400.5);
+    }
+
+    #[test]
+    fn set_generation_optimization() {
+        let particles_count = 5;
+        let intervals = vec![(-1.0, 1.0), (-1.0, 1.0)];
+
+        let mut inner = GenerationGoal {
+            generations: vec![],
+        };
+
+        {
+            let mut call_count = CallCountData::new();
+            let mut statistics = GoalCalcStatistics::new(&mut inner, &mut call_count);
+            let mut goal =
+                AdaptivePenaltyGoal::new(&mut statistics, violation_x_positive(), 1.0, 2.0);
+
+            let mut optimizer = ParticleSwarmOptimizer::new(
+                Box::new(&mut goal),
+                Box::new(MaxIterations::new(3)),
+                Box::new(RandomCoordinatesInitializer::new(
+                    intervals.clone(),
+                    particles_count,
+                )),
+                Box::new(ZeroVelocityInitializer::new(
+                    intervals.len(),
+                    particles_count,
+                )),
+                Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+            );
+            optimizer.find_min();
+        }
+
+        assert_eq!(inner.generations, vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn clamped_input() {
+        let mut inner = GoalFromFunction::new(|x: &Vec<f64>| {
+            assert!(x.iter().all(|xi| *xi > 0.0));
+            x.iter().map(|xi| xi.ln()).sum()
+        });
+        let mut recording = RecordingGoal::new(&mut inner);
+
+        {
+            let mut goal = ClampedInputGoal::new(&mut recording, vec![(1.0, 10.0), (1.0, 10.0)]);
+            assert_eq!(goal.get(&vec![-5.0, 20.0]), 10.0_f64.ln());
+            assert_eq!(goal.get(&vec![2.0, 3.0]), 6.0_f64.ln());
+        }
+
+        assert_eq!(
+            recording.get_history(),
+            &vec![
+                (vec![1.0, 10.0], 10.0_f64.ln()),
+                (vec![2.0, 3.0], 2.0_f64.ln() + 3.0_f64.ln())
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_caching() {
+        let mut inner = GoalFromFunction::new(|x: &Vec<f64>| x.iter().sum());
+        let mut call_count = CallCountData::new();
+        call_count.next_run();
+
+        {
+            let mut statistics = GoalCalcStatistics::new(&mut inner, &mut call_count);
+            let mut goal = GridCachingGoal::new(&mut statistics, vec![1.0, 0.5]);
+
+            // The same cell
+            assert_eq!(goal.get(&vec![2.25, 1.0]), 3.25);
+            assert_eq!(goal.get(&vec![1.75, 1.125]), 3.25);
+            assert_eq!(goal.get(&vec![2.375, 0.875]), 3.25);
+            assert_eq!(goal.get_cache_size(), 1);
+
+            // Other cells
+            assert_eq!(goal.get(&vec![2.75, 1.0]), 3.75);
+            assert_eq!(goal.get(&vec![2.25, 1.5]), 3.75);
+            assert_eq!(goal.get(&vec![-2.25, 1.0]), -1.25);
+            assert_eq!(goal.get_cache_size(), 4);
+
+            // Non-finite coordinates are not cached
+            assert!(goal.get(&vec![f64::NAN, 1.0]).is_nan());
+            assert!(goal.get(&vec![f64::NAN, 1.0]).is_nan());
+            assert_eq!(goal.get_cache_size(), 4);
+
+            goal.clear();
+            assert_eq!(goal.get(&vec![1.75, 1.125]), 2.875);
+        }
+
+        assert_eq!(call_count.get_call_count(), vec![7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_caching_zero_resolution() {
+        let mut inner = SquareGoal;
+        GridCachingGoal::new(&mut inner, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn normalizing() {
+        let mut inner = GoalFromFunction::new(|x: &Vec<f64>| x[0] + x[1]);
+        let mut recording = RecordingGoal::new(&mut inner);
+        let intervals = vec![(-10.0, 10.0), (1000.0, 2000.0)];
+
+        {
+            let mut goal = NormalizingGoal::new(&mut recording, intervals);
+            assert_eq!(goal.get(&vec![0.0, 0.0]), 990.0);
+            assert_eq!(goal.get(&vec![0.75, 0.5]), 1505.0);
+            assert_eq!(goal.get(&vec![1.0, 1.0]), 2010.0);
+
+            assert_eq!(goal.denormalize(&[0.25, 0.25]), vec![-5.0, 1250.0]);
+            assert_eq!(goal.normalize(&[-5.0, 1250.0]), vec![0.25, 0.25]);
+        }
+
+        assert_eq!(recording.get_history()[1], (vec![5.0, 1500.0], 1505.0));
+    }
+
+    #[test]
+    fn normalizing_optimization() {
+        let intervals = vec![(100.0, 200.0), (-0.01, 0.01)];
+        let mut inner = GoalFromFunction::new(|x: &Vec<f64>| {
+            (x[0] - 150.0).powi(2) / 100.0 + (x[1] - 0.005).powi(2) * 10000.0
+        });
+        let mut goal = NormalizingGoal::new(&mut inner, intervals.clone());
+
+        let (solution, _) = {
+            let mut optimizer = ParticleSwarmOptimizer::new(
+                Box::new(&mut goal),
+                Box::new(MaxIterations::new(100)),
+                Box::new(RandomCoordinatesInitializer::new(vec![(0.0, 1.0); 2], 20)),
+                Box::new(ZeroVelocityInitializer::new(2, 20)),
+                Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+            );
+            optimizer.find_min().unwrap()
+        };
+
+        let real_solution = goal.denormalize(&solution);
+        assert!((real_solution[0] - 150.0).abs() < 1.0);
+        assert!((real_solution[1] - 0.005).abs() < 0.001);
+    }
+
+    #[test]
+    fn timing_empty() {
+        let mut inner = GoalFromFunction::new(|x: &f64| *x);
+        let goal = TimingGoal::new(&mut inner);
+
+        let report = goal.get_timing_report();
+        assert_eq!(report.count, 0);
+        assert_eq!(report.get_average(), None);
+        assert_eq!(report.min, None);
+        assert_eq!(report.max, None);
+        assert_eq!(report.histogram, [0; TIMING_BUCKETS_COUNT]);
+    }
+
+    #[test]
+    fn timing_sleep() {
+        let delay = Duration::from_millis(20);
+        let mut inner = GoalFromFunction::new(|x: &f64| {
+            std::thread::sleep(Duration::from_millis(20));
+            *x
+        });
+        let mut goal = TimingGoal::new(&mut inner);
+
+        for i in 0..5 {
+            assert_eq!(goal.get(&(i as f64)), i as f64);
+        }
+
+        let report = goal.get_timing_report();
+        assert_eq!(report.count, 5);
+        assert!(report.min.unwrap() >= delay);
+        assert!(report.max.unwrap() >= report.min.unwrap());
+
+        let average = report.get_average().unwrap();
+        assert!(average >= delay);
+        assert!(average < delay * 3);
+
+        // 20 ms lies in the bucket from 10 ms to 100 ms
+        assert_eq!(report.histogram.iter().sum::<usize>(), 5);
+        assert_eq!(report.histogram[5] + report.histogram[6], 5);
+
+        goal.clear();
+        assert_eq!(goal.get_timing_report().count, 0);
+    }
+}
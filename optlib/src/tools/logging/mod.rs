@@ -1,10 +1,13 @@
 //! The module with the loggers ready for using. The loggers implements the `Logger` trait.
 
 use std::fmt::Display;
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
 use std::time;
 
-use crate::AlgorithmState;
+use crate::{AlgorithmState, Objective, Solution};
 
 /// The logging trait for algorithm with the agents.
 ///
@@ -57,6 +60,71 @@ impl<'a, T: Display> Logger<Vec<T>> for VerboseLogger<'a> {
     }
 }
 
+/// The logger prints out iteration number, value of goal function of the best solution and the
+/// improvement of the goal function since the previous iteration for every iteration.
+/// The improvement is positive if the goal function became better (less for minimization and
+/// greater for maximization). The stagnation of the algorithm is visible as zero improvement.
+///
+/// The logger remembers the goal function of the state in `start()` and `resume()`, so the first
+/// improvement after the calls is calculated relative to the state before the iterations.
+/// The improvement is 0 if the previous value of goal function is unknown.
+pub struct ImprovementLogger<'a> {
+    writer: &'a mut dyn io::Write,
+    precision: usize,
+    previous_goal: Option<f64>,
+}
+
+impl<'a> ImprovementLogger<'a> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `precision` - count of the digits after comma for float numbers.
+    pub fn new(writer: &'a mut dyn io::Write, precision: usize) -> Self {
+        Self {
+            writer,
+            precision,
+            previous_goal: None,
+        }
+    }
+
+    fn remember<T>(&mut self, state: &dyn AlgorithmState<T>) {
+        self.previous_goal = state.get_best_solution().map(|(_, goal)| goal);
+    }
+}
+
+impl<'a, T> Logger<T> for ImprovementLogger<'a> {
+    fn start(&mut self, state: &dyn AlgorithmState<T>) {
+        self.remember(state);
+    }
+
+    fn resume(&mut self, state: &dyn AlgorithmState<T>) {
+        self.remember(state);
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        if let Some((_, goal)) = state.get_best_solution() {
+            let delta = match (self.previous_goal, state.get_objective()) {
+                (None, _) => 0.0,
+                (Some(previous), Objective::Minimize) => previous - goal,
+                (Some(previous), Objective::Maximize) => goal - previous,
+            };
+
+            writeln!(
+                &mut self.writer,
+                "{:<8}  {:20.*}  {:20.*}",
+                state.get_iteration(),
+                self.precision,
+                goal,
+                self.precision,
+                delta
+            )
+            .unwrap();
+        }
+
+        self.remember(state);
+    }
+}
+
 /// The logger print out to stdout best result and value of goal function after end of genetic algorithm running.
 pub struct ResultOnlyLogger<'a> {
     writer: &'a mut dyn io::Write,
@@ -101,6 +169,115 @@ impl<'a, T: Display> Logger<Vec<T>> for ResultOnlyLogger<'a> {
     }
 }
 
+/// The logger prints out best result and value of goal function after end of algorithm running.
+/// Unlike `ResultOnlyLogger` the logger can be used for any type of point in search space
+/// which implements `Display` (scalars, custom types etc).
+pub struct GenericResultLogger<'a> {
+    writer: &'a mut dyn io::Write,
+    precision: usize,
+}
+
+impl<'a> GenericResultLogger<'a> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `precision` - count of the digits after comma for float numbers.
+    pub fn new(writer: &'a mut dyn io::Write, precision: usize) -> Self {
+        Self { writer, precision }
+    }
+}
+
+impl<'a, T: Display> Logger<T> for GenericResultLogger<'a> {
+    fn finish(&mut self, state: &dyn AlgorithmState<T>) {
+        match state.get_best_solution() {
+            None => writeln!(&mut self.writer, "Solution not found").unwrap(),
+            Some((solution, goal)) => {
+                writeln!(&mut self.writer, "Solution: {:.*}", self.precision, solution).unwrap();
+                writeln!(&mut self.writer, "Goal: {:.*}", self.precision, goal).unwrap();
+            }
+        }
+        writeln!(
+            &mut self.writer,
+            "Iterations count: {}",
+            state.get_iteration()
+        )
+        .unwrap();
+    }
+}
+
+/// The logger stores the best solution for all algorithm runnings after `start` (including
+/// resuming by `IterativeOptimizer::next_iterations()`) and prints out the best solution and value
+/// of goal function after every finish.
+pub struct BestEverLogger<'a, T> {
+    writer: &'a mut dyn io::Write,
+    precision: usize,
+    best: Option<Solution<T>>,
+}
+
+impl<'a, T: Clone> BestEverLogger<'a, T> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `precision` - count of the digits after comma for float numbers.
+    pub fn new(writer: &'a mut dyn io::Write, precision: usize) -> Self {
+        Self {
+            writer,
+            precision,
+            best: None,
+        }
+    }
+
+    /// Returns the best solution for all runnings after `start`.
+    pub fn get_best(&self) -> Option<Solution<T>> {
+        self.best.clone()
+    }
+
+    fn update(&mut self, state: &dyn AlgorithmState<T>) {
+        if let Some((solution, goal)) = state.get_best_solution() {
+            let is_better = match &self.best {
+                None => true,
+                Some((_, best_goal)) => state.get_objective().is_better(goal, *best_goal),
+            };
+
+            if is_better {
+                self.best = Some((solution, goal));
+            }
+        }
+    }
+}
+
+impl<'a, T: Display + Clone> Logger<Vec<T>> for BestEverLogger<'a, Vec<T>> {
+    fn start(&mut self, state: &dyn AlgorithmState<Vec<T>>) {
+        self.best = None;
+        self.update(state);
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<Vec<T>>) {
+        self.update(state);
+    }
+
+    fn finish(&mut self, state: &dyn AlgorithmState<Vec<T>>) {
+        self.update(state);
+        match &self.best {
+            None => writeln!(&mut self.writer, "Best ever solution not found").unwrap(),
+            Some((solution, goal)) => {
+                let mut result = String::from("Best ever solution:");
+                for x in solution {
+                    result = result + &format!("  {:.*}", self.precision, x);
+                }
+
+                writeln!(&mut self.writer, "{}", result).unwrap();
+                writeln!(
+                    &mut self.writer,
+                    "Best ever goal: {:.*}",
+                    self.precision, goal
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
 /// The logger prints out time duration after finish of algorithm.
 pub struct TimeLogger<'a> {
     writer: &'a mut dyn io::Write,
@@ -129,3 +306,688 @@ impl<'a, T: Display> Logger<Vec<T>> for TimeLogger<'a> {
         writeln!(&mut self.writer, "Time elapsed: {} ms", time_ms).unwrap();
     }
 }
+
+/// The message with the state of algorithm which `ChannelLogger` sends after every iteration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressUpdate<T> {
+    /// Iteration number.
+    pub iteration: usize,
+
+    /// Value of goal function for the best solution or None if the solution is not found.
+    pub best_goal: Option<f64>,
+
+    /// The best solution or None if the solution is not found.
+    pub best_solution: Option<T>,
+}
+
+/// The logger sends `ProgressUpdate` to the channel after every iteration. The logger may be
+/// used to show progress of algorithm in GUI in other thread.
+///
+/// If the receiver is disconnected, the messages are dropped.
+pub struct ChannelLogger<T: Clone + Send> {
+    sender: Sender<ProgressUpdate<T>>,
+}
+
+impl<T: Clone + Send> ChannelLogger<T> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `sender` - the sending half of the channel for `ProgressUpdate` messages.
+    pub fn new(sender: Sender<ProgressUpdate<T>>) -> Self {
+        Self { sender }
+    }
+}
+
+impl<T: Clone + Send> Logger<T> for ChannelLogger<T> {
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        let (best_solution, best_goal) = match state.get_best_solution() {
+            None => (None, None),
+            Some((solution, goal)) => (Some(solution), Some(goal)),
+        };
+
+        let update = ProgressUpdate {
+            iteration: state.get_iteration(),
+            best_goal,
+            best_solution,
+        };
+
+        // The receiver may be disconnected. It is not an error for the algorithm.
+        let _ = self.sender.send(update);
+    }
+}
+
+/// The logger forwards all calls to several loggers. The logger may be used where a single logger
+/// is expected. The loggers are called in the order of the vector.
+pub struct TeeLogger<'a, T> {
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+}
+
+impl<'a, T> TeeLogger<'a, T> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `loggers` - the loggers to forward the calls.
+    pub fn new(loggers: Vec<Box<dyn Logger<T> + 'a>>) -> Self {
+        Self { loggers }
+    }
+}
+
+impl<'a, T> Logger<T> for TeeLogger<'a, T> {
+    fn start(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.start(state);
+        }
+    }
+
+    fn resume(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.resume(state);
+        }
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.next_iteration(state);
+        }
+    }
+
+    fn finish(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.finish(state);
+        }
+    }
+}
+
+/// The logger watches the magnitude of goal function of the best solution and prints out a
+/// warning the first time the magnitude exceeds `threshold` or grows more than `growth_factor`
+/// times since the previous iteration. The logger may be used to detect the divergence of the
+/// algorithm because of unstable parameters (for example, too large `phi` in particle swarm).
+///
+/// The warning is printed once after `start()`. The infinite and NaN values of goal function are
+/// considered as the blow-up.
+pub struct BlowUpDetector<'a> {
+    writer: &'a mut dyn io::Write,
+    threshold: f64,
+    growth_factor: f64,
+    previous_magnitude: Option<f64>,
+    blowup_iteration: Option<usize>,
+}
+
+impl<'a> BlowUpDetector<'a> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `threshold` - the maximum allowed magnitude of the goal function. Must be positive.
+    /// * `growth_factor` - the maximum allowed ratio of the magnitudes of the goal function on the
+    ///   neighboring iterations. Must be greater than 1.
+    pub fn new(writer: &'a mut dyn io::Write, threshold: f64, growth_factor: f64) -> Self {
+        assert!(threshold > 0.0);
+        assert!(growth_factor > 1.0);
+
+        Self {
+            writer,
+            threshold,
+            growth_factor,
+            previous_magnitude: None,
+            blowup_iteration: None,
+        }
+    }
+
+    /// Returns the iteration number when the blow-up was detected or None if the blow-up was
+    /// not detected after `start()`.
+    pub fn get_blowup_iteration(&self) -> Option<usize> {
+        self.blowup_iteration
+    }
+
+    fn remember<T>(&mut self, state: &dyn AlgorithmState<T>) {
+        self.previous_magnitude = state.get_best_solution().map(|(_, goal)| goal.abs());
+    }
+
+    fn is_blowup(&self, magnitude: f64) -> bool {
+        if !magnitude.is_finite() || magnitude > self.threshold {
+            return true;
+        }
+
+        match self.previous_magnitude {
+            Some(previous) if previous > 0.0 => magnitude > previous * self.growth_factor,
+            _ => false,
+        }
+    }
+}
+
+impl<'a, T> Logger<T> for BlowUpDetector<'a> {
+    fn start(&mut self, state: &dyn AlgorithmState<T>) {
+        self.blowup_iteration = None;
+        self.remember(state);
+    }
+
+    fn resume(&mut self, state: &dyn AlgorithmState<T>) {
+        self.remember(state);
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        if self.blowup_iteration.is_none() {
+            if let Some((_, goal)) = state.get_best_solution() {
+                if self.is_blowup(goal.abs()) {
+                    let iteration = state.get_iteration();
+                    self.blowup_iteration = Some(iteration);
+                    writeln!(
+                        &mut self.writer,
+                        "Warning: numerical blow-up at iteration {}. Goal: {}",
+                        iteration, goal
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        self.remember(state);
+    }
+}
+
+/// The logger writes the iteration number, the best solution and value of goal function to the
+/// file every `interval` iterations and after finish of algorithm. The file is overwritten, so
+/// it contains the last checkpoint only. The logger may be used to save progress of long
+/// runnings if the process will be killed.
+///
+/// The checkpoint is written to the temporary file `<path>.tmp` first, then the file is renamed,
+/// so the checkpoint file is not corrupted if the process is killed during writing. The values
+/// are written with full precision. The writing errors are printed out to stderr.
+pub struct CheckpointLogger {
+    path: PathBuf,
+    interval: usize,
+}
+
+impl CheckpointLogger {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `path` - path to the checkpoint file.
+    /// * `interval` - how often (in iterations) the checkpoint will be written. Must be greater
+    ///   than 0.
+    pub fn new<P: AsRef<Path>>(path: P, interval: usize) -> Self {
+        assert!(interval > 0);
+        Self {
+            path: path.as_ref().to_path_buf(),
+            interval,
+        }
+    }
+
+    fn write_checkpoint<T: Display>(&self, state: &dyn AlgorithmState<T>) {
+        let mut content = format!("Iteration: {}\n", state.get_iteration());
+        match state.get_best_solution() {
+            None => content += "Solution not found\n",
+            Some((solution, goal)) => {
+                content += &format!("Solution: {}\nGoal: {}\n", solution, goal);
+            }
+        }
+
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+
+        let result =
+            fs::write(&temp_path, content).and_then(|_| fs::rename(&temp_path, &self.path));
+        if let Err(error) = result {
+            eprintln!(
+                "Can't write checkpoint to {}: {}",
+                self.path.display(),
+                error
+            );
+        }
+    }
+}
+
+impl<T: Display> Logger<T> for CheckpointLogger {
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        if state.get_iteration() % self.interval == 0 {
+            self.write_checkpoint(state);
+        }
+    }
+
+    fn finish(&mut self, state: &dyn AlgorithmState<T>) {
+        self.write_checkpoint(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StateStub {
+        solution: Option<Solution<f64>>,
+        iteration: usize,
+    }
+
+    impl AlgorithmState<f64> for StateStub {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            self.solution
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+    }
+
+    struct VecStateStub {
+        solution: Option<Solution<Vec<f64>>>,
+        iteration: usize,
+    }
+
+    impl AlgorithmState<Vec<f64>> for VecStateStub {
+        fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+            self.solution.clone()
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+    }
+
+    #[test]
+    fn generic_result_logger_scalar() {
+        let state = StateStub {
+            solution: Some((1.23456, 0.5)),
+            iteration: 10,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = GenericResultLogger::new(&mut buffer, 2);
+            logger.start(&state);
+            logger.next_iteration(&state);
+            logger.finish(&state);
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output, "Solution: 1.23\nGoal: 0.50\nIterations count: 10\n");
+    }
+
+    #[test]
+    fn generic_result_logger_not_found() {
+        let state = StateStub {
+            solution: None,
+            iteration: 3,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = GenericResultLogger::new(&mut buffer, 2);
+            logger.finish(&state);
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output, "Solution not found\nIterations count: 3\n");
+    }
+
+    #[test]
+    fn improvement_logger() {
+        let state = |goal, iteration| StateStub {
+            solution: Some((0.0, goal)),
+            iteration,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = ImprovementLogger::new(&mut buffer, 2);
+            logger.start(&state(10.0, 0));
+            logger.next_iteration(&state(7.5, 1));
+            logger.next_iteration(&state(7.5, 2));
+            logger.next_iteration(&state(7.0, 3));
+            logger.finish(&state(7.0, 3));
+
+            logger.resume(&state(7.0, 3));
+            logger.next_iteration(&state(6.0, 4));
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        let deltas: Vec<&str> = output
+            .lines()
+            .map(|line| line.split_whitespace().last().unwrap())
+            .collect();
+        assert_eq!(deltas, vec!["2.50", "0.00", "0.50", "1.00"]);
+        assert_eq!(
+            output.lines().next().unwrap(),
+            format!("{:<8}  {:>20}  {:>20}", 1, "7.50", "2.50")
+        );
+    }
+
+    #[test]
+    fn improvement_logger_unknown_previous() {
+        let state_empty = StateStub {
+            solution: None,
+            iteration: 0,
+        };
+        let state = StateStub {
+            solution: Some((0.0, 3.0)),
+            iteration: 1,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = ImprovementLogger::new(&mut buffer, 1);
+            logger.start(&state_empty);
+            logger.next_iteration(&state_empty);
+            logger.next_iteration(&state);
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.trim_end().ends_with("0.0"));
+    }
+
+    #[test]
+    fn best_ever_logger() {
+        let state_start = VecStateStub {
+            solution: Some((vec![1.0, -1.0], 10.0)),
+            iteration: 0,
+        };
+        let state_good = VecStateStub {
+            solution: Some((vec![2.0, -2.0], 5.0)),
+            iteration: 5,
+        };
+        let state_worse = VecStateStub {
+            solution: Some((vec![3.0, -3.0], 7.0)),
+            iteration: 10,
+        };
+        let state_better = VecStateStub {
+            solution: Some((vec![4.0, -4.0], 1.0)),
+            iteration: 15,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = BestEverLogger::new(&mut buffer, 1);
+            logger.start(&state_start);
+            assert_eq!(logger.get_best(), Some((vec![1.0, -1.0], 10.0)));
+
+            logger.resume(&state_start);
+            logger.next_iteration(&state_good);
+            logger.finish(&state_good);
+            assert_eq!(logger.get_best(), Some((vec![2.0, -2.0], 5.0)));
+
+            logger.resume(&state_worse);
+            logger.next_iteration(&state_worse);
+            logger.finish(&state_worse);
+            assert_eq!(logger.get_best(), Some((vec![2.0, -2.0], 5.0)));
+
+            logger.resume(&state_better);
+            logger.finish(&state_better);
+            assert_eq!(logger.get_best(), Some((vec![4.0, -4.0], 1.0)));
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            output,
+            "Best ever solution:  2.0  -2.0\nBest ever goal: 5.0\n\
+             Best ever solution:  2.0  -2.0\nBest ever goal: 5.0\n\
+             Best ever solution:  4.0  -4.0\nBest ever goal: 1.0\n"
+        );
+    }
+
+    #[test]
+    fn best_ever_logger_not_found() {
+        let state = VecStateStub {
+            solution: None,
+            iteration: 0,
+        };
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = BestEverLogger::<Vec<f64>>::new(&mut buffer, 1);
+            logger.start(&state);
+            logger.finish(&state);
+            assert_eq!(logger.get_best(), None);
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output, "Best ever solution not found\n");
+    }
+
+    #[test]
+    fn channel_logger() {
+        use crate::particleswarm::initializing::{
+            RandomCoordinatesInitializer, ZeroVelocityInitializer,
+        };
+        use crate::particleswarm::velocitycalc::ClassicVelocityCalculator;
+        use crate::particleswarm::ParticleSwarmOptimizer;
+        use crate::tools::stopchecker::MaxIterations;
+        use crate::{GoalFromFunction, Optimizer};
+        use std::sync::mpsc;
+
+        let iterations = 5;
+        let intervals = vec![(-1.0, 1.0); 2];
+        let (sender, receiver) = mpsc::channel();
+
+        let mut optimizer = ParticleSwarmOptimizer::new(
+            Box::new(GoalFromFunction::new(|x: &Vec<f64>| {
+                x.iter().map(|xi| xi * xi).sum()
+            })),
+            Box::new(MaxIterations::new(iterations)),
+            Box::new(RandomCoordinatesInitializer::new(intervals.clone(), 10)),
+            Box::new(ZeroVelocityInitializer::new(intervals.len(), 10)),
+            Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+        );
+        optimizer.set_loggers(vec![Box::new(ChannelLogger::new(sender))]);
+        let (solution, goal) = optimizer.find_min().unwrap();
+        drop(optimizer);
+
+        let updates: Vec<ProgressUpdate<Vec<f64>>> = receiver.iter().collect();
+        assert_eq!(updates.len(), iterations);
+        for (n, update) in updates.iter().enumerate() {
+            assert_eq!(update.iteration, n + 1);
+            assert!(update.best_goal.is_some());
+        }
+
+        let last = updates.last().unwrap();
+        assert_eq!(last.best_goal, Some(goal));
+        assert_eq!(last.best_solution, Some(solution));
+    }
+
+    #[test]
+    fn channel_logger_disconnected() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+
+        let state = StateStub {
+            solution: Some((1.0, 2.0)),
+            iteration: 1,
+        };
+
+        let mut logger = ChannelLogger::new(sender);
+        logger.next_iteration(&state);
+    }
+
+    #[test]
+    fn channel_logger_not_found() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let state = StateStub {
+            solution: None,
+            iteration: 2,
+        };
+
+        let mut logger = ChannelLogger::new(sender);
+        logger.next_iteration(&state);
+
+        assert_eq!(
+            receiver.try_recv().unwrap(),
+            ProgressUpdate {
+                iteration: 2,
+                best_goal: None,
+                best_solution: None,
+            }
+        );
+    }
+
+    #[test]
+    fn blowup_detector_growth() {
+        let goals = [1.0, 2.0, 3.5, 50.0, 5000.0, f64::INFINITY];
+
+        let mut buffer: Vec<u8> = vec![];
+        let blowup_iteration = {
+            let mut logger = BlowUpDetector::new(&mut buffer, 1e6, 10.0);
+            logger.start(&StateStub {
+                solution: None,
+                iteration: 0,
+            });
+
+            for (n, goal) in goals.iter().enumerate() {
+                logger.next_iteration(&StateStub {
+                    solution: Some((0.0, *goal)),
+                    iteration: n + 1,
+                });
+            }
+            logger.get_blowup_iteration()
+        };
+
+        assert_eq!(blowup_iteration, Some(4));
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            output,
+            "Warning: numerical blow-up at iteration 4. Goal: 50\n"
+        );
+    }
+
+    #[test]
+    fn blowup_detector_threshold() {
+        let goals = [-10.0, -90.0, -150.0, -200.0, -1e10];
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut logger = BlowUpDetector::new(&mut buffer, 100.0, 1000.0);
+            for (n, goal) in goals.iter().enumerate() {
+                logger.next_iteration(&StateStub {
+                    solution: Some((0.0, *goal)),
+                    iteration: n + 1,
+                });
+            }
+            assert_eq!(logger.get_blowup_iteration(), Some(3));
+
+            // The detector is reset after the start
+            let state = StateStub {
+                solution: Some((0.0, 1.0)),
+                iteration: 0,
+            };
+            logger.start(&state);
+            logger.next_iteration(&state);
+            assert_eq!(logger.get_blowup_iteration(), None);
+        }
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output.lines().count(), 1);
+    }
+
+    #[test]
+    fn checkpoint_logger_interval() {
+        let path = std::env::temp_dir().join(format!(
+            "optlib-checkpoint-interval-{}.txt",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut logger = CheckpointLogger::new(&path, 3);
+        for iteration in 1..=5 {
+            logger.next_iteration(&StateStub {
+                solution: Some((iteration as f64, 0.5)),
+                iteration,
+            });
+
+            if iteration < 3 {
+                assert!(!path.exists());
+            }
+        }
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "Iteration: 3\nSolution: 3\nGoal: 0.5\n");
+
+        logger.finish(&StateStub {
+            solution: None,
+            iteration: 5,
+        });
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "Iteration: 5\nSolution not found\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn checkpoint_logger_write_error() {
+        let path = std::env::temp_dir()
+            .join("optlib-not-existing-directory")
+            .join("checkpoint.txt");
+
+        let mut logger = CheckpointLogger::new(&path, 1);
+        let state = StateStub {
+            solution: Some((1.0, 2.0)),
+            iteration: 1,
+        };
+        logger.next_iteration(&state);
+        logger.finish(&state);
+        assert!(!path.exists());
+    }
+
+    /// The logger records names of the called methods and iteration numbers.
+    struct RecordingLogger<'a> {
+        calls: &'a mut Vec<(String, usize)>,
+    }
+
+    impl<'a> Logger<f64> for RecordingLogger<'a> {
+        fn start(&mut self, state: &dyn AlgorithmState<f64>) {
+            self.calls
+                .push(("start".to_string(), state.get_iteration()));
+        }
+
+        fn resume(&mut self, state: &dyn AlgorithmState<f64>) {
+            self.calls
+                .push(("resume".to_string(), state.get_iteration()));
+        }
+
+        fn next_iteration(&mut self, state: &dyn AlgorithmState<f64>) {
+            self.calls
+                .push(("next_iteration".to_string(), state.get_iteration()));
+        }
+
+        fn finish(&mut self, state: &dyn AlgorithmState<f64>) {
+            self.calls
+                .push(("finish".to_string(), state.get_iteration()));
+        }
+    }
+
+    #[test]
+    fn tee_logger() {
+        let mut calls_1 = vec![];
+        let mut calls_2 = vec![];
+        let mut state = StateStub {
+            solution: None,
+            iteration: 0,
+        };
+
+        {
+            let mut logger = TeeLogger::new(vec![
+                Box::new(RecordingLogger {
+                    calls: &mut calls_1,
+                }),
+                Box::new(RecordingLogger {
+                    calls: &mut calls_2,
+                }),
+            ]);
+
+            logger.start(&state);
+            logger.resume(&state);
+            for iteration in 1..=2 {
+                state.iteration = iteration;
+                logger.next_iteration(&state);
+            }
+            logger.finish(&state);
+        }
+
+        let expected = vec![
+            ("start".to_string(), 0),
+            ("resume".to_string(), 0),
+            ("next_iteration".to_string(), 1),
+            ("next_iteration".to_string(), 2),
+            ("finish".to_string(), 2),
+        ];
+        assert_eq!(calls_1, expected);
+        assert_eq!(calls_2, expected);
+    }
+}
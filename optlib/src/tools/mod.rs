@@ -1,29 +1,318 @@
+pub mod goals;
 pub mod logging;
+pub mod runners;
 pub mod stopchecker;
 pub mod statistics;
 
-use num::NumCast;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Sub;
+
+use num::{Float, NumCast};
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::genetic::{Population, PreBirth};
+use crate::particleswarm::PostMove;
+use crate::tools::goals::Violation;
+use crate::tools::statistics::{CallCountData, GoalCalcStatistics};
+use crate::tools::stopchecker::{StopChecker, Threshold};
+use crate::{Goal, GoalValue, Optimizer, Solution, Tunable};
+
+/// The error of the intervals validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntervalsError {
+    /// Minimum value is greater than maximum value (or the values can't be compared, for example
+    /// NaN) for the interval with the index.
+    InvalidInterval(usize),
+}
+
+impl fmt::Display for IntervalsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntervalsError::InvalidInterval(index) => {
+                write!(f, "Invalid interval with index {}: min > max", index)
+            }
+        }
+    }
+}
+
+impl error::Error for IntervalsError {}
+
+/// Intervals (min, max) for every dimension of the search space.
+/// Minimum value must be less or equal than maximum value. Degenerate intervals (min == max) are
+/// allowed.
+///
+/// `T` - type of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intervals<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: PartialOrd> Intervals<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - vector of tuples (min, max). Size of the vector must be equal to dimension.
+    ///
+    /// Returns error if min > max for any interval.
+    pub fn new(intervals: Vec<(T, T)>) -> Result<Self, IntervalsError> {
+        Self::validate(&intervals)?;
+        Ok(Self { intervals })
+    }
+
+    /// Check the intervals without creating `Intervals`.
+    pub fn validate(intervals: &[(T, T)]) -> Result<(), IntervalsError> {
+        for (index, (min, max)) in intervals.iter().enumerate() {
+            match min.partial_cmp(max) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => {}
+                _ => return Err(IntervalsError::InvalidInterval(index)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Intervals<T> {
+    /// Returns count of the intervals (dimension).
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns true if there are no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns reference to vector of tuples (min, max).
+    pub fn as_vec(&self) -> &Vec<(T, T)> {
+        &self.intervals
+    }
+
+    /// Returns iterator for tuples (min, max).
+    pub fn iter(&self) -> std::slice::Iter<'_, (T, T)> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Intervals<T> {
+    /// Returns width (max - min) of the interval with index `i`.
+    pub fn width(&self, i: usize) -> T {
+        let (min, max) = self.intervals[i];
+        max - min
+    }
+}
+
+impl<T: Float> Intervals<T> {
+    /// Move values of `x` inside the intervals. NaN and infinite values are replaced by min value.
+    /// Length of `x` must be equal to count of the intervals.
+    pub fn clamp(&self, x: &mut [T]) {
+        assert_eq!(x.len(), self.intervals.len());
+
+        for (value, (min, max)) in x.iter_mut().zip(self.intervals.iter()) {
+            if !value.is_finite() {
+                *value = *min;
+            }
+
+            if *value < *min {
+                *value = *min;
+            }
+
+            if *value > *max {
+                *value = *max;
+            }
+        }
+    }
+
+    /// Returns true if all values of `x` are finite and lie inside the intervals.
+    /// Length of `x` must be equal to count of the intervals.
+    pub fn contains(&self, x: &[T]) -> bool {
+        assert_eq!(x.len(), self.intervals.len());
+
+        x.iter()
+            .zip(self.intervals.iter())
+            .all(|(value, (min, max))| value.is_finite() && value >= min && value <= max)
+    }
+}
+
+/// Returns true if `objectives_1` dominates `objectives_2`: all objectives of `objectives_1` are
+/// less or equal than objectives of `objectives_2` and at least one objective is less (all
+/// objectives are minimized). Length of the vectors must be equal.
+pub fn dominates(objectives_1: &[f64], objectives_2: &[f64]) -> bool {
+    assert_eq!(objectives_1.len(), objectives_2.len());
+
+    let mut better = false;
+    for (x1, x2) in objectives_1.iter().zip(objectives_2.iter()) {
+        if x1 > x2 {
+            return false;
+        }
+
+        if x1 < x2 {
+            better = true;
+        }
+    }
+
+    better
+}
+
+/// The archive of the non-dominated solutions for multi-objective problems (all objectives are
+/// minimized). Every solution is the tuple (objectives, point). The archive may be filled by a
+/// logger or by results of several runnings (for example, runnings with different weights of
+/// scalarized goal function) to collect the Pareto front.
+///
+/// The archive size may be limited (see `ParetoArchive::max_size()`). If the archive is full,
+/// the solution with minimal crowding distance is removed, so the front keeps its extreme points
+/// and diversity.
+///
+/// `T` - type of a point in the search space.
+#[derive(Debug, Clone)]
+pub struct ParetoArchive<T> {
+    front: Vec<(Vec<f64>, T)>,
+    max_size: Option<usize>,
+}
+
+impl<T> ParetoArchive<T> {
+    /// Constructor. The archive size is not limited.
+    pub fn new() -> Self {
+        Self {
+            front: vec![],
+            max_size: None,
+        }
+    }
+
+    /// Set max count of the solutions in the archive. The value must be greater than 0.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        assert!(max_size > 0);
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Add the solution to the archive if the solution is not dominated by solutions in the
+    /// archive (and the archive does not contain solution with the same objectives). The solutions
+    /// which are dominated by the new solution are removed.
+    ///
+    /// Returns true if the solution is in the archive after the insertion.
+    pub fn insert(&mut self, objectives: Vec<f64>, point: T) -> bool {
+        let rejected = self
+            .front
+            .iter()
+            .any(|(other, _)| dominates(other, &objectives) || *other == objectives);
+
+        if rejected {
+            return false;
+        }
+
+        self.front
+            .retain(|(other, _)| !dominates(&objectives, other));
+        self.front.push((objectives, point));
+
+        let new_index = self.front.len() - 1;
+        match self.max_size {
+            Some(max_size) if self.front.len() > max_size => {
+                let removed = self.get_most_crowded();
+                self.front.remove(removed);
+                removed != new_index
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the non-dominated solutions (objectives, point).
+    pub fn get_front(&self) -> &Vec<(Vec<f64>, T)> {
+        &self.front
+    }
+
+    /// Returns count of the solutions in the archive.
+    pub fn len(&self) -> usize {
+        self.front.len()
+    }
+
+    /// Returns true if the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty()
+    }
+
+    /// Remove all solutions from the archive.
+    pub fn clear(&mut self) {
+        self.front.clear();
+    }
+
+    /// Returns index of the solution with minimal crowding distance. The extreme solutions for
+    /// every objective have infinite crowding distance. The last solution is preferred for equal
+    /// distances.
+    fn get_most_crowded(&self) -> usize {
+        let count = self.front.len();
+        let objectives_count = self.front[0].0.len();
+        let mut distances = vec![0.0; count];
+
+        for m in 0..objectives_count {
+            let mut indexes: Vec<usize> = (0..count).collect();
+            indexes.sort_by(|&i, &j| {
+                self.front[i].0[m]
+                    .partial_cmp(&self.front[j].0[m])
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            let min = self.front[indexes[0]].0[m];
+            let max = self.front[indexes[count - 1]].0[m];
+            distances[indexes[0]] = f64::INFINITY;
+            distances[indexes[count - 1]] = f64::INFINITY;
+
+            if max > min {
+                for k in 1..count - 1 {
+                    let next = self.front[indexes[k + 1]].0[m];
+                    let prev = self.front[indexes[k - 1]].0[m];
+                    distances[indexes[k]] += (next - prev) / (max - min);
+                }
+            }
+        }
+
+        let mut result = count - 1;
+        for (i, distance) in distances.iter().enumerate() {
+            if *distance < distances[result] {
+                result = i;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T> Default for ParetoArchive<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// Creator to initialize vector with random values in given interval.
 /// `T` - vector items type
 pub struct RandomVectorCreator {
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl RandomVectorCreator {
-    /// Constructor.
+    /// Constructor. The random generator is initialized from the system entropy.
     pub fn new() -> Self {
         Self {
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
 
-    pub fn create_vec<T: NumCast + PartialOrd>(&mut self, intervals: &Vec<(T, T)>) -> Vec<T> {
-        for interval in intervals {
-            assert!(interval.0 < interval.1);
+    /// Constructor with the fixed seed of the random generator. The creators with the same seed
+    /// create the same sequence of vectors, so the results are reproducible.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self {
+            random: StdRng::seed_from_u64(seed),
         }
+    }
+
+    pub fn create_vec<T: NumCast + PartialOrd>(&mut self, intervals: &[(T, T)]) -> Vec<T> {
+        Intervals::validate(intervals).unwrap();
 
         let result = intervals
             .iter()
@@ -37,9 +326,377 @@ impl RandomVectorCreator {
     }
 }
 
+/// Returns random value from the standard normal distribution (mean = 0, standard deviation = 1)
+/// with the Box-Muller transform.
+pub(crate) fn sample_standard_normal<R: Rng>(random: &mut R) -> f64 {
+    // u1 is in (0, 1] to avoid ln(0)
+    let u1: f64 = 1.0 - random.gen::<f64>();
+    let u2: f64 = random.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// The result of `run_to_target()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TargetResult {
+    /// The optimizer reached the target. `evaluations` - call count of the goal function.
+    Reached { evaluations: usize },
+
+    /// The optimizer stopped before reaching the target. `final_goal` - the goal function value
+    /// of the found solution or None if the optimizer did not find a solution.
+    Failed { final_goal: Option<GoalValue> },
+}
+
+/// Run an optimizer until the goal function value is less or equal than `target` (for example,
+/// for "performance profile" experiments) and return call count of the goal function.
+///
+/// The function wraps `goal` with `GoalCalcStatistics` and creates the `Threshold` stop checker.
+/// The optimizer is created by the `make_optimizer` closure which accepts the wrapped goal and
+/// the stop checker. The closure may unite the stop checker with other ones (for example, with
+/// `MaxIterations` by `CompositeAny`) to stop the optimizer if the target is not reachable.
+/// The call count of the running is added to `call_count`.
+///
+/// # Parameters
+/// * `make_optimizer` - the closure to create an optimizer with the goal function and the stop
+///   checker.
+/// * `goal` - the goal function.
+/// * `call_count` - the call count data to store call count of the goal function.
+/// * `target` - the goal function value to reach.
+pub fn run_to_target<T, F>(
+    make_optimizer: F,
+    goal: &mut dyn Goal<T>,
+    call_count: &mut CallCountData,
+    target: GoalValue,
+) -> TargetResult
+where
+    F: for<'b> FnOnce(Box<dyn Goal<T> + 'b>, Box<dyn StopChecker<T>>) -> Box<dyn Optimizer<T> + 'b>,
+{
+    call_count.next_run();
+    let result = {
+        let goal_stat = GoalCalcStatistics::new(goal, call_count);
+        let mut optimizer = make_optimizer(Box::new(goal_stat), Box::new(Threshold::new(target)));
+        optimizer.find_min()
+    };
+
+    match result {
+        Some((_, goal_value)) if goal_value <= target => TargetResult::Reached {
+            evaluations: *call_count.get_call_count().last().unwrap(),
+        },
+        Some((_, goal_value)) => TargetResult::Failed {
+            final_goal: Some(goal_value),
+        },
+        None => TargetResult::Failed { final_goal: None },
+    }
+}
+
+/// Count and magnitude of the violations of a single constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ConstraintViolations {
+    /// Count of the points which violate the constraint.
+    pub count: usize,
+
+    /// Sum of the violation measures.
+    pub total: GoalValue,
+
+    /// Maximal violation measure.
+    pub max: GoalValue,
+}
+
+/// The report of `ViolationRecorder`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ViolationReport {
+    /// Count of the checked points.
+    pub points_count: usize,
+
+    /// The violations for every constraint in the order of the constraints in
+    /// `ViolationRecorder::new()`.
+    pub constraints: Vec<ConstraintViolations>,
+}
+
+/// The diagnostic tool to collect count and magnitude of the constraints violation during
+/// optimization. `ViolationRecorder` implements `PostMove` for the particle swarm optimization
+/// and `PreBirth` for the genetic algorithm and does not change the points. Pass the recorder to
+/// the optimizer by mutable reference (`Box::new(&mut recorder)`) to read the report after
+/// optimization.
+///
+/// `P` - type of a point in the search space.
+pub struct ViolationRecorder<'a, P> {
+    constraints: Vec<Box<Violation<'a, P>>>,
+    report: ViolationReport,
+}
+
+impl<'a, P> ViolationRecorder<'a, P> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `constraints` - the functions return measure of the violation for every constraint. The
+    ///   value must be 0 if the constraint is satisfied and positive value otherwise.
+    pub fn new(constraints: Vec<Box<Violation<'a, P>>>) -> Self {
+        let report = ViolationReport {
+            points_count: 0,
+            constraints: vec![ConstraintViolations::default(); constraints.len()],
+        };
+
+        Self {
+            constraints,
+            report,
+        }
+    }
+
+    pub fn get_report(&self) -> &ViolationReport {
+        &self.report
+    }
+
+    /// Remove the collected data.
+    pub fn clear(&mut self) {
+        self.report.points_count = 0;
+        for violations in &mut self.report.constraints {
+            *violations = ConstraintViolations::default();
+        }
+    }
+
+    fn record(&mut self, point: &P) {
+        self.report.points_count += 1;
+        for (constraint, violations) in self
+            .constraints
+            .iter()
+            .zip(self.report.constraints.iter_mut())
+        {
+            let violation = constraint(point);
+            if violation > 0.0 {
+                violations.count += 1;
+                violations.total += violation;
+                violations.max = violations.max.max(violation);
+            }
+        }
+    }
+}
+
+impl<'a, G> PostMove<G> for ViolationRecorder<'a, Vec<G>> {
+    fn post_move(&mut self, coordinates: &mut Vec<G>) {
+        self.record(coordinates);
+    }
+}
+
+impl<'a, T> PreBirth<T> for ViolationRecorder<'a, T> {
+    fn pre_birth(&mut self, _population: &Population<T>, new_chromosomes: &mut Vec<T>) {
+        for chromosomes in new_chromosomes.iter() {
+            self.record(chromosomes);
+        }
+    }
+}
+
+/// The closure for `MetaOptimizer`. The closure accepts the goal function over the parameters of
+/// the inner optimizer and must return the outer optimizer which minimizes the goal function.
+pub type OuterFactory<'a> =
+    dyn for<'b> FnMut(Box<dyn Goal<Vec<f64>> + 'b>) -> Box<dyn Optimizer<Vec<f64>> + 'b> + 'a;
+
+/// The optimizer to tune the parameters of other (inner) optimizer with `Tunable` trait. The point
+/// in the search space is vector of the parameters values in the order of `params` in
+/// `MetaOptimizer::new()`. The goal function for the point is the average value of goal function
+/// of the inner optimizer results for `runs_count` runnings, or NaN if the inner optimizer did not
+/// find a solution in any running. The values of the parameters are clamped to the bounds.
+///
+/// Any optimizer for Vec<f64> may be used as outer optimizer. The outer optimizer is created by
+/// the closure for every `find_min()` call. After `find_min()` the inner optimizer gets the best
+/// found parameters. If the outer optimizer did not find a solution, the parameters of the inner
+/// optimizer are restored.
+///
+/// The inner optimizer must search minimum of its goal function.
+///
+/// `O` - type of the inner optimizer.
+/// `T` - type of a point in the search space for goal function of the inner optimizer.
+pub struct MetaOptimizer<'a, O, T> {
+    inner: O,
+    params: Vec<(String, (f64, f64))>,
+    runs_count: usize,
+    outer_factory: Box<OuterFactory<'a>>,
+    point_type: PhantomData<T>,
+}
+
+impl<'a, O: Optimizer<T> + Tunable, T> MetaOptimizer<'a, O, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `inner` - the optimizer which parameters will be tuned.
+    /// * `params` - names of the parameters with the bounds (min, max).
+    /// * `runs_count` - count of the runnings of the inner optimizer for every point.
+    /// * `outer_factory` - the closure to create the outer optimizer by goal function.
+    pub fn new(
+        inner: O,
+        params: Vec<(String, (f64, f64))>,
+        runs_count: usize,
+        outer_factory: Box<OuterFactory<'a>>,
+    ) -> Self {
+        assert!(!params.is_empty());
+        assert!(runs_count > 0);
+        for (_, (min, max)) in &params {
+            assert!(min <= max);
+        }
+
+        Self {
+            inner,
+            params,
+            runs_count,
+            outer_factory,
+            point_type: PhantomData,
+        }
+    }
+
+    /// Returns the inner optimizer.
+    pub fn get_inner(&self) -> &O {
+        &self.inner
+    }
+
+    /// Convert the point of the search space to the parameters of the inner optimizer.
+    pub fn to_params(&self, values: &[f64]) -> HashMap<String, f64> {
+        values_to_params(&self.params, values)
+    }
+
+    /// Run the outer optimizer with the iterations limit if `max_iterations` is not None.
+    fn search(&mut self, max_iterations: Option<usize>) -> Option<Solution<Vec<f64>>> {
+        let initial_params = self.inner.get_params();
+        let goal = MetaGoal {
+            inner: &mut self.inner,
+            params: &self.params,
+            runs_count: self.runs_count,
+            point_type: PhantomData,
+        };
+
+        let result = {
+            let mut outer = (self.outer_factory)(Box::new(goal));
+            match max_iterations {
+                None => outer.find_min(),
+                Some(max_iterations) => outer.find_min_with_budget(max_iterations),
+            }
+        };
+
+        match &result {
+            Some((values, _)) => self
+                .inner
+                .set_params(&values_to_params(&self.params, values)),
+            None => self.inner.set_params(&initial_params),
+        }
+
+        result
+    }
+}
+
+impl<'a, O: Optimizer<T> + Tunable, T> Optimizer<Vec<f64>> for MetaOptimizer<'a, O, T> {
+    /// Returns the best parameters of the inner optimizer and the average value of its goal
+    /// function.
+    fn find_min(&mut self) -> Option<Solution<Vec<f64>>> {
+        self.search(None)
+    }
+
+    /// The limit is applied to the outer optimizer.
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<Vec<f64>>> {
+        self.search(Some(max_iterations))
+    }
+}
+
+/// The goal function of `MetaOptimizer`.
+struct MetaGoal<'b, O, T> {
+    inner: &'b mut O,
+    params: &'b [(String, (f64, f64))],
+    runs_count: usize,
+    point_type: PhantomData<T>,
+}
+
+impl<'b, O: Optimizer<T> + Tunable, T> Goal<Vec<f64>> for MetaGoal<'b, O, T> {
+    fn get(&mut self, values: &Vec<f64>) -> GoalValue {
+        self.inner
+            .set_params(&values_to_params(self.params, values));
+
+        let mut sum = 0.0;
+        for _ in 0..self.runs_count {
+            match self.inner.find_min() {
+                Some((_, goal)) => sum += goal,
+                None => return GoalValue::NAN,
+            }
+        }
+
+        sum / self.runs_count as GoalValue
+    }
+}
+
+fn values_to_params(params: &[(String, (f64, f64))], values: &[f64]) -> HashMap<String, f64> {
+    assert_eq!(params.len(), values.len());
+
+    params
+        .iter()
+        .zip(values.iter())
+        .map(|((name, (min, max)), value)| (name.clone(), value.max(*min).min(*max)))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::tools::RandomVectorCreator;
+    use crate::particleswarm::PostMove;
+    use crate::randomsearch::RandomSearchOptimizer;
+    use crate::tools::statistics::CallCountData;
+    use crate::tools::stopchecker::{CompositeAny, MaxIterations, StopChecker};
+    use crate::tools::{
+        dominates, run_to_target, sample_standard_normal, ConstraintViolations, Intervals,
+        IntervalsError, MetaOptimizer, ParetoArchive, RandomVectorCreator, TargetResult,
+        ViolationRecorder,
+    };
+    use crate::{AlgorithmState, Goal, GoalFromFunction, Optimizer, Solution, Tunable};
+    use std::collections::HashMap;
+
+    /// The state of `StepOptimizer`.
+    #[derive(Default)]
+    struct StepState {
+        iteration: usize,
+        best: Option<Solution<f64>>,
+    }
+
+    impl AlgorithmState<f64> for StepState {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            self.best
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+    }
+
+    /// The optimizer moves the point from 10.0 to 0.0 with the step 1.0 until the stop checker
+    /// returns true or the point reaches 0.0. The goal function is calculated for every point.
+    struct StepOptimizer<'a> {
+        goal: Box<dyn Goal<f64> + 'a>,
+        stop_checker: Box<dyn StopChecker<f64>>,
+        state: StepState,
+    }
+
+    impl<'a> Optimizer<f64> for StepOptimizer<'a> {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            let mut x = 10.0;
+            while x >= 0.0 && !self.stop_checker.can_stop(&self.state) {
+                let value = self.goal.get(&x);
+                if self.state.best.is_none_or(|(_, best)| value < best) {
+                    self.state.best = Some((x, value));
+                }
+                x -= 1.0;
+                self.state.iteration += 1;
+            }
+            self.state.best
+        }
+    }
+
+    fn make_step_optimizer<'b>(
+        goal: Box<dyn Goal<f64> + 'b>,
+        stop_checker: Box<dyn StopChecker<f64>>,
+    ) -> Box<dyn Optimizer<f64> + 'b> {
+        Box::new(StepOptimizer {
+            goal,
+            stop_checker,
+            state: StepState::default(),
+        })
+    }
+
+    fn identity(x: &f64) -> f64 {
+        *x
+    }
 
     #[test]
     fn test_empty() {
@@ -78,11 +735,13 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn invalid_intervals_02() {
-        let intervals = vec![(0.0, 0.0)];
+    fn degenerate_intervals() {
+        let intervals = vec![(0.0, 0.0), (1.0, 2.0)];
         let mut creator = RandomVectorCreator::new();
-        creator.create_vec(&intervals);
+        let result = creator.create_vec(&intervals);
+
+        assert_eq!(result[0], 0.0);
+        assert!(result[1] >= 1.0 && result[1] <= 2.0);
     }
 
     #[test]
@@ -92,4 +751,415 @@ mod tests {
         let mut creator = RandomVectorCreator::new();
         creator.create_vec(&intervals);
     }
+
+    #[test]
+    fn random_vector_seed() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 10.0), (100.0, 200.0)];
+        let mut creator_1 = RandomVectorCreator::new_with_seed(42);
+        let mut creator_2 = RandomVectorCreator::new_with_seed(42);
+        let mut creator_3 = RandomVectorCreator::new_with_seed(43);
+
+        for _ in 0..10 {
+            let result_1: Vec<f64> = creator_1.create_vec(&intervals);
+            let result_2: Vec<f64> = creator_2.create_vec(&intervals);
+            let result_3: Vec<f64> = creator_3.create_vec(&intervals);
+
+            assert_eq!(result_1, result_2);
+            assert_ne!(result_1, result_3);
+        }
+    }
+
+    #[test]
+    fn intervals_new() {
+        let intervals = Intervals::new(vec![(0.0, 1.0), (-1.0, -1.0)]).unwrap();
+        assert_eq!(intervals.len(), 2);
+        assert!(!intervals.is_empty());
+        assert_eq!(intervals.as_vec(), &vec![(0.0, 1.0), (-1.0, -1.0)]);
+    }
+
+    #[test]
+    fn intervals_new_empty() {
+        let intervals: Intervals<f64> = Intervals::new(vec![]).unwrap();
+        assert!(intervals.is_empty());
+    }
+
+    #[test]
+    fn intervals_new_errors() {
+        assert_eq!(
+            Intervals::new(vec![(1.0, 0.0)]),
+            Err(IntervalsError::InvalidInterval(0))
+        );
+        assert_eq!(
+            Intervals::new(vec![(0.0, 1.0), (10.0, 0.0)]),
+            Err(IntervalsError::InvalidInterval(1))
+        );
+        assert_eq!(
+            Intervals::new(vec![(0.0, 1.0), (f64::NAN, 0.0)]),
+            Err(IntervalsError::InvalidInterval(1))
+        );
+    }
+
+    #[test]
+    fn intervals_width() {
+        let intervals = Intervals::new(vec![(0.0, 1.0), (-2.0, 3.0), (5.0, 5.0)]).unwrap();
+        assert_eq!(intervals.width(0), 1.0);
+        assert_eq!(intervals.width(1), 5.0);
+        assert_eq!(intervals.width(2), 0.0);
+    }
+
+    #[test]
+    fn intervals_clamp() {
+        let intervals = Intervals::new(vec![(0.0, 1.0), (1.0, 3.0), (5.0, 10.0), (0.0, 1.0)]).unwrap();
+        let mut x = vec![-3.0, 4.0, 6.0, f64::NAN];
+        intervals.clamp(&mut x);
+
+        assert_eq!(x, vec![0.0, 3.0, 6.0, 0.0]);
+    }
+
+    #[test]
+    fn intervals_contains() {
+        let intervals = Intervals::new(vec![(0.0, 1.0), (1.0, 3.0)]).unwrap();
+
+        assert!(intervals.contains(&[0.0, 3.0]));
+        assert!(intervals.contains(&[0.5, 2.0]));
+        assert!(!intervals.contains(&[-0.1, 2.0]));
+        assert!(!intervals.contains(&[0.5, 3.1]));
+        assert!(!intervals.contains(&[f64::NAN, 2.0]));
+        assert!(!intervals.contains(&[0.5, f64::INFINITY]));
+    }
+
+    #[test]
+    fn dominates_objectives() {
+        assert!(dominates(&[1.0, 2.0], &[2.0, 3.0]));
+        assert!(dominates(&[1.0, 2.0], &[1.0, 3.0]));
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[1.0, 3.0], &[2.0, 2.0]));
+        assert!(!dominates(&[2.0, 3.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn pareto_insert_dominated() {
+        let mut archive = ParetoArchive::new();
+        assert!(archive.insert(vec![1.0, 1.0], 'a'));
+        assert!(!archive.insert(vec![2.0, 1.0], 'b'));
+        assert!(!archive.insert(vec![1.0, 1.0], 'c'));
+
+        assert_eq!(archive.get_front(), &vec![(vec![1.0, 1.0], 'a')]);
+    }
+
+    #[test]
+    fn pareto_insert_dominating() {
+        let mut archive = ParetoArchive::new();
+        assert!(archive.insert(vec![1.0, 4.0], 'a'));
+        assert!(archive.insert(vec![2.5, 3.0], 'b'));
+        assert!(archive.insert(vec![4.0, 1.0], 'c'));
+        assert!(archive.insert(vec![3.0, 2.5], 'd'));
+        assert_eq!(archive.len(), 4);
+
+        // Dominates 'b' and 'd'
+        assert!(archive.insert(vec![2.0, 2.0], 'e'));
+
+        assert_eq!(
+            archive.get_front(),
+            &vec![
+                (vec![1.0, 4.0], 'a'),
+                (vec![4.0, 1.0], 'c'),
+                (vec![2.0, 2.0], 'e')
+            ]
+        );
+    }
+
+    #[test]
+    fn pareto_max_size() {
+        let mut archive = ParetoArchive::new().max_size(3);
+        assert!(archive.insert(vec![0.0, 10.0], 0));
+        assert!(archive.insert(vec![10.0, 0.0], 1));
+        assert!(archive.insert(vec![1.0, 9.0], 2));
+
+        // The most crowded solution is removed
+        assert!(archive.insert(vec![5.0, 5.0], 3));
+        assert_eq!(archive.len(), 3);
+        let points: Vec<i32> = archive
+            .get_front()
+            .iter()
+            .map(|(_, point)| *point)
+            .collect();
+        assert_eq!(points, vec![0, 1, 3]);
+
+        // The new solution is the most crowded
+        assert!(!archive.insert(vec![4.5, 5.6], 4));
+        assert_eq!(archive.len(), 3);
+    }
+
+    #[test]
+    fn run_to_target_reached() {
+        let mut goal = GoalFromFunction::new(identity);
+        let mut call_count = CallCountData::new();
+
+        // The goal function is calculated for 10.0, 9.0, ..., 3.0
+        let result = run_to_target(make_step_optimizer, &mut goal, &mut call_count, 3.0);
+        assert_eq!(result, TargetResult::Reached { evaluations: 8 });
+
+        let result = run_to_target(make_step_optimizer, &mut goal, &mut call_count, 9.5);
+        assert_eq!(result, TargetResult::Reached { evaluations: 2 });
+
+        assert_eq!(call_count.get_call_count(), vec![8, 2]);
+    }
+
+    #[test]
+    fn run_to_target_failed() {
+        let mut goal = GoalFromFunction::new(identity);
+        let mut call_count = CallCountData::new();
+
+        let result = run_to_target(make_step_optimizer, &mut goal, &mut call_count, -1.0);
+        assert_eq!(
+            result,
+            TargetResult::Failed {
+                final_goal: Some(0.0)
+            }
+        );
+        assert_eq!(call_count.get_call_count(), vec![11]);
+    }
+
+    #[test]
+    fn run_to_target_composite() {
+        let mut goal = GoalFromFunction::new(identity);
+        let mut call_count = CallCountData::new();
+
+        let result = run_to_target(
+            |goal, stop_checker| {
+                let stop_checker =
+                    CompositeAny::new(vec![stop_checker, Box::new(MaxIterations::new(3))]);
+                make_step_optimizer(goal, Box::new(stop_checker))
+            },
+            &mut goal,
+            &mut call_count,
+            0.0,
+        );
+        assert_eq!(
+            result,
+            TargetResult::Failed {
+                final_goal: Some(8.0)
+            }
+        );
+        assert_eq!(call_count.get_call_count(), vec![3]);
+    }
+
+    #[test]
+    fn standard_normal_moments() {
+        let count = 100000;
+        let mut random = rand::thread_rng();
+        let values: Vec<f64> = (0..count)
+            .map(|_| sample_standard_normal(&mut random))
+            .collect();
+
+        let mean = values.iter().sum::<f64>() / count as f64;
+        let variance = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count as f64;
+        assert!(mean.abs() < 0.02);
+        assert!((variance - 1.0).abs() < 0.03);
+    }
+
+    /// The constraints: x[0] <= 1 and x[1] >= 0.
+    fn create_violation_recorder<'a>() -> ViolationRecorder<'a, Vec<f64>> {
+        ViolationRecorder::new(vec![
+            Box::new(|x: &Vec<f64>| (x[0] - 1.0).max(0.0)),
+            Box::new(|x: &Vec<f64>| (-x[1]).max(0.0)),
+        ])
+    }
+
+    #[test]
+    fn violation_recorder_post_move() {
+        let mut recorder = create_violation_recorder();
+        let mut points = vec![vec![0.5, 0.5], vec![2.0, 0.0], vec![4.0, 1.0]];
+        for point in points.iter_mut() {
+            recorder.post_move(point);
+        }
+
+        // The points are not changed
+        assert_eq!(points, vec![vec![0.5, 0.5], vec![2.0, 0.0], vec![4.0, 1.0]]);
+
+        let report = recorder.get_report();
+        assert_eq!(report.points_count, 3);
+        assert_eq!(
+            report.constraints,
+            vec![
+                ConstraintViolations {
+                    count: 2,
+                    total: 4.0,
+                    max: 3.0,
+                },
+                ConstraintViolations::default(),
+            ]
+        );
+
+        recorder.clear();
+        assert_eq!(recorder.get_report().points_count, 0);
+        assert_eq!(
+            recorder.get_report().constraints[0],
+            ConstraintViolations::default()
+        );
+    }
+
+    #[test]
+    fn violation_recorder_pre_birth() {
+        use crate::genetic::creation::vec_float::RandomCreator;
+        use crate::genetic::cross::{CrossMean, VecCrossAllGenes};
+        use crate::genetic::mutation::{BitwiseMutation, VecMutation};
+        use crate::genetic::pairing::RandomPairing;
+        use crate::genetic::GeneticOptimizer;
+
+        // The genes are in [2; 3], so the first constraint is always violated
+        let mut recorder = create_violation_recorder();
+        {
+            let mut optimizer = GeneticOptimizer::new(
+                Box::new(GoalFromFunction::new(|x: &Vec<f64>| x.iter().sum())),
+                Box::new(MaxIterations::new(3)),
+                Box::new(RandomCreator::new(10, vec![(2.0, 3.0); 2])),
+                Box::new(RandomPairing::new()),
+                Box::new(VecCrossAllGenes::new(Box::new(CrossMean::new()))),
+                Box::new(VecMutation::new(0.0, Box::new(BitwiseMutation::new(1)))),
+                vec![],
+                vec![Box::new(&mut recorder)],
+            );
+            optimizer.find_min();
+        }
+
+        let report = recorder.get_report();
+        assert!(report.points_count > 0);
+        assert_eq!(report.constraints[0].count, report.points_count);
+        assert!(report.constraints[0].total >= report.points_count as f64);
+        assert_eq!(report.constraints[1], ConstraintViolations::default());
+    }
+
+    /// The optimizer returns the value of the "step" parameter as the result.
+    struct ParamOptimizer {
+        step: f64,
+        runs: usize,
+    }
+
+    impl Optimizer<f64> for ParamOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.runs += 1;
+            Some((self.step, self.step))
+        }
+    }
+
+    impl Tunable for ParamOptimizer {
+        fn get_params(&self) -> HashMap<String, f64> {
+            let mut params = HashMap::new();
+            params.insert("step".to_string(), self.step);
+            params
+        }
+
+        fn set_params(&mut self, params: &HashMap<String, f64>) {
+            if let Some(step) = params.get("step") {
+                self.step = *step;
+            }
+        }
+    }
+
+    #[test]
+    fn meta_optimizer() {
+        let inner = ParamOptimizer { step: 5.0, runs: 0 };
+        let mut meta = MetaOptimizer::new(
+            inner,
+            vec![("step".to_string(), (0.0, 10.0))],
+            3,
+            Box::new(|goal| {
+                Box::new(
+                    RandomSearchOptimizer::new(
+                        goal,
+                        Box::new(MaxIterations::new(50)),
+                        vec![(0.0, 10.0)],
+                    )
+                    .seed(1),
+                )
+            }),
+        );
+
+        let (values, goal) = meta.find_min().unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0], goal);
+        assert!(goal < 1.0);
+
+        // The inner optimizer gets the best parameters
+        assert_eq!(meta.get_inner().step, values[0]);
+        assert_eq!(meta.get_inner().runs, 3 * 50);
+    }
+
+    #[test]
+    fn meta_optimizer_budget() {
+        let inner = ParamOptimizer { step: 5.0, runs: 0 };
+        let mut meta = MetaOptimizer::new(
+            inner,
+            vec![("step".to_string(), (0.0, 10.0))],
+            3,
+            Box::new(|goal| {
+                Box::new(RandomSearchOptimizer::new(
+                    goal,
+                    Box::new(MaxIterations::new(50)),
+                    vec![(0.0, 10.0)],
+                ))
+            }),
+        );
+
+        assert!(meta.find_min_with_budget(10).is_some());
+        assert_eq!(meta.get_inner().runs, 3 * 10);
+    }
+
+    /// The outer optimizer checks single point and does not return a solution.
+    struct FailedOptimizer<'b> {
+        goal: Box<dyn Goal<Vec<f64>> + 'b>,
+    }
+
+    impl<'b> Optimizer<Vec<f64>> for FailedOptimizer<'b> {
+        fn find_min(&mut self) -> Option<Solution<Vec<f64>>> {
+            self.goal.get(&vec![9.0]);
+            None
+        }
+    }
+
+    #[test]
+    fn meta_optimizer_restore_params() {
+        let inner = ParamOptimizer { step: 5.0, runs: 0 };
+        let mut meta = MetaOptimizer::new(
+            inner,
+            vec![("step".to_string(), (0.0, 10.0))],
+            2,
+            Box::new(|goal| Box::new(FailedOptimizer { goal })),
+        );
+
+        assert!(meta.find_min().is_none());
+        assert_eq!(meta.get_inner().runs, 2);
+        assert_eq!(meta.get_inner().step, 5.0);
+
+        assert!(meta.find_min_with_budget(10).is_none());
+        assert_eq!(meta.get_inner().runs, 4);
+        assert_eq!(meta.get_inner().step, 5.0);
+    }
+
+    #[test]
+    fn meta_optimizer_params_bounds() {
+        let meta = MetaOptimizer::new(
+            ParamOptimizer { step: 5.0, runs: 0 },
+            vec![
+                ("step".to_string(), (0.0, 10.0)),
+                ("other".to_string(), (-1.0, 1.0)),
+            ],
+            1,
+            Box::new(|goal| {
+                Box::new(RandomSearchOptimizer::new(
+                    goal,
+                    Box::new(MaxIterations::new(1)),
+                    vec![(0.0, 10.0), (-1.0, 1.0)],
+                ))
+            }),
+        );
+
+        let params = meta.to_params(&[20.0, 0.5]);
+        assert_eq!(params.len(), 2);
+        assert_eq!(params["step"], 10.0);
+        assert_eq!(params["other"], 0.5);
+        assert_eq!(meta.to_params(&[-1.0, -2.0])["other"], -1.0);
+    }
 }
@@ -0,0 +1,636 @@
+//! The module with the structs to run several optimizers together.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "parallel")]
+use crate::tools::logging::Logger;
+#[cfg(feature = "parallel")]
+use crate::tools::statistics::{Statistics, StatisticsLogger};
+use crate::{Objective, Optimizer, Solution};
+
+/// The injection closure for `SequentialOptimizer`.
+/// The closure accepts result of the first optimizer and must return the second optimizer.
+pub type Injection<'a, T> = dyn FnMut(Option<&Solution<T>>) -> Box<dyn Optimizer<T> + 'a> + 'a;
+
+/// The optimizer runs the first optimizer to completion, then runs the second optimizer which
+/// seeded with the best solution of the first one. For example, particle swarm optimization may
+/// be used to find the basin of the global minimum and genetic algorithm to refine the solution.
+///
+/// The second optimizer is created by the injection closure. The closure accepts the result of
+/// the first optimizer (`None` if the first optimizer did not find a solution) and must return
+/// the second optimizer with the solution added to its creator or initializer.
+/// The closure is called for every `find_min()` call.
+///
+/// The optimizers must search minimum of the goal function by default. Use
+/// `SequentialOptimizer::objective()` if the optimizers search maximum.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct SequentialOptimizer<'a, T> {
+    first: Box<dyn Optimizer<T> + 'a>,
+    injection: Box<Injection<'a, T>>,
+    objective: Objective,
+}
+
+impl<'a, T> SequentialOptimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `first` - the optimizer to run first.
+    /// * `injection` - the closure to create the second optimizer by result of the first
+    ///   optimizer.
+    pub fn new(first: Box<dyn Optimizer<T> + 'a>, injection: Box<Injection<'a, T>>) -> Self {
+        Self {
+            first,
+            injection,
+            objective: Objective::Minimize,
+        }
+    }
+
+    /// Set the objective of the optimizers to select the best solution.
+    pub fn objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+}
+
+impl<'a, T> Optimizer<T> for SequentialOptimizer<'a, T> {
+    /// Returns the best solution of the two optimizers.
+    fn find_min(&mut self) -> Option<Solution<T>> {
+        let first_result = self.first.find_min();
+        let mut second = (self.injection)(first_result.as_ref());
+        let second_result = second.find_min();
+
+        best_solution(first_result, second_result, self.objective)
+    }
+
+    /// The limit is applied to every optimizer.
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<T>> {
+        let first_result = self.first.find_min_with_budget(max_iterations);
+        let mut second = (self.injection)(first_result.as_ref());
+        let second_result = second.find_min_with_budget(max_iterations);
+
+        best_solution(first_result, second_result, self.objective)
+    }
+}
+
+/// The runner runs an optimizer many times in parallel (with the rayon thread pool) and collects
+/// results and convergence of all runnings to `Statistics`.
+///
+/// A new optimizer is created by the `make_optimizer` closure for every running. The closure
+/// accepts a logger which collects statistics for the running and must add the logger to the
+/// optimizer (for example, with `GeneticOptimizer::set_loggers()` or
+/// `ParticleSwarmOptimizer::set_loggers()`). The logger borrows local statistics of the running,
+/// so the closure returns the optimizer as a boxed trait object with the lifetime of the logger.
+///
+/// The struct is available with the `parallel` feature only.
+///
+/// # Examples
+///
+/// ```
+/// use optlib::particleswarm::{initializing, velocitycalc, ParticleSwarmOptimizer};
+/// use optlib::tools::runners::MultiStartRunner;
+/// use optlib::tools::stopchecker::MaxIterations;
+/// use optlib::{GoalFromFunction, Optimizer};
+///
+/// let intervals = vec![(-1.0, 1.0); 2];
+/// let runner = MultiStartRunner::new();
+///
+/// let statistics = runner.run(
+///     |logger| {
+///         let mut optimizer = ParticleSwarmOptimizer::new(
+///             Box::new(GoalFromFunction::new(optlib_testfunc::paraboloid)),
+///             Box::new(MaxIterations::new(10)),
+///             Box::new(initializing::RandomCoordinatesInitializer::new(intervals.clone(), 10)),
+///             Box::new(initializing::ZeroVelocityInitializer::new(2, 10)),
+///             Box::new(velocitycalc::ClassicVelocityCalculator::new(0.5, 0.5)),
+///         );
+///         optimizer.set_loggers(vec![logger]);
+///         Box::new(optimizer) as Box<dyn Optimizer<Vec<f64>>>
+///     },
+///     20,
+/// );
+///
+/// assert_eq!(statistics.get_run_count(), 20);
+/// ```
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+pub struct MultiStartRunner {
+    // The iterations limit for every running
+    max_iterations: Option<usize>,
+}
+
+#[cfg(feature = "parallel")]
+impl MultiStartRunner {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            max_iterations: None,
+        }
+    }
+
+    /// Limit iterations of every running. The optimizers are run by
+    /// `Optimizer::find_min_with_budget()` instead of `Optimizer::find_min()`.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Run the optimizers and return united statistics of all runnings.
+    /// Runnings in the statistics are ordered by running number.
+    ///
+    /// # Parameters
+    /// * `make_optimizer` - the closure to create an optimizer with the statistics logger.
+    /// * `runs` - count of the runnings.
+    pub fn run<T, F>(&self, make_optimizer: F, runs: usize) -> Statistics<T>
+    where
+        T: Clone + Send,
+        F: for<'b> Fn(Box<dyn Logger<T> + 'b>) -> Box<dyn Optimizer<T> + 'b> + Sync,
+    {
+        (0..runs)
+            .into_par_iter()
+            .map(|_| {
+                let mut statistics = Statistics::new();
+                {
+                    let logger = Box::new(StatisticsLogger::new(&mut statistics));
+                    let mut optimizer = make_optimizer(logger);
+                    match self.max_iterations {
+                        None => optimizer.find_min(),
+                        Some(max_iterations) => optimizer.find_min_with_budget(max_iterations),
+                    };
+                }
+                statistics
+            })
+            .reduce(Statistics::new, |mut statistics, other| {
+                statistics.unite(other);
+                statistics
+            })
+    }
+}
+
+/// The optimizer runs several optimizers (for example, genetic algorithm and particle swarm
+/// optimization with different settings) one after another and returns the best solution.
+/// Results of all optimizers are stored in the report with labels of the optimizers
+/// (see `BestOfOptimizers::get_report()`).
+///
+/// The optimizers of the library are not `Send`, so the optimizers are run in the current
+/// thread. Use `MultiStartRunner` to run an optimizer in parallel.
+///
+/// The optimizers must search minimum of the goal function by default. Use
+/// `BestOfOptimizers::objective()` if the optimizers search maximum.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct BestOfOptimizers<'a, T> {
+    optimizers: Vec<(String, Box<dyn Optimizer<T> + 'a>)>,
+    report: Vec<(String, Option<Solution<T>>)>,
+    objective: Objective,
+}
+
+impl<'a, T> BestOfOptimizers<'a, T> {
+    /// Constructor. The optimizers are added by `BestOfOptimizers::add()`.
+    pub fn new() -> Self {
+        Self {
+            optimizers: vec![],
+            report: vec![],
+            objective: Objective::Minimize,
+        }
+    }
+
+    /// Set the objective of the optimizers to select the best solution.
+    pub fn objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Add the optimizer with the label for the report.
+    pub fn add(mut self, label: &str, optimizer: Box<dyn Optimizer<T> + 'a>) -> Self {
+        self.optimizers.push((label.to_string(), optimizer));
+        self
+    }
+
+    /// Returns the labels and results of all optimizers for the last `find_min()` call in the
+    /// order of adding.
+    pub fn get_report(&self) -> &Vec<(String, Option<Solution<T>>)> {
+        &self.report
+    }
+}
+
+impl<'a, T: Clone> BestOfOptimizers<'a, T> {
+    /// Run all optimizers with the iterations limit if `max_iterations` is not None.
+    fn run(&mut self, max_iterations: Option<usize>) -> Option<Solution<T>> {
+        self.report = self
+            .optimizers
+            .iter_mut()
+            .map(|(label, optimizer)| {
+                let result = match max_iterations {
+                    None => optimizer.find_min(),
+                    Some(max_iterations) => optimizer.find_min_with_budget(max_iterations),
+                };
+                (label.clone(), result)
+            })
+            .collect();
+
+        let objective = self.objective;
+        self.report.iter().fold(None, |best, (_, result)| {
+            best_solution(best, result.clone(), objective)
+        })
+    }
+}
+
+impl<'a, T> Default for BestOfOptimizers<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: Clone> Optimizer<T> for BestOfOptimizers<'a, T> {
+    /// Returns the best solution of all optimizers.
+    fn find_min(&mut self) -> Option<Solution<T>> {
+        self.run(None)
+    }
+
+    /// The limit is applied to every optimizer.
+    fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<T>> {
+        self.run(Some(max_iterations))
+    }
+}
+
+/// Returns the best solution for the `objective`. NaN value is worse than others.
+fn best_solution<T>(
+    solution_1: Option<Solution<T>>,
+    solution_2: Option<Solution<T>>,
+    objective: Objective,
+) -> Option<Solution<T>> {
+    match (solution_1, solution_2) {
+        (None, solution) => solution,
+        (solution, None) => solution,
+        (Some(solution_1), Some(solution_2)) => {
+            if objective.is_better(solution_2.1, solution_1.1) {
+                Some(solution_2)
+            } else {
+                Some(solution_1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "parallel")]
+    use crate::AlgorithmState;
+
+    /// The optimizer returns preset solution.
+    struct StubOptimizer {
+        result: Option<Solution<f64>>,
+    }
+
+    impl Optimizer<f64> for StubOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.result
+        }
+    }
+
+    /// The optimizer improves the seed solution.
+    struct RefineOptimizer {
+        seed: f64,
+    }
+
+    impl Optimizer<f64> for RefineOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            let x = self.seed / 2.0;
+            Some((x, x * x))
+        }
+    }
+
+    /// The optimizer makes preset count of iterations and returns the count of the done
+    /// iterations as the solution and the goal.
+    struct CountOptimizer {
+        iterations: usize,
+    }
+
+    impl Optimizer<f64> for CountOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.find_min_with_budget(self.iterations)
+        }
+
+        fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<f64>> {
+            let count = self.iterations.min(max_iterations) as f64;
+            Some((count, count))
+        }
+    }
+
+    #[test]
+    fn sequential_second_better() {
+        let coarse = StubOptimizer {
+            result: Some((1.0, 1.0)),
+        };
+        let mut seeds = vec![];
+
+        let result = {
+            let mut optimizer = SequentialOptimizer::new(
+                Box::new(coarse),
+                Box::new(|solution: Option<&Solution<f64>>| {
+                    let seed = solution.unwrap().0;
+                    seeds.push(seed);
+                    Box::new(RefineOptimizer { seed }) as Box<dyn Optimizer<f64>>
+                }),
+            );
+            optimizer.find_min()
+        };
+
+        assert_eq!(seeds, vec![1.0]);
+        assert_eq!(result, Some((0.5, 0.25)));
+    }
+
+    #[test]
+    fn sequential_first_better() {
+        let coarse = StubOptimizer {
+            result: Some((1.0, 0.1)),
+        };
+        let mut optimizer = SequentialOptimizer::new(
+            Box::new(coarse),
+            Box::new(|_solution: Option<&Solution<f64>>| {
+                Box::new(StubOptimizer {
+                    result: Some((2.0, 4.0)),
+                }) as Box<dyn Optimizer<f64>>
+            }),
+        );
+
+        assert_eq!(optimizer.find_min(), Some((1.0, 0.1)));
+    }
+
+    #[test]
+    fn sequential_first_failed() {
+        let coarse = StubOptimizer { result: None };
+        let mut optimizer = SequentialOptimizer::new(
+            Box::new(coarse),
+            Box::new(|solution: Option<&Solution<f64>>| {
+                assert!(solution.is_none());
+                Box::new(StubOptimizer {
+                    result: Some((2.0, 4.0)),
+                }) as Box<dyn Optimizer<f64>>
+            }),
+        );
+
+        assert_eq!(optimizer.find_min(), Some((2.0, 4.0)));
+    }
+
+    #[test]
+    fn best_solution_nan() {
+        let objective = Objective::Minimize;
+        assert_eq!(
+            best_solution(Some((1.0, f64::NAN)), Some((2.0, 4.0)), objective),
+            Some((2.0, 4.0))
+        );
+        assert_eq!(
+            best_solution(Some((1.0, 3.0)), Some((2.0, f64::NAN)), objective),
+            Some((1.0, 3.0))
+        );
+        assert_eq!(best_solution::<f64>(None, None, objective), None);
+    }
+
+    #[test]
+    fn best_solution_maximize() {
+        let objective = Objective::Maximize;
+        assert_eq!(
+            best_solution(Some((1.0, 3.0)), Some((2.0, 4.0)), objective),
+            Some((2.0, 4.0))
+        );
+        assert_eq!(
+            best_solution(Some((1.0, 5.0)), Some((2.0, 4.0)), objective),
+            Some((1.0, 5.0))
+        );
+        assert_eq!(
+            best_solution(Some((1.0, f64::NAN)), Some((2.0, -4.0)), objective),
+            Some((2.0, -4.0))
+        );
+        assert_eq!(
+            best_solution(Some((1.0, 3.0)), Some((2.0, f64::NAN)), objective),
+            Some((1.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn sequential_maximize() {
+        let mut optimizer = SequentialOptimizer::new(
+            Box::new(StubOptimizer {
+                result: Some((1.0, 1.0)),
+            }),
+            Box::new(|_solution: Option<&Solution<f64>>| {
+                Box::new(StubOptimizer {
+                    result: Some((2.0, 4.0)),
+                }) as Box<dyn Optimizer<f64>>
+            }),
+        )
+        .objective(Objective::Maximize);
+
+        assert_eq!(optimizer.find_min(), Some((2.0, 4.0)));
+    }
+
+    #[cfg(feature = "parallel")]
+    struct StateStub {
+        solution: Solution<f64>,
+        iteration: usize,
+    }
+
+    #[cfg(feature = "parallel")]
+    impl AlgorithmState<f64> for StateStub {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            Some(self.solution)
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    /// The optimizer makes preset count of iterations and calls the logger.
+    struct LoggingOptimizer<'a> {
+        logger: Box<dyn Logger<f64> + 'a>,
+        iterations: usize,
+    }
+
+    #[cfg(feature = "parallel")]
+    impl<'a> Optimizer<f64> for LoggingOptimizer<'a> {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.find_min_with_budget(self.iterations)
+        }
+
+        fn find_min_with_budget(&mut self, max_iterations: usize) -> Option<Solution<f64>> {
+            let mut state = StateStub {
+                solution: (1.0, 10.0),
+                iteration: 0,
+            };
+
+            self.logger.start(&state);
+            self.logger.resume(&state);
+            for n in 1..=self.iterations.min(max_iterations) {
+                state.iteration = n;
+                state.solution = (1.0, 10.0 - n as f64);
+                self.logger.next_iteration(&state);
+            }
+            self.logger.finish(&state);
+
+            Some(state.solution)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn multi_start() {
+        let runner = MultiStartRunner::new();
+        let statistics = runner.run(
+            |logger| {
+                Box::new(LoggingOptimizer {
+                    logger,
+                    iterations: 3,
+                })
+            },
+            50,
+        );
+
+        assert_eq!(statistics.get_run_count(), 50);
+        assert_eq!(statistics.get_convergence().len(), 50);
+        for (result, convergence) in statistics
+            .get_results()
+            .iter()
+            .zip(statistics.get_convergence())
+        {
+            assert_eq!(*result, Some((1.0, 7.0)));
+            assert_eq!(
+                convergence,
+                &vec![Some((1.0, 9.0)), Some((1.0, 8.0)), Some((1.0, 7.0))]
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn multi_start_budget() {
+        let runner = MultiStartRunner::new().max_iterations(2);
+        let statistics = runner.run(
+            |logger| {
+                Box::new(LoggingOptimizer {
+                    logger,
+                    iterations: 3,
+                })
+            },
+            10,
+        );
+
+        assert_eq!(statistics.get_run_count(), 10);
+        for convergence in statistics.get_convergence() {
+            assert_eq!(convergence, &vec![Some((1.0, 9.0)), Some((1.0, 8.0))]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn multi_start_zero() {
+        let runner = MultiStartRunner::new();
+        let statistics = runner.run(
+            |logger| {
+                Box::new(LoggingOptimizer {
+                    logger,
+                    iterations: 3,
+                })
+            },
+            0,
+        );
+
+        assert_eq!(statistics.get_run_count(), 0);
+    }
+
+    #[test]
+    fn best_of_optimizers() {
+        let mut optimizer = BestOfOptimizers::new()
+            .add(
+                "first",
+                Box::new(StubOptimizer {
+                    result: Some((1.0, 3.0)),
+                }),
+            )
+            .add(
+                "second",
+                Box::new(StubOptimizer {
+                    result: Some((2.0, 1.0)),
+                }),
+            )
+            .add("third", Box::new(StubOptimizer { result: None }));
+        assert!(optimizer.get_report().is_empty());
+
+        assert_eq!(optimizer.find_min(), Some((2.0, 1.0)));
+        assert_eq!(
+            optimizer.get_report(),
+            &vec![
+                ("first".to_string(), Some((1.0, 3.0))),
+                ("second".to_string(), Some((2.0, 1.0))),
+                ("third".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn best_of_optimizers_maximize() {
+        let mut optimizer = BestOfOptimizers::new()
+            .objective(Objective::Maximize)
+            .add(
+                "first",
+                Box::new(StubOptimizer {
+                    result: Some((1.0, 3.0)),
+                }),
+            )
+            .add(
+                "second",
+                Box::new(StubOptimizer {
+                    result: Some((2.0, 1.0)),
+                }),
+            );
+
+        assert_eq!(optimizer.find_min(), Some((1.0, 3.0)));
+    }
+
+    #[test]
+    fn best_of_optimizers_budget() {
+        let mut optimizer = BestOfOptimizers::new()
+            .add("long", Box::new(CountOptimizer { iterations: 10 }))
+            .add("short", Box::new(CountOptimizer { iterations: 3 }));
+
+        assert_eq!(optimizer.find_min_with_budget(5), Some((3.0, 3.0)));
+        assert_eq!(
+            optimizer.get_report(),
+            &vec![
+                ("long".to_string(), Some((5.0, 5.0))),
+                ("short".to_string(), Some((3.0, 3.0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn sequential_budget() {
+        let mut seeds = vec![];
+
+        let result = {
+            let mut optimizer = SequentialOptimizer::new(
+                Box::new(CountOptimizer { iterations: 10 }),
+                Box::new(|solution: Option<&Solution<f64>>| {
+                    seeds.push(solution.unwrap().0);
+                    Box::new(CountOptimizer { iterations: 2 }) as Box<dyn Optimizer<f64>>
+                }),
+            );
+            optimizer.find_min_with_budget(4)
+        };
+
+        assert_eq!(seeds, vec![4.0]);
+        assert_eq!(result, Some((2.0, 2.0)));
+    }
+
+    #[test]
+    fn best_of_optimizers_empty() {
+        let mut optimizer: BestOfOptimizers<f64> = BestOfOptimizers::new();
+        assert_eq!(optimizer.find_min(), None);
+        assert!(optimizer.get_report().is_empty());
+    }
+}
@@ -1,6 +1,11 @@
 //! The module with the loggers ready for using. The loggers implements the `Logger` trait.
 
+use std::fmt::Display;
+use std::io;
+
 use num::Float;
+use rand::rngs::ThreadRng;
+use rand::Rng;
 
 use crate::{tools::logging::Logger, AlgorithmState, Goal, GoalValue, Solution};
 
@@ -35,12 +40,100 @@ pub trait StatFunctionsConvergence {
     /// Calculate average goal function versus iteration number.
     /// Average by run count.
     /// Returns vector with: index - iteration, value - Option<GoalValue>.
-    /// Value is None if Solution is None for any running.
+    /// The runnings with None solution for the iteration are ignored, value is None if Solution
+    /// is None for all runnings.
     /// Length of result is minimal iterations count for all running.
     /// # Params
     /// self[run number][iteration]
     fn get_average_convergence(&self) -> Vec<Option<GoalValue>>;
     fn get_min_iterations(&self) -> usize;
+
+    /// Find the first iteration where the goal function less or equal than `threshold`
+    /// for every running.
+    /// Returns vector with: index - run number, value - iteration number or None if the running
+    /// did not reach the threshold.
+    fn get_iterations_to_threshold<T>(&self, threshold: GoalValue) -> Vec<Option<usize>>
+    where
+        Self: AsRef<[Vec<Option<Solution<T>>>]> + Sized,
+    {
+        self.as_ref()
+            .iter()
+            .map(|run| {
+                run.iter().position(|solution| match solution {
+                    None => false,
+                    Some((_, goal)) => *goal <= threshold,
+                })
+            })
+            .collect()
+    }
+
+    /// Calculate average iterations count to reach the `threshold` by the runnings which reached
+    /// the threshold.
+    /// Returns None if no running reached the threshold.
+    fn get_average_iterations_to_threshold<T>(&self, threshold: GoalValue) -> Option<f64>
+    where
+        Self: AsRef<[Vec<Option<Solution<T>>>]> + Sized,
+    {
+        let iterations: Vec<usize> = self
+            .get_iterations_to_threshold(threshold)
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if iterations.is_empty() {
+            None
+        } else {
+            let sum: usize = iterations.iter().sum();
+            Some((sum as f64) / (iterations.len() as f64))
+        }
+    }
+
+    /// Calculate average goal function versus iteration number as `get_average_convergence()`,
+    /// but the worst `trim_fraction` of the runnings (with the greatest goal function, NaN is
+    /// the worst) are dropped for every iteration before averaging.
+    ///
+    /// The missing values are handled as by `get_average_convergence()` of `Convergence<T>`:
+    /// length of result is minimal iterations count for all runnings and the runnings with None
+    /// solution for the iteration are ignored. Count of dropped runnings is calculated from the
+    /// runnings with solution for the iteration and is rounded down.
+    /// Value is None if no running remains for the iteration.
+    /// # Params
+    /// `trim_fraction` - must be in [0; 1).
+    fn get_trimmed_average_convergence<T>(&self, trim_fraction: f64) -> Vec<Option<GoalValue>>
+    where
+        Self: AsRef<[Vec<Option<Solution<T>>>]> + Sized,
+    {
+        assert!((0.0..1.0).contains(&trim_fraction));
+
+        let convergence = self.as_ref();
+        let min_iterations = convergence.iter().map(|run| run.len()).min().unwrap_or(0);
+        let mut result = Vec::with_capacity(min_iterations);
+
+        for i in 0..min_iterations {
+            let mut goals: Vec<GoalValue> = convergence
+                .iter()
+                .filter_map(|run| run[i].as_ref().map(|(_, goal)| *goal))
+                .collect();
+
+            // NaN is the worst value
+            goals.sort_by(|goal_1, goal_2| match (goal_1.is_nan(), goal_2.is_nan()) {
+                (false, false) => goal_1.partial_cmp(goal_2).unwrap(),
+                (nan_1, nan_2) => nan_1.cmp(&nan_2),
+            });
+
+            let trim_count = (goals.len() as f64 * trim_fraction).floor() as usize;
+            let retained = &goals[..goals.len() - trim_count];
+
+            if retained.is_empty() {
+                result.push(None);
+            } else {
+                let sum: GoalValue = retained.iter().sum();
+                result.push(Some(sum / (retained.len() as GoalValue)));
+            }
+        }
+
+        result
+    }
 }
 
 /// The trait contains methods for calculate goal function statistics for Vec<Option<Solution<T>>>
@@ -102,6 +195,94 @@ pub fn get_predicate_success_vec_solution<T: Float>(
     }
 }
 
+/// Calculate the success performance (SP1): average call count of the goal function for the
+/// successful runnings divided by the success rate.
+/// Returns None if no running succeeded.
+/// # Params
+/// `results` - final solution for every running.
+/// `call_counts` - call count of the goal function for every running. Count of the runnings must
+/// be equal to length of `results`.
+/// `predicate` - function must return true for success solution and false otherwise.
+pub fn success_performance<T, P>(
+    results: &[Option<Solution<T>>],
+    call_counts: &CallCountData,
+    predicate: P,
+) -> Option<f64>
+where
+    P: Fn(&Solution<T>) -> bool,
+{
+    assert_eq!(results.len(), call_counts.0.len());
+
+    let success_calls: Vec<usize> = results
+        .iter()
+        .zip(call_counts.0.iter())
+        .filter(|(result, _)| matches!(result, Some(solution) if predicate(solution)))
+        .map(|(_, calls)| *calls)
+        .collect();
+
+    if success_calls.is_empty() {
+        return None;
+    }
+
+    let success_count = success_calls.len() as f64;
+    let average_calls = success_calls.iter().sum::<usize>() as f64 / success_count;
+    let success_rate = success_count / results.len() as f64;
+    Some(average_calls / success_rate)
+}
+
+/// Pair the final solution and call count of the goal function for every running.
+/// # Params
+/// `results` - final solution for every running.
+/// `call_count` - call count of the goal function for every running. Count of the runnings must
+/// be equal to length of `results`.
+pub fn zip_results_with_calls<T: Clone>(
+    results: &[Option<Solution<T>>],
+    call_count: &CallCountData,
+) -> Vec<(Option<Solution<T>>, usize)> {
+    assert_eq!(
+        results.len(),
+        call_count.0.len(),
+        "Count of the results is not equal to count of the runnings in the call count data"
+    );
+
+    results
+        .iter()
+        .cloned()
+        .zip(call_count.0.iter().cloned())
+        .collect()
+}
+
+/// Calculate average goal function versus iteration number as
+/// `StatFunctionsConvergence::get_average_convergence()`, but the runnings shorter than `length`
+/// are padded by the last value (carry-forward), and the longer runnings are truncated.
+/// The padding assumes the running terminated early because the algorithm already converged, so
+/// the last value of goal function is the value for the later iterations.
+/// Returns vector with length `length`: index - iteration, value - Option<GoalValue>.
+/// Value is None if Solution is None for all runnings. The empty runnings are ignored.
+/// # Params
+/// `convergence` - convergence[run number][iteration].
+/// `length` - iterations count in the result.
+pub fn pad_convergence<T>(
+    convergence: &[Vec<Option<Solution<T>>>],
+    length: usize,
+) -> Vec<Option<GoalValue>> {
+    (0..length)
+        .map(|i| {
+            let goals: Vec<GoalValue> = convergence
+                .iter()
+                .filter_map(|run| run.get(i).or_else(|| run.last()))
+                .filter_map(|solution| solution.as_ref().map(|(_, goal)| *goal))
+                .collect();
+
+            if goals.is_empty() {
+                None
+            } else {
+                Some(goals.iter().sum::<GoalValue>() / (goals.len() as GoalValue))
+            }
+        })
+        .collect()
+}
+
 impl CallCountData {
     pub fn new() -> Self {
         Self(vec![])
@@ -171,6 +352,16 @@ impl<T: Clone> Statistics<T> {
         &self.convergence
     }
 
+    /// Returns the best point for every iteration of the running with number `run` (without
+    /// values of the goal function). The item is None if the algorithm has no solution for the
+    /// iteration. The method panics if `run` is not less than count of the runnings.
+    pub fn get_best_trajectory(&self, run: usize) -> Vec<Option<T>> {
+        self.convergence[run]
+            .iter()
+            .map(|solution| solution.as_ref().map(|(point, _)| point.clone()))
+            .collect()
+    }
+
     fn add_result(&mut self, state: &dyn AlgorithmState<T>) {
         self.results.push(state.get_best_solution().clone());
     }
@@ -190,7 +381,8 @@ impl<T> StatFunctionsConvergence for Convergence<T> {
     /// Calculate average goal function versus iteration number.
     /// Average by run count.
     /// Returns vector with: index - iteration, value - Option<GoalValue>.
-    /// Value is None if Solution is None for any running.
+    /// The runnings with None solution for the iteration are ignored, value is None if Solution
+    /// is None for all runnings.
     /// Length of result is minimal iterations count for all running.
     /// # Params
     /// self[run number][iteration]
@@ -378,6 +570,116 @@ fn vectorize<T>(v1: &Vec<T>, v2: &Vec<T>, func: fn(&T, &T) -> T) -> Vec<T> {
     v1.iter().zip(v2.iter()).map(|(x, y)| func(x, y)).collect()
 }
 
+/// Write the running number, coordinates of the solution and value of the goal function in the
+/// single line. The running number is aligned by 8 chars, every coordinate and the goal value
+/// are aligned by 20 chars.
+///
+/// # Parameters
+/// * `run` - the running number.
+/// * `precision` - count of the digits after comma for float numbers.
+pub fn write_solution<T: Display>(
+    writer: &mut dyn io::Write,
+    run: usize,
+    solution: &Solution<Vec<T>>,
+    precision: usize,
+) -> io::Result<()> {
+    let (coordinates, goal) = solution;
+    let mut result = format!("{:<8}", run);
+    for x in coordinates {
+        result += &format!("  {:<20.*}", precision, x);
+    }
+    result += &format!("  {:20.*}", precision, goal);
+
+    writeln!(writer, "{}", result)
+}
+
+/// Write convergence of all runnings (see `Statistics::get_convergence()`) as the table for
+/// gnuplot. Every row contains the iteration number, value of the goal function for every
+/// running, average and standard deviation of the goal function by the runnings. Count of the
+/// rows is minimal iterations count for all runnings (see
+/// `StatFunctionsConvergence::get_min_iterations()`).
+///
+/// Missing values (the running has not found a solution, average and standard deviation if the
+/// values are not enough) are written as "nan", so gnuplot skips them.
+pub fn export_convergence_matrix<T>(
+    writer: &mut dyn io::Write,
+    convergence: &Convergence<T>,
+) -> io::Result<()> {
+    for i in 0..convergence.get_min_iterations() {
+        let goals: Vec<Option<GoalValue>> = convergence
+            .iter()
+            .map(|run| run[i].as_ref().map(|(_, goal)| *goal))
+            .collect();
+
+        let values: Vec<GoalValue> = goals.iter().filter_map(|goal| *goal).collect();
+        let count = values.len();
+
+        let average = match count {
+            0 => None,
+            _ => Some(values.iter().sum::<GoalValue>() / count as GoalValue),
+        };
+
+        let deviation = match (average, count) {
+            (Some(average), count) if count >= 2 => {
+                let sum = values
+                    .iter()
+                    .fold(0.0, |acc, goal| acc + (goal - average) * (goal - average));
+                Some((sum / (count - 1) as GoalValue).sqrt())
+            }
+            _ => None,
+        };
+
+        let mut row = format!("{:<8}", i);
+        for goal in goals.iter().chain([average, deviation].iter()) {
+            match goal {
+                Some(value) => row += &format!("  {:17.10e}", value),
+                None => row += &format!("  {:>17}", "nan"),
+            }
+        }
+
+        writeln!(writer, "{}", row)?;
+    }
+
+    Ok(())
+}
+
+/// Write results of all runnings (see `Statistics::get_results()`). Every line begins with
+/// the running number and contains the solution in the format of `write_solution()` or
+/// "Failed" marker if the running did not find a solution.
+///
+/// # Parameters
+/// * `precision` - count of the digits after comma for float numbers.
+/// * `header` - write the header row with names of columns ("Run", "x0", "x1", ..., "Goal").
+pub fn write_results<T: Display>(
+    writer: &mut dyn io::Write,
+    results: &[Option<Solution<Vec<T>>>],
+    precision: usize,
+    header: bool,
+) -> io::Result<()> {
+    if header {
+        let dimension = results
+            .iter()
+            .find_map(|result| result.as_ref().map(|(coordinates, _)| coordinates.len()))
+            .unwrap_or(0);
+
+        let mut header_str = format!("{:<8}", "Run");
+        for i in 0..dimension {
+            header_str += &format!("  {:<20}", format!("x{}", i));
+        }
+        header_str += &format!("  {:>20}", "Goal");
+        writeln!(writer, "{}", header_str)?;
+    }
+
+    for (n, result) in results.iter().enumerate() {
+        match result {
+            Some(solution) => write_solution(writer, n, solution, precision)?,
+            None => writeln!(writer, "{:<8}  Failed", n)?,
+        }
+    }
+
+    Ok(())
+}
+
 pub struct StatisticsLogger<'a, T> {
     statistics: &'a mut Statistics<T>,
 }
@@ -408,6 +710,125 @@ impl<'a, T: Clone> Logger<T> for StatisticsLogger<'a, T> {
     }
 }
 
+/// The `AgentsStatistics` struct stores values of goal function for all agents for every
+/// algorithm running and every iteration.
+///
+/// The struct stores (run count * iterations count * agents count) values, so it may require a
+/// lot of memory for long runnings or large populations.
+#[derive(Debug, Clone, Default)]
+pub struct AgentsStatistics {
+    /// goals[run number][iteration][agent number]
+    goals: Vec<Vec<Vec<GoalValue>>>,
+}
+
+impl AgentsStatistics {
+    pub fn new() -> Self {
+        Self { goals: vec![] }
+    }
+
+    pub fn get_run_count(&self) -> usize {
+        self.goals.len()
+    }
+
+    /// Returns values of goal function for all agents. goals[run number][iteration][agent number]
+    pub fn get_agents_goals(&self) -> &Vec<Vec<Vec<GoalValue>>> {
+        &self.goals
+    }
+
+    /// The agents are ignored if the running is not started.
+    fn add_agents<T>(&mut self, state: &dyn AlgorithmState<T>) {
+        if let (Some(agents), Some(run)) = (state.get_agent_list(), self.goals.last_mut()) {
+            let goals = agents.iter().map(|agent| agent.get_goal()).collect();
+            run.push(goals);
+        }
+    }
+
+    pub fn unite(&mut self, mut other: Self) {
+        self.goals.append(&mut other.goals);
+    }
+}
+
+/// The logger to gather values of goal function for all agents on every iteration.
+/// The algorithm state must return agents by `AlgorithmState::get_agent_list()`.
+pub struct AgentsStatisticsLogger<'a> {
+    statistics: &'a mut AgentsStatistics,
+}
+
+impl<'a> AgentsStatisticsLogger<'a> {
+    pub fn new(statistics: &'a mut AgentsStatistics) -> Self {
+        Self { statistics }
+    }
+}
+
+impl<'a, T> Logger<T> for AgentsStatisticsLogger<'a> {
+    fn start(&mut self, _state: &dyn AlgorithmState<T>) {
+        self.statistics.goals.push(vec![]);
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        self.statistics.add_agents(state);
+    }
+}
+
+/// Calculate diversity of the population as mean Euclidean distance of the agents from the
+/// centroid of the population. Returns 0 for the empty population.
+/// All agents must have the same dimension.
+pub fn population_diversity<G: Float>(agents: &[&Vec<G>]) -> f64 {
+    if agents.is_empty() {
+        return 0.0;
+    }
+
+    let dimension = agents[0].len();
+    let count = agents.len() as f64;
+    let mut centroid = vec![0.0; dimension];
+    for agent in agents {
+        assert_eq!(agent.len(), dimension);
+        for (center, x) in centroid.iter_mut().zip(agent.iter()) {
+            *center += x.to_f64().unwrap() / count;
+        }
+    }
+
+    agents
+        .iter()
+        .map(|agent| {
+            agent
+                .iter()
+                .zip(centroid.iter())
+                .map(|(x, center)| (x.to_f64().unwrap() - center).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        })
+        .sum::<f64>()
+        / count
+}
+
+/// The logger to calculate diversity of the population (see `population_diversity()`) on every
+/// iteration. The algorithm state must return agents by `AlgorithmState::get_agent_list()`.
+/// The diversity is stored to the vector: diversity[run number][iteration].
+pub struct DiversityLogger<'a> {
+    diversity: &'a mut Vec<Vec<f64>>,
+}
+
+impl<'a> DiversityLogger<'a> {
+    pub fn new(diversity: &'a mut Vec<Vec<f64>>) -> Self {
+        Self { diversity }
+    }
+}
+
+impl<'a, G: Float> Logger<Vec<G>> for DiversityLogger<'a> {
+    fn start(&mut self, _state: &dyn AlgorithmState<Vec<G>>) {
+        self.diversity.push(vec![]);
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<Vec<G>>) {
+        if let Some(agents) = state.get_agent_list() {
+            let points: Vec<&Vec<G>> = agents.iter().map(|agent| agent.get_parameter()).collect();
+            let run_index = self.diversity.len() - 1;
+            self.diversity[run_index].push(population_diversity(&points));
+        }
+    }
+}
+
 impl<'a, T> GoalCalcStatistics<'a, T> {
     pub fn new(goal: &'a mut dyn Goal<T>, call_count: &'a mut CallCountData) -> Self {
         Self { goal, call_count }
@@ -419,11 +840,371 @@ impl<'a, T> Goal<T> for GoalCalcStatistics<'a, T> {
         self.call_count.increment();
         self.goal.get(x)
     }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.goal.set_generation(generation);
+    }
+}
+
+/// The struct aggregates results of the runnings one by one without storing all results.
+/// The struct calculates count, mean and variance of the goal function values with the Welford's
+/// algorithm and stores a random sample (reservoir) of the goal function values to calculate
+/// approximate median. Required memory does not depend on count of the runnings.
+pub struct StreamingStats {
+    run_count: usize,
+    success_count: usize,
+    mean: f64,
+
+    /// Sum of squares of differences from the mean.
+    m2: f64,
+
+    reservoir: Vec<GoalValue>,
+    reservoir_size: usize,
+    random: ThreadRng,
+}
+
+impl StreamingStats {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `reservoir_size` - count of the goal function values to calculate approximate median.
+    ///   `reservoir_size` must be greater than 0.
+    pub fn new(reservoir_size: usize) -> Self {
+        assert!(reservoir_size > 0);
+        Self {
+            run_count: 0,
+            success_count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            reservoir: Vec::with_capacity(reservoir_size),
+            reservoir_size,
+            random: rand::thread_rng(),
+        }
+    }
+
+    /// Add result of a running. `None` means the running did not find a solution.
+    pub fn push<T>(&mut self, result: Option<Solution<T>>) {
+        self.run_count += 1;
+
+        if let Some((_, goal)) = result {
+            self.success_count += 1;
+
+            let delta = goal - self.mean;
+            self.mean += delta / (self.success_count as f64);
+            self.m2 += delta * (goal - self.mean);
+
+            if self.reservoir.len() < self.reservoir_size {
+                self.reservoir.push(goal);
+            } else {
+                let index = self.random.gen_range(0, self.success_count);
+                if index < self.reservoir_size {
+                    self.reservoir[index] = goal;
+                }
+            }
+        }
+    }
+
+    /// Returns count of the runnings.
+    pub fn get_run_count(&self) -> usize {
+        self.run_count
+    }
+
+    /// Returns count of the runnings which found a solution.
+    pub fn get_success_count(&self) -> usize {
+        self.success_count
+    }
+
+    /// Returns average of the goal function.
+    /// Returns None if there are not solutions.
+    pub fn mean(&self) -> Option<GoalValue> {
+        if self.success_count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Returns variance of the goal function (with `n - 1` in the denominator as
+    /// `StatFunctionsGoal::get_standard_deviation_goal()`).
+    /// Returns None if count of the solutions less 2.
+    pub fn variance(&self) -> Option<GoalValue> {
+        if self.success_count < 2 {
+            None
+        } else {
+            Some(self.m2 / ((self.success_count - 1) as f64))
+        }
+    }
+
+    /// Returns median of the goal function values in the reservoir. The median is exact if
+    /// count of the solutions is not greater than the reservoir size.
+    /// Returns None if there are not solutions.
+    pub fn approx_median(&self) -> Option<GoalValue> {
+        if self.reservoir.is_empty() {
+            return None;
+        }
+
+        let mut values = self.reservoir.clone();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let middle = values.len() / 2;
+        match values.len() % 2 {
+            0 => Some((values[middle - 1] + values[middle]) / 2.0),
+            _ => Some(values[middle]),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Agent;
+
+    struct AgentStub {
+        x: f64,
+        goal: GoalValue,
+    }
+
+    impl Agent<f64> for AgentStub {
+        fn get_parameter(&self) -> &f64 {
+            &self.x
+        }
+
+        fn get_goal(&self) -> GoalValue {
+            self.goal
+        }
+    }
+
+    struct AgentsStateStub {
+        agents: Vec<AgentStub>,
+        iteration: usize,
+    }
+
+    impl AlgorithmState<f64> for AgentsStateStub {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            None
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+
+        fn get_agent_list(&self) -> Option<Vec<&dyn Agent<f64>>> {
+            Some(self.agents.iter().map(|agent| agent as &dyn Agent<f64>).collect())
+        }
+    }
+
+    fn create_agents_state(goals: &[GoalValue], iteration: usize) -> AgentsStateStub {
+        AgentsStateStub {
+            agents: goals
+                .iter()
+                .map(|&goal| AgentStub { x: 0.0, goal })
+                .collect(),
+            iteration,
+        }
+    }
+
+    #[test]
+    fn agents_statistics_logger() {
+        let mut statistics = AgentsStatistics::new();
+        {
+            let mut logger = AgentsStatisticsLogger::new(&mut statistics);
+            let state_0 = create_agents_state(&[5.0, 4.0, 3.0], 0);
+            let state_1 = create_agents_state(&[3.0, 2.0, 1.0], 1);
+            let state_2 = create_agents_state(&[1.0, 0.5], 2);
+
+            logger.start(&state_0);
+            logger.next_iteration(&state_1);
+            logger.next_iteration(&state_2);
+            logger.finish(&state_2);
+
+            logger.start(&state_0);
+            logger.next_iteration(&state_2);
+            logger.finish(&state_2);
+        }
+
+        assert_eq!(statistics.get_run_count(), 2);
+        assert_eq!(
+            statistics.get_agents_goals(),
+            &vec![
+                vec![vec![3.0, 2.0, 1.0], vec![1.0, 0.5]],
+                vec![vec![1.0, 0.5]],
+            ]
+        );
+    }
+
+    #[test]
+    fn agents_statistics_without_start() {
+        let mut statistics = AgentsStatistics::new();
+        {
+            let mut logger = AgentsStatisticsLogger::new(&mut statistics);
+            let state = create_agents_state(&[1.0, 2.0], 1);
+            logger.next_iteration(&state);
+            logger.finish(&state);
+        }
+
+        assert_eq!(statistics.get_run_count(), 0);
+        assert!(statistics.get_agents_goals().is_empty());
+    }
+
+    #[test]
+    fn agents_statistics_unite() {
+        let mut statistics_1 = AgentsStatistics::new();
+        let mut statistics_2 = AgentsStatistics::new();
+        {
+            let state = create_agents_state(&[1.0, 2.0], 1);
+            let mut logger_1 = AgentsStatisticsLogger::new(&mut statistics_1);
+            logger_1.start(&state);
+            logger_1.next_iteration(&state);
+
+            let mut logger_2 = AgentsStatisticsLogger::new(&mut statistics_2);
+            logger_2.start(&state);
+        }
+
+        statistics_1.unite(statistics_2);
+        assert_eq!(
+            statistics_1.get_agents_goals(),
+            &vec![vec![vec![1.0, 2.0]], vec![]]
+        );
+    }
+
+    #[test]
+    fn population_diversity_collapsed() {
+        let point = vec![1.0, -2.0, 3.0];
+        let agents = vec![&point; 10];
+        assert!(population_diversity(&agents).abs() < 1e-12);
+    }
+
+    #[test]
+    fn population_diversity_spread() {
+        // The points on the circle with radius 2 around (1, 1)
+        let points = [
+            vec![3.0_f32, 1.0],
+            vec![-1.0, 1.0],
+            vec![1.0, 3.0],
+            vec![1.0, -1.0],
+        ];
+        let agents: Vec<&Vec<f32>> = points.iter().collect();
+        assert!((population_diversity(&agents) - 2.0).abs() < 1e-12);
+
+        // Distances from the centroid (0.5) are 0.5
+        let points = [vec![0.0], vec![1.0], vec![0.0], vec![1.0]];
+        let agents: Vec<&Vec<f64>> = points.iter().collect();
+        assert_eq!(population_diversity(&agents), 0.5);
+    }
+
+    #[test]
+    fn population_diversity_empty() {
+        let agents: Vec<&Vec<f64>> = vec![];
+        assert_eq!(population_diversity(&agents), 0.0);
+    }
+
+    #[test]
+    fn diversity_logger() {
+        use crate::particleswarm::initializing::{
+            RandomCoordinatesInitializer, ZeroVelocityInitializer,
+        };
+        use crate::particleswarm::velocitycalc::ClassicVelocityCalculator;
+        use crate::particleswarm::ParticleSwarmOptimizer;
+        use crate::tools::stopchecker::MaxIterations;
+        use crate::{GoalFromFunction, Optimizer};
+
+        let mut diversity = vec![];
+        for _ in 0..2 {
+            let mut optimizer = ParticleSwarmOptimizer::new(
+                Box::new(GoalFromFunction::new(|x: &Vec<f64>| {
+                    x[0] * x[0] + x[1] * x[1]
+                })),
+                Box::new(MaxIterations::new(10)),
+                Box::new(RandomCoordinatesInitializer::new(vec![(-1.0, 1.0); 2], 20)),
+                Box::new(ZeroVelocityInitializer::new(2, 20)),
+                Box::new(ClassicVelocityCalculator::new(0.5, 0.5)),
+            );
+            optimizer.set_loggers(vec![Box::new(DiversityLogger::new(&mut diversity))]);
+            optimizer.find_min();
+        }
+
+        assert_eq!(diversity.len(), 2);
+        for run in &diversity {
+            assert_eq!(run.len(), 10);
+            assert!(run.iter().all(|value| value.is_finite() && *value >= 0.0));
+        }
+    }
+
+    #[test]
+    fn agents_statistics_no_agents() {
+        struct StateStub;
+
+        impl AlgorithmState<f64> for StateStub {
+            fn get_best_solution(&self) -> Option<Solution<f64>> {
+                None
+            }
+
+            fn get_iteration(&self) -> usize {
+                0
+            }
+        }
+
+        let mut statistics = AgentsStatistics::new();
+        {
+            let mut logger = AgentsStatisticsLogger::new(&mut statistics);
+            logger.start(&StateStub);
+            logger.next_iteration(&StateStub);
+        }
+
+        assert_eq!(statistics.get_agents_goals(), &vec![Vec::<Vec<GoalValue>>::new()]);
+    }
+
+    #[test]
+    fn get_iterations_to_threshold_empty() {
+        let convergence: Convergence<f32> = vec![];
+        assert_eq!(convergence.get_iterations_to_threshold(1.0), vec![]);
+        assert_eq!(convergence.get_average_iterations_to_threshold(1.0), None);
+    }
+
+    #[test]
+    fn get_iterations_to_threshold_many() {
+        let convergence: Convergence<f32> = vec![
+            vec![
+                None,
+                Some((1_f32, 5_f64)),
+                Some((1_f32, 1_f64)),
+                Some((1_f32, 0.5_f64)),
+            ],
+            vec![Some((1_f32, 3_f64)), Some((1_f32, 2_f64))],
+            vec![Some((1_f32, 0.1_f64))],
+            vec![],
+            vec![
+                Some((1_f32, 4_f64)),
+                Some((1_f32, 3_f64)),
+                Some((1_f32, 2_f64)),
+                Some((1_f32, 1_f64)),
+                Some((1_f32, 0_f64)),
+            ],
+        ];
+
+        assert_eq!(
+            convergence.get_iterations_to_threshold(1.0),
+            vec![Some(2), None, Some(0), None, Some(3)]
+        );
+        assert_eq!(
+            convergence.get_average_iterations_to_threshold(1.0),
+            Some(5.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn get_iterations_to_threshold_none() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((1_f32, 5_f64)), Some((1_f32, 3_f64))],
+            vec![None, None],
+        ];
+
+        assert_eq!(
+            convergence.get_iterations_to_threshold(1.0),
+            vec![None, None]
+        );
+        assert_eq!(convergence.get_average_iterations_to_threshold(1.0), None);
+    }
 
     #[test]
     fn get_min_iterations_empty() {
@@ -552,6 +1333,94 @@ mod tests {
         assert_eq!(convergence.get_average_convergence(), result);
     }
 
+    #[test]
+    fn get_trimmed_average_convergence_outliers() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), Some((1_f32, 10_f64))],
+            vec![Some((3_f32, 1000_f64)), Some((1_f32, 500_f64))],
+            vec![Some((3_f32, 50_f64)), Some((1_f32, 20_f64))],
+            vec![Some((3_f32, 40_f64)), Some((1_f32, 30_f64))],
+            vec![Some((3_f32, 20_f64)), Some((1_f32, f64::NAN))],
+        ];
+
+        // One of five runnings is dropped for every iteration
+        let result = vec![Some(35_f64), Some(140_f64)];
+        assert_eq!(convergence.get_trimmed_average_convergence(0.2), result);
+
+        // Two of five runnings are dropped for every iteration
+        let result = vec![Some(30_f64), Some(20_f64)];
+        assert_eq!(convergence.get_trimmed_average_convergence(0.5), result);
+    }
+
+    #[test]
+    fn get_trimmed_average_convergence_zero() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), None, Some((1_f32, 10_f64))],
+            vec![Some((3_f32, 50_f64)), None, Some((1_f32, 30_f64))],
+        ];
+
+        assert_eq!(
+            convergence.get_trimmed_average_convergence(0.0),
+            convergence.get_average_convergence()
+        );
+    }
+
+    #[test]
+    fn get_trimmed_average_convergence_none() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), None],
+            vec![None, Some((1_f32, 30_f64))],
+        ];
+
+        // Single running for every iteration is not dropped
+        let result = vec![Some(30_f64), Some(30_f64)];
+        assert_eq!(convergence.get_trimmed_average_convergence(0.9), result);
+
+        let convergence: Convergence<f32> = vec![vec![None]];
+        assert_eq!(convergence.get_trimmed_average_convergence(0.5), vec![None]);
+
+        let convergence: Convergence<f32> = vec![];
+        assert_eq!(convergence.get_trimmed_average_convergence(0.5), vec![]);
+    }
+
+    #[test]
+    fn get_trimmed_average_convergence_different_length() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), None, Some((1_f32, 10_f64))],
+            vec![Some((3_f32, 900_f64)), Some((1_f32, 800_f64))],
+            vec![
+                Some((3_f32, 50_f64)),
+                Some((1_f32, 40_f64)),
+                Some((1_f32, 5_f64)),
+                Some((1_f32, 1_f64)),
+            ],
+            vec![None, Some((1_f32, 20_f64)), Some((1_f32, 15_f64))],
+        ];
+
+        // The result is truncated to the shortest running and None is ignored
+        assert_eq!(
+            convergence.get_trimmed_average_convergence(0.0),
+            convergence.get_average_convergence()
+        );
+        assert_eq!(
+            convergence.get_trimmed_average_convergence(0.0),
+            vec![Some(980_f64 / 3.0), Some(860_f64 / 3.0)]
+        );
+
+        // One of three runnings with solution is dropped for every iteration
+        assert_eq!(
+            convergence.get_trimmed_average_convergence(0.4),
+            vec![Some(40_f64), Some(30_f64)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_trimmed_average_convergence_invalid() {
+        let convergence: Convergence<f32> = vec![];
+        convergence.get_trimmed_average_convergence(1.0);
+    }
+
     #[test]
     fn get_average_goal_empty() {
         let results: Vec<Option<Solution<f32>>> = vec![];
@@ -851,6 +1720,113 @@ mod tests {
         assert!((results.get_success_rate(&predicate).unwrap() - 0.5).abs() < 1e-5);
     }
 
+    #[test]
+    fn success_performance_several() {
+        let results: Vec<Option<Solution<f64>>> =
+            vec![Some((0.0, 0.0)), Some((1.0, 1.0)), Some((0.1, 0.01)), None];
+        let mut call_counts = CallCountData::new();
+        for calls in &[100, 500, 300, 50] {
+            call_counts.next_run();
+            call_counts.add(*calls);
+        }
+        let predicate = get_predicate_success_goal(0.0, 0.1);
+
+        // Average call count for success runnings is 200, success rate is 0.5
+        let sp1 = success_performance(&results, &call_counts, predicate).unwrap();
+        assert!((sp1 - 400.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn success_performance_fail() {
+        let results: Vec<Option<Solution<f64>>> = vec![Some((1.0, 1.0)), None];
+        let mut call_counts = CallCountData::new();
+        call_counts.next_run();
+        call_counts.add(10);
+        call_counts.next_run();
+        call_counts.add(20);
+        let predicate = get_predicate_success_goal(0.0, 0.1);
+
+        assert_eq!(success_performance(&results, &call_counts, predicate), None);
+    }
+
+    #[test]
+    fn success_performance_empty() {
+        let results: Vec<Option<Solution<f64>>> = vec![];
+        let call_counts = CallCountData::new();
+        let predicate = get_predicate_success_goal(0.0, 0.1);
+
+        assert_eq!(success_performance(&results, &call_counts, predicate), None);
+    }
+
+    #[test]
+    fn zip_results_with_calls_pairs() {
+        let results: Vec<Option<Solution<f64>>> = vec![Some((1.0, 0.5)), None, Some((2.0, 0.1))];
+        let mut call_count = CallCountData::new();
+        for calls in &[10, 20, 30] {
+            call_count.next_run();
+            call_count.add(*calls);
+        }
+
+        assert_eq!(
+            zip_results_with_calls(&results, &call_count),
+            vec![(Some((1.0, 0.5)), 10), (None, 20), (Some((2.0, 0.1)), 30)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Count of the results is not equal")]
+    fn zip_results_with_calls_mismatch() {
+        let results: Vec<Option<Solution<f64>>> = vec![Some((1.0, 0.5)), None];
+        let mut call_count = CallCountData::new();
+        call_count.next_run();
+
+        zip_results_with_calls(&results, &call_count);
+    }
+
+    #[test]
+    fn pad_convergence_different_lengths() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), Some((2_f32, 20_f64))],
+            vec![
+                Some((3_f32, 40_f64)),
+                Some((2_f32, 30_f64)),
+                Some((1_f32, 20_f64)),
+                Some((0_f32, 10_f64)),
+            ],
+            vec![],
+        ];
+
+        let result = vec![Some(35_f64), Some(25_f64), Some(20_f64), Some(15_f64)];
+        assert_eq!(pad_convergence(&convergence, 4), result);
+
+        // The longer runnings are truncated
+        let result = vec![Some(35_f64), Some(25_f64), Some(20_f64)];
+        assert_eq!(pad_convergence(&convergence, 3), result);
+
+        let result = vec![
+            Some(35_f64),
+            Some(25_f64),
+            Some(20_f64),
+            Some(15_f64),
+            Some(15_f64),
+        ];
+        assert_eq!(pad_convergence(&convergence, 5), result);
+    }
+
+    #[test]
+    fn pad_convergence_none() {
+        let convergence: Convergence<f32> = vec![
+            vec![Some((3_f32, 30_f64)), None],
+            vec![None, Some((1_f32, 10_f64)), Some((1_f32, 10_f64))],
+        ];
+
+        let result = vec![Some(30_f64), Some(10_f64), Some(10_f64)];
+        assert_eq!(pad_convergence(&convergence, 3), result);
+
+        let empty: Convergence<f32> = vec![];
+        assert_eq!(pad_convergence(&empty, 2), vec![None, None]);
+    }
+
     #[test]
     fn call_count_data_average_empty() {
         let data = CallCountData::new();
@@ -912,6 +1888,35 @@ mod tests {
         assert_eq!(stat_1.convergence, valid_convergence);
     }
 
+    #[test]
+    fn get_best_trajectory() {
+        let mut statistics: Statistics<Vec<f64>> = Statistics::new();
+        statistics.convergence = vec![
+            vec![
+                None,
+                Some((vec![1.0, 2.0], 5.0)),
+                Some((vec![0.5, 1.0], 1.25)),
+            ],
+            vec![Some((vec![3.0, 0.0], 9.0)), Some((vec![0.0, 0.0], 0.0))],
+        ];
+
+        assert_eq!(
+            statistics.get_best_trajectory(0),
+            vec![None, Some(vec![1.0, 2.0]), Some(vec![0.5, 1.0])]
+        );
+        assert_eq!(
+            statistics.get_best_trajectory(1),
+            vec![Some(vec![3.0, 0.0]), Some(vec![0.0, 0.0])]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_best_trajectory_invalid_run() {
+        let statistics: Statistics<Vec<f64>> = Statistics::new();
+        statistics.get_best_trajectory(0);
+    }
+
     #[test]
     fn statistics_unite_results_01() {
         let mut stat_1: Statistics<f32> = Statistics::new();
@@ -1057,4 +2062,169 @@ mod tests {
 
         assert_eq!(call_count_1.0, valid_call_count_stat);
     }
+
+    #[test]
+    fn write_solution_line() {
+        let mut buffer: Vec<u8> = vec![];
+        write_solution(&mut buffer, 3, &(vec![1.0, -2.5], 0.25), 2).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            output,
+            format!(
+                "{:<8}  {:<20}  {:<20}  {:>20}\n",
+                3, "1.00", "-2.50", "0.25"
+            )
+        );
+    }
+
+    #[test]
+    fn write_results_lines() {
+        let results = [Some((vec![1.0, 2.0], 3.0)), None, Some((vec![0.5, 0.5], 0.1))];
+
+        let mut buffer: Vec<u8> = vec![];
+        write_results(&mut buffer, &results, 3, true).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Run"));
+        assert!(lines[0].contains("x0"));
+        assert!(lines[0].contains("x1"));
+        assert!(lines[0].ends_with("Goal"));
+        assert!(lines[1].starts_with("0 "));
+        assert!(lines[1].contains("1.000"));
+        assert_eq!(lines[2], "1         Failed");
+        assert!(lines[3].starts_with("2 "));
+        assert!(lines[3].ends_with("0.100"));
+    }
+
+    #[test]
+    fn write_results_no_header() {
+        let results: [Option<Solution<Vec<f64>>>; 2] = [None, None];
+
+        let mut buffer: Vec<u8> = vec![];
+        write_results(&mut buffer, &results, 3, false).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output, "0         Failed\n1         Failed\n");
+    }
+
+    #[test]
+    fn export_convergence_matrix_columns() {
+        let convergence: Convergence<f64> = vec![
+            vec![Some((0.0, 3.0)), Some((0.0, 1.0)), Some((0.0, 0.5))],
+            vec![None, Some((0.0, 3.0)), Some((0.0, 2.0))],
+            vec![None, Some((0.0, 2.0))],
+        ];
+
+        let mut buffer: Vec<u8> = vec![];
+        export_convergence_matrix(&mut buffer, &convergence).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+
+        // Rows count is min iterations count
+        assert_eq!(rows.len(), 2);
+
+        // Iteration, 3 runnings, average, standard deviation
+        for row in &rows {
+            assert_eq!(row.len(), 6);
+        }
+
+        assert_eq!(rows[0][0], "0");
+        assert_eq!(rows[0][1].parse::<f64>().unwrap(), 3.0);
+        assert_eq!(rows[0][2], "nan");
+        assert_eq!(rows[0][3], "nan");
+        assert_eq!(rows[0][4].parse::<f64>().unwrap(), 3.0);
+        assert_eq!(rows[0][5], "nan");
+
+        assert_eq!(rows[1][0], "1");
+        assert_eq!(rows[1][4].parse::<f64>().unwrap(), 2.0);
+        assert!((rows[1][5].parse::<f64>().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn export_convergence_matrix_empty() {
+        let convergence: Convergence<f64> = vec![];
+
+        let mut buffer: Vec<u8> = vec![];
+        export_convergence_matrix(&mut buffer, &convergence).unwrap();
+        assert!(buffer.is_empty());
+    }
+
+    fn create_results(count: usize) -> Vec<Option<Solution<f64>>> {
+        (0..count)
+            .map(|n| {
+                if n % 10 == 3 {
+                    None
+                } else {
+                    let x = n as f64;
+                    Some((x, (x * 0.37).sin() * 100.0 + x * 0.01))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn streaming_stats_mean_variance() {
+        let results = create_results(1000);
+
+        let mut stats = StreamingStats::new(10);
+        for result in results.iter() {
+            stats.push(*result);
+        }
+
+        let exact_mean = results.get_average_goal().unwrap();
+        let exact_deviation = results.get_standard_deviation_goal().unwrap();
+
+        assert_eq!(stats.get_run_count(), 1000);
+        assert_eq!(stats.get_success_count(), 900);
+        assert!((stats.mean().unwrap() - exact_mean).abs() < 1e-10);
+        assert!((stats.variance().unwrap() - exact_deviation * exact_deviation).abs() < 1e-8);
+    }
+
+    #[test]
+    fn streaming_stats_empty() {
+        let mut stats = StreamingStats::new(10);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.approx_median(), None);
+
+        stats.push::<f64>(None);
+        assert_eq!(stats.get_run_count(), 1);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.approx_median(), None);
+
+        stats.push(Some((0.0, 2.0)));
+        assert_eq!(stats.mean(), Some(2.0));
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.approx_median(), Some(2.0));
+    }
+
+    #[test]
+    fn streaming_stats_median_exact() {
+        let mut stats = StreamingStats::new(10);
+        for goal in &[5.0, 1.0, 4.0, 2.0] {
+            stats.push(Some((0.0, *goal)));
+        }
+        assert_eq!(stats.approx_median(), Some(3.0));
+
+        stats.push(Some((0.0, 3.5)));
+        assert_eq!(stats.approx_median(), Some(3.5));
+    }
+
+    #[test]
+    fn streaming_stats_median_approx() {
+        let mut stats = StreamingStats::new(500);
+        for n in 0..10000 {
+            stats.push(Some((0.0, n as f64)));
+        }
+
+        let median = stats.approx_median().unwrap();
+        assert!(median > 3000.0 && median < 7000.0);
+    }
 }
@@ -1,13 +1,26 @@
+use std::collections::VecDeque;
 use std::f64;
 
-use super::super::AlgorithmState;
+use num::Float;
+
+use super::super::{AlgorithmState, Objective};
+use crate::tools::Intervals;
 
 /// The trait with break criterion optimization algorithm.
 ///
 /// `T` - type of a point in the search space for goal function.
 pub trait StopChecker<T> {
     /// The method must return true if algorithm must be stopped.
+    ///
+    /// The method may be called several times for the same iteration (for example, by the
+    /// composite stop checkers or by user code between `IterativeOptimizer` calls). The stop
+    /// checkers with internal state must update the state by the iteration number of `state`
+    /// rather than by count of the calls, so the repeated calls return the same result.
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool;
+
+    /// The method must reset internal state of the stop checker. The optimizers call the method
+    /// at the start of `Optimizer::find_min()`, so the optimizer may be used several times.
+    fn reset(&mut self) {}
 }
 
 /// Stop the algorithm if ANY of stop checker returns true
@@ -33,6 +46,12 @@ impl<T> StopChecker<T> for CompositeAny<T> {
 
         false
     }
+
+    fn reset(&mut self) {
+        for checker in &mut self.stop_checkers {
+            checker.reset();
+        }
+    }
 }
 
 /// Stop the algorithm if ALL stop checkers returns true
@@ -58,6 +77,12 @@ impl<T> StopChecker<T> for CompositeAll<T> {
 
         true
     }
+
+    fn reset(&mut self) {
+        for checker in &mut self.stop_checkers {
+            checker.reset();
+        }
+    }
 }
 
 /// The algorithm will be stopped after specified iteration.
@@ -82,6 +107,8 @@ impl<T> StopChecker<T> for MaxIterations {
 }
 
 /// The algorithm will be stopped if the best goal function does not change.
+/// The checker remembers the iteration of the last change of the goal function, so
+/// `can_stop()` may be called several times for the same iteration.
 pub struct GoalNotChange {
     max_iter: usize,
     delta: f64,
@@ -119,13 +146,164 @@ impl<T> StopChecker<T> for GoalNotChange {
                     self.change_iter = state.get_iteration();
                 }
 
-                (state.get_iteration() - self.change_iter) > self.max_iter
+                state.get_iteration().saturating_sub(self.change_iter) > self.max_iter
             }
         }
     }
+
+    fn reset(&mut self) {
+        self.old_goal = f64::MAX;
+        self.change_iter = 0;
+    }
+}
+
+/// The algorithm will be stopped if the best goal function improves slowly: total change of the
+/// best goal function over the last `window` iterations is less than `min_improvement`.
+/// The checker is more robust to small incremental improvements than `GoalNotChange`.
+pub struct SlowProgress {
+    window: usize,
+    min_improvement: f64,
+
+    /// Tuples (iteration, the best goal function) for the window.
+    history: VecDeque<(usize, f64)>,
+}
+
+impl SlowProgress {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `window` - count of the iterations to calculate the improvement. Must be greater
+    ///   than 0.
+    /// * `min_improvement` - the algorithm will be stopped if the improvement of the best goal
+    ///   function over the window less than `min_improvement`.
+    pub fn new(window: usize, min_improvement: f64) -> Self {
+        assert!(window > 0);
+        Self {
+            window,
+            min_improvement,
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> StopChecker<T> for SlowProgress {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        let best_goal = match state.get_best_solution() {
+            None => return false,
+            Some((_, best_goal)) => best_goal,
+        };
+        let iteration = state.get_iteration();
+
+        match self.history.back_mut() {
+            Some(last) if last.0 == iteration => last.1 = best_goal,
+            _ => self.history.push_back((iteration, best_goal)),
+        }
+
+        // The first item is the last iteration before the window
+        while self.history.len() >= 2 && self.history[1].0 + self.window <= iteration {
+            self.history.pop_front();
+        }
+
+        let (first_iteration, first_goal) = self.history[0];
+        first_iteration + self.window <= iteration
+            && (best_goal - first_goal).abs() < self.min_improvement
+    }
+
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+/// The algorithm will be stopped if the best goal function improves by small steps: the
+/// improvement is positive but less than `epsilon` for `consecutive` iterations in a row.
+/// Unlike `GoalNotChange`, the iterations without improvement reset the counter, so the checker
+/// detects diminishing returns rather than a plateau.
+pub struct DiminishingReturns {
+    consecutive: usize,
+    epsilon: f64,
+
+    /// The iteration and the best goal function of the last call.
+    last: Option<(usize, f64)>,
+
+    /// The best goal function of the previous iteration.
+    previous_goal: Option<f64>,
+
+    /// Count of the small improvements before the last iteration.
+    previous_count: usize,
+
+    /// Count of the small improvements in a row.
+    count: usize,
+}
+
+impl DiminishingReturns {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `consecutive` - count of the small improvements in a row to stop the algorithm. Must
+    ///   be greater than 0.
+    /// * `epsilon` - the improvement of the best goal function is small if it is less than
+    ///   `epsilon`.
+    pub fn new(consecutive: usize, epsilon: f64) -> Self {
+        assert!(consecutive > 0);
+        Self {
+            consecutive,
+            epsilon,
+            last: None,
+            previous_goal: None,
+            previous_count: 0,
+            count: 0,
+        }
+    }
 }
 
-/// Stop the algorithm if value of the goal function less of than threshold.
+impl<T> StopChecker<T> for DiminishingReturns {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        let best_goal = match state.get_best_solution() {
+            None => return false,
+            Some((_, best_goal)) => best_goal,
+        };
+        let iteration = state.get_iteration();
+
+        // The repeated call for the same iteration recalculates the counter
+        match self.last {
+            Some((last_iteration, _)) if last_iteration == iteration => {}
+            Some((_, last_goal)) => {
+                self.previous_goal = Some(last_goal);
+                self.previous_count = self.count;
+            }
+            None => {}
+        }
+        self.last = Some((iteration, best_goal));
+
+        self.count = match self.previous_goal {
+            None => 0,
+            Some(previous_goal) => {
+                let improvement = match state.get_objective() {
+                    Objective::Minimize => previous_goal - best_goal,
+                    Objective::Maximize => best_goal - previous_goal,
+                };
+
+                if improvement > 0.0 && improvement < self.epsilon {
+                    self.previous_count + 1
+                } else {
+                    0
+                }
+            }
+        };
+
+        self.count >= self.consecutive
+    }
+
+    fn reset(&mut self) {
+        self.last = None;
+        self.previous_goal = None;
+        self.previous_count = 0;
+        self.count = 0;
+    }
+}
+
+/// Stop the algorithm if value of the goal function less of than threshold (or greater than
+/// threshold if the objective of the algorithm is `Objective::Maximize`).
 pub struct Threshold {
     threshold: f64,
 }
@@ -144,7 +322,376 @@ impl<T> StopChecker<T> for Threshold {
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
         match state.get_best_solution() {
             None => false,
-            Some((_, goal)) => goal <= self.threshold,
+            Some((_, goal)) => match state.get_objective() {
+                Objective::Minimize => goal <= self.threshold,
+                Objective::Maximize => goal >= self.threshold,
+            },
+        }
+    }
+}
+
+/// Stop the algorithm if the agents (particles, individuals) are contracted.
+/// The checker calculates diagonal of the bounding box of all agents coordinates and stops the
+/// algorithm if the diagonal is less than `fraction` of the search box diagonal.
+///
+/// The checker gets the agents by `AlgorithmState::get_agent_list()`. If the state does not
+/// return the agents (the method returns None), the checker never stops the algorithm.
+pub struct SwarmDiameter<T: Float> {
+    intervals: Intervals<T>,
+    fraction: f64,
+}
+
+impl<T: Float> SwarmDiameter<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - intervals (min, max) of the search box for every dimension.
+    /// * `fraction` - the algorithm will be stopped if diagonal of the agents bounding box less
+    ///   than `fraction` * (diagonal of the search box).
+    ///
+    /// # Panics
+    /// Panics if min > max for any interval (see `Intervals::new()`).
+    pub fn new(intervals: Vec<(T, T)>, fraction: f64) -> Self {
+        assert!(fraction >= 0.0);
+        Self {
+            intervals: Intervals::new(intervals).unwrap(),
+            fraction,
+        }
+    }
+
+    fn get_search_diagonal(&self) -> f64 {
+        (0..self.intervals.len())
+            .map(|i| self.intervals.width(i).to_f64().unwrap().powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl<T: Float> StopChecker<Vec<T>> for SwarmDiameter<T> {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<Vec<T>>) -> bool {
+        let agents = match state.get_agent_list() {
+            None => return false,
+            Some(agents) => agents,
+        };
+
+        if agents.is_empty() {
+            return false;
+        }
+
+        let dimension = self.intervals.len();
+        let mut min_coord = vec![f64::INFINITY; dimension];
+        let mut max_coord = vec![f64::NEG_INFINITY; dimension];
+
+        for agent in agents {
+            let coordinates = agent.get_parameter();
+            assert_eq!(coordinates.len(), dimension);
+
+            for (i, x) in coordinates.iter().enumerate() {
+                let x = x.to_f64().unwrap();
+                min_coord[i] = min_coord[i].min(x);
+                max_coord[i] = max_coord[i].max(x);
+            }
+        }
+
+        let diagonal = min_coord
+            .iter()
+            .zip(max_coord.iter())
+            .map(|(min, max)| (max - min).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        diagonal < self.fraction * self.get_search_diagonal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Agent, Solution};
+
+    struct AgentStub {
+        coordinates: Vec<f64>,
+    }
+
+    impl Agent<Vec<f64>> for AgentStub {
+        fn get_parameter(&self) -> &Vec<f64> {
+            &self.coordinates
+        }
+
+        fn get_goal(&self) -> f64 {
+            0.0
+        }
+    }
+
+    struct AgentsStateStub {
+        agents: Vec<AgentStub>,
+    }
+
+    impl AlgorithmState<Vec<f64>> for AgentsStateStub {
+        fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+            None
+        }
+
+        fn get_iteration(&self) -> usize {
+            0
+        }
+
+        fn get_agent_list(&self) -> Option<Vec<&dyn Agent<Vec<f64>>>> {
+            Some(
+                self.agents
+                    .iter()
+                    .map(|agent| agent as &dyn Agent<Vec<f64>>)
+                    .collect(),
+            )
+        }
+    }
+
+    fn create_state(points: &[[f64; 2]]) -> AgentsStateStub {
+        AgentsStateStub {
+            agents: points
+                .iter()
+                .map(|point| AgentStub {
+                    coordinates: point.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn swarm_diameter() {
+        // Diagonal of the search box is 5
+        let mut checker = SwarmDiameter::new(vec![(0.0, 3.0), (0.0, 4.0)], 0.5);
+
+        let state_wide = create_state(&[[0.0, 0.0], [3.0, 4.0], [0.5, 0.2]]);
+        assert!(!checker.can_stop(&state_wide));
+
+        // Diagonal of the agents is 2.5 (not less than 0.5 * 5)
+        let state_border = create_state(&[[1.0, 1.0], [2.5, 3.0]]);
+        assert!(!checker.can_stop(&state_border));
+
+        let state_narrow = create_state(&[[1.0, 1.0], [1.5, 1.5], [1.25, 1.0]]);
+        assert!(checker.can_stop(&state_narrow));
+    }
+
+    #[test]
+    fn swarm_diameter_empty() {
+        let mut checker = SwarmDiameter::new(vec![(0.0, 3.0), (0.0, 4.0)], 0.1);
+        assert!(!checker.can_stop(&create_state(&[])));
+    }
+
+    #[test]
+    fn swarm_diameter_no_agents() {
+        struct StateStub;
+
+        impl AlgorithmState<Vec<f64>> for StateStub {
+            fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+                None
+            }
+
+            fn get_iteration(&self) -> usize {
+                0
+            }
+        }
+
+        let mut checker = SwarmDiameter::new(vec![(0.0, 3.0), (0.0, 4.0)], 0.1);
+        assert!(!checker.can_stop(&StateStub));
+    }
+
+    struct ObjectiveStateStub {
+        goal: f64,
+        objective: Objective,
+    }
+
+    impl AlgorithmState<f64> for ObjectiveStateStub {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            Some((0.0, self.goal))
+        }
+
+        fn get_iteration(&self) -> usize {
+            0
+        }
+
+        fn get_objective(&self) -> Objective {
+            self.objective
+        }
+    }
+
+    #[test]
+    fn threshold_objective() {
+        let mut checker = Threshold::new(1.0);
+
+        let state = |goal, objective| ObjectiveStateStub { goal, objective };
+
+        assert!(checker.can_stop(&state(0.5, Objective::Minimize)));
+        assert!(checker.can_stop(&state(1.0, Objective::Minimize)));
+        assert!(!checker.can_stop(&state(1.5, Objective::Minimize)));
+
+        assert!(!checker.can_stop(&state(0.5, Objective::Maximize)));
+        assert!(checker.can_stop(&state(1.0, Objective::Maximize)));
+        assert!(checker.can_stop(&state(1.5, Objective::Maximize)));
+    }
+
+    struct GoalStateStub {
+        goal: f64,
+        iteration: usize,
+    }
+
+    impl AlgorithmState<f64> for GoalStateStub {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            Some((0.0, self.goal))
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+    }
+
+    #[test]
+    fn goal_not_change_reset() {
+        let mut checker = GoalNotChange::new(2, 0.1);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+
+        assert!(!checker.can_stop(&state(10.0, 0)));
+        assert!(!checker.can_stop(&state(5.0, 5)));
+        assert!(!checker.can_stop(&state(5.0, 7)));
+        assert!(checker.can_stop(&state(5.0, 8)));
+
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!checker.can_stop(&state(5.0, 0)));
+        assert!(!checker.can_stop(&state(5.0, 2)));
+        assert!(checker.can_stop(&state(5.0, 3)));
+    }
+
+    #[test]
+    fn goal_not_change_repeated_calls() {
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+        let goals = [10.0, 5.0, 5.0, 5.05, 5.0, 5.0, 4.0, 4.0, 4.0, 4.0];
+
+        let mut checker_single = GoalNotChange::new(2, 0.1);
+        let mut checker_repeated = GoalNotChange::new(2, 0.1);
+        let mut checker_composite: CompositeAny<f64> = CompositeAny::new(vec![
+            Box::new(GoalNotChange::new(2, 0.1)),
+            Box::new(MaxIterations::new(100)),
+        ]);
+
+        for (iteration, goal) in goals.iter().enumerate() {
+            let expected = checker_single.can_stop(&state(*goal, iteration));
+
+            for _ in 0..3 {
+                assert_eq!(
+                    checker_repeated.can_stop(&state(*goal, iteration)),
+                    expected
+                );
+                assert_eq!(
+                    checker_composite.can_stop(&state(*goal, iteration)),
+                    expected
+                );
+            }
+        }
+
+        // The goal function was changed on the iterations 0, 1 and 6
+        assert!(checker_single.can_stop(&state(4.0, 9)));
+        assert!(!checker_single.can_stop(&state(4.0, 8)));
+    }
+
+    #[test]
+    fn goal_not_change_without_reset() {
+        let mut checker = GoalNotChange::new(2, 0.1);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+
+        assert!(!checker.can_stop(&state(10.0, 5)));
+
+        // The iteration is less than the iteration of the last change
+        assert!(!checker.can_stop(&state(10.0, 1)));
+    }
+
+    #[test]
+    fn composite_reset() {
+        let mut checker: CompositeAny<f64> = CompositeAny::new(vec![
+            Box::new(GoalNotChange::new(2, 0.1)),
+            Box::new(MaxIterations::new(100)),
+        ]);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+
+        assert!(!checker.can_stop(&state(5.0, 5)));
+        assert!(checker.can_stop(&state(5.0, 8)));
+
+        checker.reset();
+        assert!(!checker.can_stop(&state(5.0, 0)));
+    }
+
+    #[test]
+    fn slow_progress() {
+        let mut checker = SlowProgress::new(3, 5.0);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+        let goals = [100.0, 90.0, 80.0, 75.0, 72.0, 71.0, 70.5];
+
+        // The improvement over 3 iterations is 25, 18, 9 and 4.5 for the iterations 3..6
+        for (iteration, goal) in goals.iter().enumerate().take(6) {
+            assert!(!checker.can_stop(&state(*goal, iteration)));
+        }
+        assert!(checker.can_stop(&state(70.5, 6)));
+
+        // The repeated call for the same iteration
+        assert!(checker.can_stop(&state(70.5, 6)));
+
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!checker.can_stop(&state(70.5, 6)));
+        assert!(!checker.can_stop(&state(70.5, 8)));
+        assert!(checker.can_stop(&state(70.5, 9)));
+    }
+
+    #[test]
+    fn slow_progress_not_found() {
+        struct StateStub {
+            iteration: usize,
+        }
+
+        impl AlgorithmState<f64> for StateStub {
+            fn get_best_solution(&self) -> Option<Solution<f64>> {
+                None
+            }
+
+            fn get_iteration(&self) -> usize {
+                self.iteration
+            }
+        }
+
+        let mut checker = SlowProgress::new(1, 5.0);
+        for iteration in 0..10 {
+            assert!(!checker.can_stop(&StateStub { iteration }));
+        }
+    }
+
+    #[test]
+    fn diminishing_returns() {
+        let mut checker = DiminishingReturns::new(3, 1.0);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+        let goals = [100.0, 90.0, 89.5, 89.2, 89.1];
+
+        // The improvements are 10, 0.5, 0.3 and 0.1
+        for (iteration, goal) in goals.iter().enumerate().take(4) {
+            assert!(!checker.can_stop(&state(*goal, iteration)));
+        }
+        assert!(checker.can_stop(&state(89.1, 4)));
+
+        // The repeated call for the same iteration
+        assert!(checker.can_stop(&state(89.1, 4)));
+
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!checker.can_stop(&state(89.1, 4)));
+    }
+
+    #[test]
+    fn diminishing_returns_counter_reset() {
+        let mut checker = DiminishingReturns::new(2, 1.0);
+        let state = |goal, iteration| GoalStateStub { goal, iteration };
+
+        // No improvement and large improvement reset the counter
+        let goals = [100.0, 99.5, 99.5, 99.2, 95.0, 94.9];
+        for (iteration, goal) in goals.iter().enumerate() {
+            assert!(!checker.can_stop(&state(*goal, iteration)));
         }
+        assert!(checker.can_stop(&state(94.8, 6)));
     }
 }
@@ -0,0 +1,76 @@
+use std::fs;
+
+use optlib::genetic::{self, cross, mutation, pairing, selection};
+use optlib::tools::logging;
+use optlib::tools::stopchecker;
+use optlib::{GoalFromFunction, Optimizer};
+
+type Chromosomes = f64;
+
+/// The minimum is 0 for the point 2.
+fn parabola(x: &Chromosomes) -> f64 {
+    (x - 2.0) * (x - 2.0)
+}
+
+/// Creator of the first generation with the chromosomes in the interval [-10, 10].
+struct LinearCreator {
+    population_size: usize,
+}
+
+impl genetic::Creator<Chromosomes> for LinearCreator {
+    fn create(&mut self) -> Vec<Chromosomes> {
+        let step = 20.0 / (self.population_size - 1) as f64;
+        (0..self.population_size)
+            .map(|n| -10.0 + step * n as f64)
+            .collect()
+    }
+}
+
+#[test]
+fn genetic_checkpoint() {
+    let population_size = 50;
+    let path = std::env::temp_dir().join(format!(
+        "optlib-genetic-checkpoint-{}.txt",
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+
+    let goal = GoalFromFunction::new(parabola);
+    let creator = LinearCreator { population_size };
+    let pairing = pairing::Tournament::new(population_size / 2);
+    let cross = cross::CrossMean::new();
+    let mutation = mutation::BitwiseMutation::new(1);
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+    let stop_checker = stopchecker::MaxIterations::new(25);
+
+    let loggers: Vec<Box<dyn logging::Logger<Chromosomes>>> =
+        vec![Box::new(logging::CheckpointLogger::new(&path, 10))];
+
+    let mut optimizer = genetic::GeneticOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(mutation),
+        selections,
+        vec![],
+    );
+    optimizer.set_loggers(loggers);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        content,
+        format!(
+            "Iteration: 25\nSolution: {}\nGoal: {}\n",
+            solution, goal_value
+        )
+    );
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "half")]
+
+use half::f16;
+use num::abs;
+
+use optlib::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use optlib::genetic::{Cross, Mutation};
+use optlib::tools::stopchecker;
+use optlib::{GoalFromFunction, Optimizer};
+use optlib_testfunc;
+
+type Gene = f16;
+type Chromosomes = Vec<Gene>;
+
+#[test]
+fn cross_f16_as_cross_u16() {
+    let parents = [
+        (0u16, std::u16::MAX),
+        (0b_1010_1010_1010_1010, 0b_0101_0101_0101_0101),
+    ];
+    for (parent_1, parent_2) in parents.iter() {
+        for pos in 1..16 {
+            let child = cross::cross_f16(f16::from_bits(*parent_1), f16::from_bits(*parent_2), pos);
+            assert_eq!(child.to_bits(), cross::cross_u16(*parent_1, *parent_2, pos));
+        }
+    }
+}
+
+#[test]
+fn cross_bitwise_f16() {
+    let parent_1 = f16::from_bits(0);
+    let parent_2 = f16::from_bits(std::u16::MAX);
+    let mut cross = cross::CrossBitwise::new();
+
+    for _ in 0..100 {
+        let children = cross.cross(&[&parent_1, &parent_2]);
+        assert_eq!(children.len(), 1);
+
+        // The child takes the low bits from the second parent and the high bits from the first
+        let bits = children[0].to_bits();
+        assert_ne!(bits, 0);
+        assert_eq!(bits & (bits + 1), 0);
+    }
+}
+
+#[test]
+fn bitwise_mutation_f16() {
+    let gene = f16::from_f32(1.5);
+    let mut mutation = mutation::BitwiseMutation::new(1);
+
+    for _ in 0..100 {
+        let mutant = mutation.mutation(&gene);
+        assert_eq!((mutant.to_bits() ^ gene.to_bits()).count_ones(), 1);
+    }
+}
+
+#[test]
+fn genetic_paraboloid_f16() {
+    // General parameters
+    let minval = f16::from_f32(-10.0);
+    let maxval = f16::from_f32(10.0);
+    let population_size = 200;
+    let chromo_count = 3;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    // Goal function
+    let goal = GoalFromFunction::new(optlib_testfunc::paraboloid);
+
+    // Creator
+    let creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+
+    // Pairing
+    let pairing = pairing::Tournament::new(population_size / 2)
+        .partners_count(2)
+        .rounds_count(2);
+
+    // Cross
+    let single_cross = cross::CrossBitwise::new();
+    let cross = cross::VecCrossAllGenes::new(Box::new(single_cross));
+
+    // Mutation
+    let single_mutation = mutation::BitwiseMutation::new(1);
+    let mutation = mutation::VecMutation::new(10.0, Box::new(single_mutation));
+
+    // Pre birth
+    let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![Box::new(
+        pre_birth::vec_float::CheckChromoInterval::new(intervals.clone()),
+    )];
+
+    // Selection
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    // Stop checker
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-4)),
+        Box::new(stopchecker::MaxIterations::new(500)),
+    ]);
+
+    let mut optimizer = genetic::GeneticOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(mutation),
+        selections,
+        pre_births,
+    );
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    for (i, gene) in solution.iter().enumerate() {
+        assert!(abs(gene.to_f32() - (i as f32 + 1.0)) < 0.05);
+    }
+
+    assert!(goal_value < 1e-2);
+}
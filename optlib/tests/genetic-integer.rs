@@ -0,0 +1,82 @@
+use optlib::genetic::{self, creation, cross, mutation, pairing, selection};
+use optlib::tools::logging;
+use optlib::tools::stopchecker;
+use optlib::{GoalFromFunction, Optimizer};
+
+type Gene = i64;
+type Chromosomes = Vec<Gene>;
+
+/// The integer quadratic function. The minimum is 0 for the point (-7, 3, 0, 12, 25).
+fn integer_quadratic(x: &Chromosomes) -> f64 {
+    let target = [-7, 3, 0, 12, 25];
+    x.iter()
+        .zip(target.iter())
+        .map(|(xi, ti)| ((xi - ti) * (xi - ti)) as f64)
+        .sum()
+}
+
+#[test]
+fn genetic_integer() {
+    // General parameters
+    let minval: Gene = -50;
+    let maxval: Gene = 50;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    // Goal function
+    let goal = GoalFromFunction::new(integer_quadratic);
+
+    // Creator
+    let creator = creation::vec_int::RandomCreator::new(population_size, intervals.clone());
+
+    // Pairing
+    let families_count = population_size / 2;
+    let pairing = pairing::Tournament::new(families_count)
+        .partners_count(2)
+        .rounds_count(2);
+
+    // Cross
+    let cross = cross::VecCrossTwoPoint::new();
+
+    // Mutation: small steps and rare resample inside the intervals
+    let single_mutation = mutation::IntStepMutation::new(2);
+    let step_mutation = mutation::VecMutation::new(20.0, Box::new(single_mutation));
+    let mutation = mutation::IntResampleMutation::new(intervals.clone(), 1.0);
+
+    // Selection
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> =
+        vec![Box::new(selection::LimitPopulation::new(population_size))];
+
+    // Stop checker
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(0.0)),
+        Box::new(stopchecker::MaxIterations::new(2000)),
+    ]);
+
+    // Logger
+    let loggers: Vec<Box<dyn logging::Logger<Chromosomes>>> = vec![];
+
+    let mut optimizer = genetic::GeneticOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(step_mutation),
+        selections,
+        vec![],
+    );
+    optimizer.set_loggers(loggers);
+
+    // Run genetic algorithm
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    assert_eq!(solution, vec![-7, 3, 0, 12, 25]);
+    assert_eq!(goal_value, 0.0);
+
+    // The resample mutation keeps the genes inside the intervals
+    optimizer.set_mutation(Box::new(mutation));
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    assert_eq!(solution, vec![-7, 3, 0, 12, 25]);
+    assert_eq!(goal_value, 0.0);
+}